@@ -0,0 +1,174 @@
+//! Microsoft Access connection strings, the OLE DB connection strings of the Jet and
+//! ACE providers.
+
+use crate::oledb::OleDbConnStr;
+use crate::validate::{ValidationIssue, Validator};
+use crate::{decode_bytes, Error, ParseOptions};
+use std::fmt;
+use std::str::FromStr;
+
+/// An OLE DB connection string of an Access database, opened by the Jet provider for
+/// the .mdb files or by the ACE provider for the .accdb files.
+///
+/// The properties of the providers are prefixed by `Jet OLEDB:`, the colon being part
+/// of the key.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::access::AccessConnStr;
+/// use std::str::FromStr;
+///
+/// let conn = AccessConnStr::from_str(r"Provider=Microsoft.ACE.OLEDB.12.0;Data Source=C:\db.accdb;Jet OLEDB:Database Password=x;Jet OLEDB:Engine Type=6").unwrap();
+///
+/// assert!(conn.is_ace());
+/// assert_eq!(Some(r"C:\db.accdb"), conn.data_source());
+/// assert_eq!(Some("6"), conn.jet_property("engine type"));
+/// assert_eq!(
+///     r"provider=Microsoft.ACE.OLEDB.12.0;data source=C:\db.accdb;jet oledb:database password=***;jet oledb:engine type=6",
+///     conn.to_string(),
+/// );
+///
+/// assert!(AccessConnStr::from_str("Provider=SQLOLEDB;Data Source=.").is_err());
+/// ```
+#[derive(Clone)]
+pub struct AccessConnStr(OleDbConnStr);
+
+impl FromStr for AccessConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl AccessConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    /// Parses the connection string, failing when the provider is neither Jet nor ACE.
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        let conn = OleDbConnStr::parse_with(conn_str, options)?;
+
+        if let Some(provider) = conn.provider() {
+            if !is_jet_or_ace(provider) {
+                return Err(Error::InvalidValue {
+                    key: "provider".to_owned(),
+                    value: provider.to_owned(),
+                    expected: "`Microsoft.Jet.OLEDB.4.0` or `Microsoft.ACE.OLEDB.12.0`".to_owned(),
+                });
+            }
+        }
+
+        Ok(AccessConnStr(conn))
+    }
+
+    /// Gets the OLE DB connection string.
+    pub fn as_ole_db(&self) -> &OleDbConnStr {
+        &self.0
+    }
+
+    /// Gets the path of the database file.
+    pub fn data_source(&self) -> Option<&str> {
+        self.0.data_source()
+    }
+
+    #[cfg(not(feature = "secret"))]
+    pub fn database_password(&self) -> Option<&str> {
+        self.jet_property("database password")
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn database_password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.jet_property("database password")
+            .map(crate::secret::Secret::new)
+    }
+
+    /// Gets the nested connection string of the provider.
+    pub fn extended_properties(&self) -> Option<&str> {
+        self.0.extended_properties()
+    }
+
+    /// Indicates if the provider is ACE, opening the .accdb files, rather than Jet.
+    pub fn is_ace(&self) -> bool {
+        self.provider()
+            .is_some_and(|p| p.trim().to_lowercase().starts_with("microsoft.ace.oledb."))
+    }
+
+    /// Gets a `Jet OLEDB:` property by its name without the prefix, ignoring case.
+    pub fn jet_property(&self, name: &str) -> Option<&str> {
+        self.0.get(&format!("jet oledb:{}", name.trim()))
+    }
+
+    /// Gets the password of the user of the workgroup, rather than the one of the
+    /// database.
+    #[cfg(not(feature = "secret"))]
+    pub fn password(&self) -> Option<&str> {
+        self.0.password()
+    }
+
+    /// Gets the password of the user of the workgroup, rather than the one of the
+    /// database.
+    #[cfg(feature = "secret")]
+    pub fn password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.0.password()
+    }
+
+    pub fn provider(&self) -> Option<&str> {
+        self.0.provider()
+    }
+
+    /// Gets the path of the workgroup information file, .mdw, holding the users.
+    pub fn system_database(&self) -> Option<&str> {
+        self.jet_property("system database")
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        self.0.to_connection_string_with_secrets()
+    }
+
+    pub fn user_id(&self) -> Option<&str> {
+        self.0.user_id()
+    }
+
+    /// Validates the connection string, returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        self.0.validate_with(validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted, the provider first.
+impl fmt::Display for AccessConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Indicates if the provider is Jet or ACE, whatever its version.
+pub(crate) fn is_jet_or_ace(provider: &str) -> bool {
+    let name = provider.trim().to_lowercase();
+    name.starts_with("microsoft.jet.oledb.") || name.starts_with("microsoft.ace.oledb.")
+}
+
+#[test]
+fn jet_properties_keep_their_colon() {
+    let conn = AccessConnStr::from_str(
+        "Provider=Microsoft.Jet.OLEDB.4.0;Data Source=app.mdb;Jet OLEDB:System Database=system.mdw;User ID=admin;Extended Properties=\"dBASE IV;\"",
+    )
+    .unwrap();
+
+    assert!(!conn.is_ace());
+    assert_eq!(Some("system.mdw"), conn.system_database());
+    assert_eq!(Some("admin"), conn.user_id());
+    assert_eq!(Some("dBASE IV;"), conn.extended_properties());
+    assert!(conn.database_password().is_none());
+}
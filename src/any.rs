@@ -0,0 +1,257 @@
+//! Detection of the format of a connection string.
+//!
+//! Only the ADO.NET formats of SQL Server and Entity Framework are detected, any
+//! other connection string being taken for a SQL Server one.
+
+use crate::keywords::{self, Keywords};
+use crate::validate::{ValidationIssue, Validator};
+use crate::{decode_bytes, get, parse, redact_value, EFConnStr, Error, MsSqlConnStr, ParseOptions};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// The format of a connection string, among the detected ones.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Provider {
+    EntityFramework,
+    MsSql,
+}
+
+impl Provider {
+    /// Detects the format of a connection string from its keys.
+    ///
+    /// Only Entity Framework is told apart from SQL Server: the other formats, like
+    /// libpq, Redis or MongoDB, are detected as [`Provider::MsSql`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::Provider;
+    ///
+    /// let conn = r#"metadata=res://*/Model.csdl;provider connection string="server=.""#;
+    ///
+    /// assert_eq!(Provider::EntityFramework, Provider::detect(conn).unwrap());
+    /// assert_eq!(Provider::MsSql, Provider::detect("server=.;database=Db1").unwrap());
+    /// ```
+    pub fn detect(conn_str: &str) -> Result<Provider, Error> {
+        let map = parse(conn_str, false, &keywords::MS_SQL, &ParseOptions::default())?;
+
+        if map.contains_key("provider connection string") || map.contains_key("metadata") {
+            Ok(Provider::EntityFramework)
+        } else {
+            Ok(Provider::MsSql)
+        }
+    }
+}
+
+impl Provider {
+    /// Gets the keyword metadata of the format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::keywords::ValueKind;
+    /// use conn_str::Provider;
+    ///
+    /// let keyword = Provider::MsSql.keywords().find("timeout").unwrap();
+    ///
+    /// assert_eq!("connect timeout", keyword.name);
+    /// assert_eq!(Some("15"), keyword.default);
+    /// assert!(matches!(keyword.kind, ValueKind::Duration { min: 0, .. }));
+    /// ```
+    pub fn keywords(&self) -> &'static Keywords {
+        match self {
+            Provider::EntityFramework => &keywords::EF,
+            Provider::MsSql => &keywords::MS_SQL,
+        }
+    }
+}
+
+impl fmt::Display for Provider {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Provider::EntityFramework => "Entity Framework",
+            Provider::MsSql => "MS SQL",
+        })
+    }
+}
+
+/// A connection string of one of the formats detected by [`Provider::detect`].
+#[derive(Clone)]
+#[non_exhaustive]
+pub enum AnyConnStr {
+    EntityFramework(EFConnStr),
+    MsSql(MsSqlConnStr),
+}
+
+impl AnyConnStr {
+    /// Decodes the bytes with [`decode_bytes`], detects the format
+    /// of the connection string and parses it.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    /// Detects the format of the connection string and parses it.
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        Ok(match Provider::detect(conn_str)? {
+            Provider::EntityFramework => {
+                AnyConnStr::EntityFramework(EFConnStr::parse_with(conn_str, options)?)
+            }
+            Provider::MsSql => AnyConnStr::MsSql(MsSqlConnStr::parse_with(conn_str, options)?),
+        })
+    }
+
+    /// Gets the value of a key, looking up its synonyms, or of a key unknown to the
+    /// format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::AnyConnStr;
+    /// use std::str::FromStr;
+    ///
+    /// let conn = AnyConnStr::from_str("Data Source=.;Future Option=1").unwrap();
+    ///
+    /// assert_eq!(Some("."), conn.get("server"));
+    /// assert_eq!(Some("1"), conn.get("Future Option"));
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&str> {
+        let key = key.trim().to_lowercase();
+
+        let keywords = self.provider().keywords();
+
+        match keywords.find(&key) {
+            Some(keyword) => get(self.map(), keywords, keyword.name),
+            None => self.map().get(&key).map(|s| s.as_str()),
+        }
+    }
+
+    /// Gets the value of a key like [`get`](Self::get), a secret being replaced by `***`
+    /// like the [`Display`](fmt::Display) implementation does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::AnyConnStr;
+    /// use std::str::FromStr;
+    ///
+    /// let conn = AnyConnStr::from_str("Server=.;Pwd=x").unwrap();
+    ///
+    /// assert_eq!(Some("***"), conn.get_redacted("password").as_deref());
+    /// assert_eq!(Some("."), conn.get_redacted("server").as_deref());
+    /// ```
+    pub fn get_redacted(&self, key: &str) -> Option<Cow<'_, str>> {
+        let value = self.get(key)?;
+        Some(redact_value(self.provider().keywords(), key, value))
+    }
+
+    /// Gets the keys, in lower case, and their values, sorted by key, the secrets being
+    /// replaced by `***` when they are redacted, like the
+    /// [`Display`](fmt::Display) implementation does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::AnyConnStr;
+    /// use std::str::FromStr;
+    ///
+    /// let conn = AnyConnStr::from_str("Server=.;Password=x;Server=y").unwrap();
+    ///
+    /// let format = |redact| {
+    ///     conn.pairs(redact)
+    ///         .into_iter()
+    ///         .map(|(k, v)| format!("{}={}", k, v))
+    ///         .collect::<Vec<_>>()
+    /// };
+    ///
+    /// assert_eq!(vec!["password=***", "server=."], format(true));
+    /// assert_eq!(vec!["password=x", "server=."], format(false));
+    /// ```
+    pub fn pairs(&self, redact: bool) -> Vec<(&str, Cow<'_, str>)> {
+        let keywords = self.provider().keywords();
+
+        let mut pairs: Vec<_> = self
+            .map()
+            .iter()
+            .map(|(k, v)| match redact {
+                true => (k.as_str(), redact_value(keywords, k, v)),
+                false => (k.as_str(), Cow::Borrowed(v.as_str())),
+            })
+            .collect();
+
+        pairs.sort_unstable();
+        pairs
+    }
+
+    /// Validates the connection string, returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        match self {
+            AnyConnStr::EntityFramework(c) => c.validate_with(validator),
+            AnyConnStr::MsSql(c) => c.validate_with(validator),
+        }
+    }
+
+    /// Audits the connection string for insecure configurations.
+    pub fn security_audit(&self) -> Vec<ValidationIssue> {
+        match self {
+            AnyConnStr::EntityFramework(c) => c.security_audit(),
+            AnyConnStr::MsSql(c) => c.security_audit(),
+        }
+    }
+
+    /// Gets the keys unknown to the format, in lower case and sorted.
+    pub fn unknown_keys(&self) -> Vec<&str> {
+        match self {
+            AnyConnStr::EntityFramework(c) => c.unknown_keys(),
+            AnyConnStr::MsSql(c) => c.unknown_keys(),
+        }
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        match self {
+            AnyConnStr::EntityFramework(c) => c.to_connection_string_with_secrets(),
+            AnyConnStr::MsSql(c) => c.to_connection_string_with_secrets(),
+        }
+    }
+
+    pub fn provider(&self) -> Provider {
+        match self {
+            AnyConnStr::EntityFramework(_) => Provider::EntityFramework,
+            AnyConnStr::MsSql(_) => Provider::MsSql,
+        }
+    }
+
+    fn map(&self) -> &HashMap<String, String> {
+        match self {
+            AnyConnStr::EntityFramework(c) => &c.0,
+            AnyConnStr::MsSql(c) => &c.0,
+        }
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for AnyConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnyConnStr::EntityFramework(c) => c.fmt(f),
+            AnyConnStr::MsSql(c) => c.fmt(f),
+        }
+    }
+}
+
+impl FromStr for AnyConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
@@ -0,0 +1,418 @@
+//! Arena parsing of many connection strings.
+//!
+//! An [`Arena`] stores the keys and values of every connection string it parses in
+//! a single buffer. The parsed strings are accessed as borrowed views and are all
+//! freed at once when the arena is cleared or dropped.
+
+use crate::keywords::{Keywords, Lookup};
+use crate::{check_hardened, decrypt_and_check, scan_key_value, Error, ParseOptions, RawPair};
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::BuildHasher;
+use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// The source of the unique ids of the arenas.
+static NEXT_ARENA: AtomicUsize = AtomicUsize::new(0);
+
+/// Identifies a connection string parsed into an [`Arena`], until the arena is
+/// cleared.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ArenaId {
+    arena: usize,
+    index: usize,
+    generation: usize,
+}
+
+/// A store for many parsed connection strings.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::arena::Arena;
+/// use conn_str::keywords::MS_SQL;
+/// use conn_str::ParseOptions;
+///
+/// let options = ParseOptions::default();
+/// let mut arena = Arena::new();
+/// let a = arena.parse("server=.;database=Db1", false, &MS_SQL, &options).unwrap();
+/// let b = arena.parse("data source=srv;initial catalog=Db2", false, &MS_SQL, &options).unwrap();
+///
+/// assert_eq!(Some("Db1"), arena.get(a).unwrap().get("database"));
+/// assert_eq!(Some("srv"), arena.get(b).unwrap().keyword(&MS_SQL, "data source"));
+///
+/// // frees all the strings at once, invalidating their ids
+/// arena.clear();
+/// assert!(arena.is_empty());
+/// assert!(arena.get(a).is_none());
+/// ```
+pub struct Arena {
+    /// Unique to this arena, so that the ids of another arena are rejected.
+    id: usize,
+    text: String,
+    pairs: Vec<(Range<usize>, Range<usize>)>,
+    strings: Vec<Range<usize>>,
+
+    /// Incremented by [`clear`](Arena::clear), so that the previous ids are rejected.
+    generation: usize,
+
+    /// The scratch buffer of the key and the value being parsed.
+    buf: String,
+
+    /// The hash of the keys of the string being parsed, with the index of their pair.
+    keys: HashMap<u64, usize>,
+    hasher: RandomState,
+}
+
+impl Default for Arena {
+    fn default() -> Self {
+        Self::with_capacity(0, 0)
+    }
+}
+
+impl Arena {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an arena with room for `text` bytes of keys and values and `len` connection strings.
+    pub fn with_capacity(text: usize, len: usize) -> Self {
+        Arena {
+            id: NEXT_ARENA.fetch_add(1, Ordering::Relaxed),
+            text: String::with_capacity(text),
+            pairs: Vec::with_capacity(len * 4),
+            strings: Vec::with_capacity(len),
+            generation: 0,
+            buf: String::new(),
+            keys: HashMap::new(),
+            hasher: RandomState::new(),
+        }
+    }
+
+    /// Removes every connection string, keeping the allocated memory.
+    ///
+    /// The ids of the removed strings are no longer valid, [`get`](Arena::get)
+    /// returning `None` for them.
+    pub fn clear(&mut self) {
+        self.text.clear();
+        self.pairs.clear();
+        self.strings.clear();
+        self.generation += 1;
+    }
+
+    /// Gets a connection string, `None` when its id was made before the arena was
+    /// cleared or by another arena.
+    pub fn get(&self, id: ArenaId) -> Option<ArenaConnStr<'_>> {
+        match id.arena == self.id && id.generation == self.generation {
+            true => self.strings.get(id.index).map(|pairs| ArenaConnStr {
+                arena: self,
+                pairs: pairs.clone(),
+            }),
+            false => None,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ArenaConnStr<'_>> {
+        self.strings.iter().map(move |pairs| ArenaConnStr {
+            arena: self,
+            pairs: pairs.clone(),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Parses a connection string into the arena, with the same checks as the
+    /// `parse_with` of the connection strings using the keyword table.
+    ///
+    /// The keys are stored in lower case and the first occurrence of a key wins. Nothing is kept in
+    /// the arena when the parsing fails.
+    pub fn parse(
+        &mut self,
+        conn_str: &str,
+        use_odbc_rules: bool,
+        keywords: &Keywords,
+        options: &ParseOptions,
+    ) -> Result<ArenaId, Error> {
+        let text_len = self.text.len();
+        let start = self.pairs.len();
+
+        match self.push_pairs(conn_str, use_odbc_rules, keywords, options) {
+            Ok(()) => {
+                self.strings.push(start..self.pairs.len());
+                Ok(ArenaId {
+                    arena: self.id,
+                    index: self.strings.len() - 1,
+                    generation: self.generation,
+                })
+            }
+            Err(e) => {
+                self.text.truncate(text_len);
+                self.pairs.truncate(start);
+                Err(e)
+            }
+        }
+    }
+
+    fn push_pairs(
+        &mut self,
+        conn_str: &str,
+        use_odbc_rules: bool,
+        keywords: &Keywords,
+        options: &ParseOptions,
+    ) -> Result<(), Error> {
+        if let Some(limits) = &options.limits {
+            if conn_str.chars().count() > limits.max_len {
+                return Err(Error::LimitExceeded {
+                    key: None,
+                    limit: limits.max_len,
+                });
+            }
+        }
+
+        let start = self.pairs.len();
+        let mut chars = conn_str.char_indices();
+
+        self.keys.clear();
+
+        while let Some(RawPair {
+            key_len,
+            value_range: range,
+            ..
+        }) = scan_key_value(&mut chars, &mut self.buf, use_odbc_rules)?
+        {
+            if key_len == 0 {
+                break;
+            }
+
+            let (key, value) = self.buf.split_at(key_len);
+            let key_start = self.text.len();
+
+            if key.is_ascii() {
+                self.text.push_str(key);
+                self.text[key_start..].make_ascii_lowercase();
+            } else {
+                self.text.push_str(&key.to_lowercase());
+            }
+
+            let key = &self.text[key_start..];
+
+            if let Some(limits) = &options.limits {
+                if key.chars().count() > limits.max_key_len {
+                    return Err(Error::LimitExceeded {
+                        key: Some(key.to_owned()),
+                        limit: limits.max_key_len,
+                    });
+                }
+            }
+
+            if options.hardened {
+                let credential = matches!(keywords.lookup(key), Some((_, _, true)));
+                let raw = &conn_str[range.clone()];
+                let quoted = raw.starts_with(['"', '\'', '{']);
+
+                check_hardened(key, raw, range.start, credential && quoted)?;
+            }
+
+            // the strict checks and the decryption work on owned strings
+            if options.strict || options.decryptor.is_some() {
+                let (_, value) =
+                    decrypt_and_check(key.to_owned(), value.to_owned(), keywords, options)?;
+
+                let key_end = self.text.len();
+                self.text.push_str(&value);
+                self.push_pair(start, key_start..key_end)?;
+            } else {
+                let key_end = self.text.len();
+                self.text.push_str(value);
+                self.push_pair(start, key_start..key_end)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Pushes the pair whose key and value end the text, dropping it when its key is
+    /// already set since the pair `start`, the first key winning like the other
+    /// parsing methods.
+    fn push_pair(&mut self, start: usize, key: Range<usize>) -> Result<(), Error> {
+        let text = &self.text[key.clone()];
+
+        if text.starts_with(|c: char| c.is_whitespace() || c == ';') || text.contains('\0') {
+            return Err(Error::KeyNotSupported(text.to_owned()));
+        }
+
+        let hash = self.hasher.hash_one(text);
+        let duplicate = match self.keys.get(&hash) {
+            Some(&index) if self.text[self.pairs[index].0.clone()] == *text => true,
+            // a collision of the hashes, the keys are compared one by one
+            Some(_) => self.pairs[start..]
+                .iter()
+                .any(|(k, _)| self.text[k.clone()] == *text),
+            None => false,
+        };
+
+        if duplicate {
+            self.text.truncate(key.start);
+        } else {
+            self.keys.entry(hash).or_insert(self.pairs.len());
+            self.pairs.push((key.clone(), key.end..self.text.len()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats the number of connection strings only, the values being possibly secrets.
+impl fmt::Debug for Arena {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Arena").field("len", &self.len()).finish()
+    }
+}
+
+/// A borrowed view of a connection string stored in an [`Arena`].
+#[derive(Clone)]
+pub struct ArenaConnStr<'a> {
+    arena: &'a Arena,
+    pairs: Range<usize>,
+}
+
+impl<'a> ArenaConnStr<'a> {
+    /// Gets the value of a key, ignoring case.
+    pub fn get(&self, key: &str) -> Option<&'a str> {
+        let key = key.to_lowercase();
+        self.iter().find(|(k, _)| *k == key).map(|(_, v)| v)
+    }
+
+    /// Iterates over the lower case keys and the values, in order of appearance.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, &'a str)> {
+        let arena = self.arena;
+
+        arena.pairs[self.pairs.clone()]
+            .iter()
+            .map(move |(k, v)| (&arena.text[k.clone()], &arena.text[v.clone()]))
+    }
+
+    /// Gets the value of a keyword from a keyword table, looking up its synonyms.
+    pub fn keyword(&self, keywords: &Keywords, name: &str) -> Option<&'a str> {
+        keywords
+            .get(name)
+            .and_then(|k| k.names().find_map(|n| self.get(n)))
+    }
+}
+
+/// Formats the keys only, the values being possibly secrets.
+impl<'a> fmt::Debug for ArenaConnStr<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map()
+            .entries(self.iter().map(|(k, _)| (k, "***")))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+use crate::keywords::MS_SQL;
+
+#[test]
+fn arena_rolls_back_on_error() {
+    let options = ParseOptions::default();
+    let mut arena = Arena::new();
+    let a = arena
+        .parse("a=1;A=2;b=\"x;y\"", false, &MS_SQL, &options)
+        .unwrap();
+
+    assert!(arena
+        .parse("c=3;d=\"unterminated", false, &MS_SQL, &options)
+        .is_err());
+    assert_eq!(1, arena.len());
+    assert_eq!(
+        vec![("a", "1"), ("b", "x;y")],
+        arena.get(a).unwrap().iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn arena_applies_the_parse_options() {
+    let mut arena = Arena::new();
+
+    let strict = ParseOptions {
+        strict: true,
+        ..Default::default()
+    };
+    let a = arena
+        .parse(
+            "Server=.;data source=x;Pwd=s;SERVER=y",
+            false,
+            &MS_SQL,
+            &strict,
+        )
+        .unwrap();
+
+    assert_eq!(
+        vec![("server", "."), ("data source", "x"), ("pwd", "s")],
+        arena.get(a).unwrap().iter().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        Some("x"),
+        arena.get(a).unwrap().keyword(&MS_SQL, "data source")
+    );
+    assert!(arena
+        .parse("server=.;databse=Db1", false, &MS_SQL, &strict)
+        .is_err());
+
+    let hardened = ParseOptions {
+        hardened: true,
+        ..Default::default()
+    };
+    match arena.parse("uid=\"a;pwd=b\"", false, &MS_SQL, &hardened) {
+        Err(Error::SuspiciousValue { key, index }) => {
+            assert_eq!("uid", key);
+            assert_eq!(6, index);
+        }
+        _ => panic!("the nested key should be rejected"),
+    }
+
+    let limits = ParseOptions {
+        limits: Some(crate::Limits {
+            max_len: 20,
+            max_key_len: 4,
+        }),
+        ..Default::default()
+    };
+    assert!(arena.parse("server=.", false, &MS_SQL, &limits).is_err());
+    assert!(arena
+        .parse("uid=a;pwd=b;app=012345678", false, &MS_SQL, &limits)
+        .is_err());
+
+    assert_eq!(1, arena.len());
+    // the duplicate key and the failed strings are not kept in the text
+    assert_eq!("server.data sourcexpwds", arena.text);
+}
+
+#[test]
+fn ids_are_invalidated_by_clear() {
+    let options = ParseOptions::default();
+    let mut arena = Arena::new();
+    let a = arena.parse("a=1", false, &MS_SQL, &options).unwrap();
+
+    arena.clear();
+    let b = arena.parse("b=2", false, &MS_SQL, &options).unwrap();
+
+    assert!(arena.get(a).is_none());
+    assert_eq!(Some("2"), arena.get(b).unwrap().get("b"));
+    assert!(Arena::new().get(b).is_none());
+
+    let mut other = Arena::new();
+    other.parse("c=3", false, &MS_SQL, &options).unwrap();
+    other.clear();
+    other.parse("pwd=secret", false, &MS_SQL, &options).unwrap();
+
+    assert!(other.get(b).is_none());
+    assert_eq!(r#"{"b": "***"}"#, format!("{:?}", arena.get(b).unwrap()));
+}
@@ -0,0 +1,151 @@
+//! SAP ASE connection strings, as parsed by the ADO.NET provider of Sybase, AseClient.
+
+use crate::host::Host;
+use crate::keywords;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{
+    decode_bytes, get, parse, parse_bool, parse_port, to_connection_string, Error, ParseOptions,
+};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// An AseClient connection string.
+///
+/// Unlike the data source of SQL Server, the `Data Source` holds the host alone, the
+/// port being given by the `Port` key.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::ase::AseConnStr;
+/// use conn_str::host::Host;
+/// use std::str::FromStr;
+///
+/// let conn = AseConnStr::from_str("Data Source=db1;Port=5000;Database=sales;Uid=sa;Pwd=x;Charset=iso_1").unwrap();
+///
+/// assert_eq!(Some("db1"), conn.data_source());
+/// assert_eq!(Some(5000), conn.port().unwrap());
+/// assert_eq!(Some(Host::new("db1", Some(5000))), conn.host().unwrap());
+/// assert_eq!(Some("sales"), conn.database());
+/// assert_eq!(Some("sa"), conn.uid());
+/// assert_eq!(Some("iso_1"), conn.charset());
+/// assert_eq!("charset=iso_1;data source=db1;database=sales;port=5000;pwd=***;uid=sa", conn.to_string());
+/// ```
+#[derive(Clone)]
+pub struct AseConnStr(Arc<HashMap<String, String>>);
+
+impl FromStr for AseConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl AseConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        Ok(AseConnStr(Arc::new(parse(
+            conn_str,
+            false,
+            &keywords::ASE,
+            options,
+        )?)))
+    }
+
+    pub fn charset(&self) -> Option<&str> {
+        self.get("charset")
+    }
+
+    /// Gets the host of the server, without its port.
+    pub fn data_source(&self) -> Option<&str> {
+        self.get("data source")
+    }
+
+    pub fn database(&self) -> Option<&str> {
+        self.get("database")
+    }
+
+    /// Gets the host of the data source with the `Port`, `None` without data source.
+    pub fn host(&self) -> Result<Option<Host>, Error> {
+        match self.data_source() {
+            Some(name) => Ok(Some(Host::new(name.trim(), self.port()?))),
+            None => Ok(None),
+        }
+    }
+
+    #[cfg(not(feature = "secret"))]
+    pub fn password(&self) -> Option<&str> {
+        self.get("pwd")
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("pwd").map(crate::secret::Secret::new)
+    }
+
+    pub fn pooling(&self) -> Result<bool, Error> {
+        match self.get("pooling") {
+            Some(v) => parse_bool(v),
+            None => Ok(true),
+        }
+    }
+
+    /// Gets the port, `None` for the default port 5000.
+    pub fn port(&self) -> Result<Option<u16>, Error> {
+        match self.get("port") {
+            Some(port) => parse_port("port", port).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.0, &keywords::ASE, false, false)
+    }
+
+    /// Gets the login, `Uid` or one of its synonyms such as `User ID`.
+    pub fn uid(&self) -> Option<&str> {
+        self.get("uid")
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        get(&self.0, &keywords::ASE, name)
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.0, &keywords::ASE, validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for AseConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(&self.0, &keywords::ASE, false, true))
+    }
+}
+
+#[test]
+fn port_is_kept_apart_from_the_data_source() {
+    let conn = AseConnStr::from_str("Server=db1;User ID=sa;Password=x").unwrap();
+
+    assert_eq!(Some(Host::new("db1", None)), conn.host().unwrap());
+    assert_eq!(Some("sa"), conn.uid());
+
+    let conn = AseConnStr::from_str("Data Source=db1;Server Port=0").unwrap();
+    assert!(conn.host().is_err());
+}
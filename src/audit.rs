@@ -0,0 +1,85 @@
+//! Security audit of connection strings.
+//!
+//! The audit flags insecure configurations. Each finding is reported as a
+//! [`ValidationIssue`] with a stable rule id, so deployments can be gated on them.
+
+use crate::validate::{Severity, ValidationIssue};
+use crate::{Authentication, MsSqlConnStr};
+
+/// Rule reporting a connection explicitly not encrypted.
+pub const ENCRYPT_DISABLED: &str = "encrypt-disabled";
+
+/// Rule reporting a connection relying on the default encryption of the driver.
+pub const ENCRYPT_NOT_SET: &str = "encrypt-not-set";
+
+/// Rule reporting a server certificate accepted without validation.
+pub const TRUST_SERVER_CERTIFICATE: &str = "trust-server-certificate";
+
+/// Rule reporting a password kept in the connection after it is opened.
+pub const PERSIST_SECURITY_INFO: &str = "persist-security-info";
+
+/// Rule reporting a login with a user id and a password instead of an integrated
+/// or Azure Active Directory authentication.
+pub const SQL_AUTHENTICATION: &str = "sql-authentication";
+
+pub(crate) fn ms_sql(conn: &MsSqlConnStr) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+
+    match conn.get("encrypt") {
+        Some(_) if !conn.encrypt().unwrap_or(true) => issues.push(ValidationIssue::new(
+            ENCRYPT_DISABLED,
+            Severity::Error,
+            &["encrypt"],
+            "the connection is not encrypted".to_owned(),
+        )),
+        Some(_) => {}
+        None => issues.push(ValidationIssue::new(
+            ENCRYPT_NOT_SET,
+            Severity::Warning,
+            &["encrypt"],
+            "the encryption depends on the default of the driver".to_owned(),
+        )),
+    }
+
+    if conn.trust_server_certificate().unwrap_or(false) {
+        issues.push(ValidationIssue::new(
+            TRUST_SERVER_CERTIFICATE,
+            Severity::Error,
+            &["trust server certificate"],
+            "the server certificate is not validated".to_owned(),
+        ));
+    }
+
+    let persist_security_info = matches!(
+        conn.get("persist security info").map(crate::parse_bool),
+        Some(Ok(true))
+    );
+
+    if persist_security_info && conn.password().is_some() {
+        issues.push(ValidationIssue::new(
+            PERSIST_SECURITY_INFO,
+            Severity::Error,
+            &["persist security info", "password"],
+            "the password remains readable from the opened connection".to_owned(),
+        ));
+    }
+
+    let sql_authentication = matches!(
+        conn.authentication(),
+        Ok(None | Some(Authentication::SqlPassword))
+    );
+
+    if conn.password().is_some()
+        && sql_authentication
+        && !conn.integrated_security().unwrap_or(false)
+    {
+        issues.push(ValidationIssue::new(
+            SQL_AUTHENTICATION,
+            Severity::Info,
+            &["user id", "password"],
+            "SQL Server authentication is used instead of an integrated or Azure Active Directory authentication".to_owned(),
+        ));
+    }
+
+    issues
+}
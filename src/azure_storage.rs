@@ -0,0 +1,276 @@
+//! Azure Storage connection strings, as parsed by the Azure Storage client libraries.
+
+use crate::keywords;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{decode_bytes, get, parse, parse_bool, to_connection_string, Error, ParseOptions};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// The name of the well-known account of the storage emulator.
+pub const DEVELOPMENT_ACCOUNT_NAME: &str = "devstoreaccount1";
+
+/// The key of the well-known account of the storage emulator, which is public.
+pub const DEVELOPMENT_ACCOUNT_KEY: &str =
+    "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==";
+
+/// An Azure Storage connection string.
+///
+/// The endpoints of the services are built from the `AccountName`, the
+/// `DefaultEndpointsProtocol` and the `EndpointSuffix`, unless given explicitly by
+/// the `BlobEndpoint`, the `QueueEndpoint` or the `TableEndpoint`.
+/// `UseDevelopmentStorage=true` stands for the well-known account of the local
+/// storage emulator, Azurite.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::azure_storage::AzureStorageConnStr;
+/// use std::str::FromStr;
+///
+/// let conn = AzureStorageConnStr::from_str("DefaultEndpointsProtocol=https;AccountName=acct;AccountKey=base64==;EndpointSuffix=core.windows.net").unwrap();
+///
+/// assert_eq!(Some("acct"), conn.account_name().unwrap());
+/// assert_eq!(
+///     Some("https://acct.blob.core.windows.net".to_owned()),
+///     conn.blob_endpoint().unwrap(),
+/// );
+/// assert_eq!(
+///     "accountkey=***;accountname=acct;defaultendpointsprotocol=https;endpointsuffix=core.windows.net",
+///     conn.to_string(),
+/// );
+///
+/// let conn = AzureStorageConnStr::from_str("UseDevelopmentStorage=true").unwrap();
+///
+/// assert_eq!(Some("devstoreaccount1"), conn.account_name().unwrap());
+/// assert_eq!(
+///     Some("http://127.0.0.1:10001/devstoreaccount1".to_owned()),
+///     conn.queue_endpoint().unwrap(),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct AzureStorageConnStr(Arc<HashMap<String, String>>);
+
+impl FromStr for AzureStorageConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl AzureStorageConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        Ok(AzureStorageConnStr(Arc::new(parse(
+            conn_str,
+            false,
+            &keywords::AZURE_STORAGE,
+            options,
+        )?)))
+    }
+
+    /// Gets the shared key of the account, the well-known key with the development
+    /// storage.
+    #[cfg(not(feature = "secret"))]
+    pub fn account_key(&self) -> Result<Option<&str>, Error> {
+        self.account_key_value()
+    }
+
+    /// Gets the shared key of the account, the well-known key with the development
+    /// storage.
+    #[cfg(feature = "secret")]
+    pub fn account_key(&self) -> Result<Option<crate::secret::Secret<'_>>, Error> {
+        Ok(self.account_key_value()?.map(crate::secret::Secret::new))
+    }
+
+    /// Gets the name of the account, the well-known account with the development
+    /// storage.
+    pub fn account_name(&self) -> Result<Option<&str>, Error> {
+        match self.use_development_storage()? {
+            true => Ok(Some(DEVELOPMENT_ACCOUNT_NAME)),
+            false => Ok(self.get("accountname")),
+        }
+    }
+
+    /// Gets the url of the blob service.
+    pub fn blob_endpoint(&self) -> Result<Option<String>, Error> {
+        self.endpoint("blobendpoint", "blob", 10000)
+    }
+
+    /// Gets the domain of the endpoints, `core.windows.net` when missing.
+    pub fn endpoint_suffix(&self) -> &str {
+        match self.get("endpointsuffix").map(str::trim) {
+            Some(suffix) if !suffix.is_empty() => suffix,
+            _ => "core.windows.net",
+        }
+    }
+
+    /// Gets the scheme of the endpoints, `https` when missing or `http` with the
+    /// development storage.
+    pub fn protocol(&self) -> Result<&str, Error> {
+        match self.get("defaultendpointsprotocol").map(str::trim) {
+            Some(p) if p.eq_ignore_ascii_case("http") => Ok("http"),
+            Some(p) if p.eq_ignore_ascii_case("https") => Ok("https"),
+            Some(p) => Err(Error::InvalidValue {
+                key: "defaultendpointsprotocol".to_owned(),
+                value: p.to_owned(),
+                expected: keywords::AZURE_STORAGE
+                    .get("defaultendpointsprotocol")
+                    .unwrap()
+                    .kind
+                    .to_string(),
+            }),
+            None if self.use_development_storage()? => Ok("http"),
+            None => Ok("https"),
+        }
+    }
+
+    /// Gets the url of the queue service.
+    pub fn queue_endpoint(&self) -> Result<Option<String>, Error> {
+        self.endpoint("queueendpoint", "queue", 10001)
+    }
+
+    /// Gets the shared access signature used instead of the account key.
+    #[cfg(not(feature = "secret"))]
+    pub fn shared_access_signature(&self) -> Option<&str> {
+        self.get("sharedaccesssignature")
+    }
+
+    /// Gets the shared access signature used instead of the account key.
+    #[cfg(feature = "secret")]
+    pub fn shared_access_signature(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("sharedaccesssignature")
+            .map(crate::secret::Secret::new)
+    }
+
+    /// Gets the url of the table service.
+    pub fn table_endpoint(&self) -> Result<Option<String>, Error> {
+        self.endpoint("tableendpoint", "table", 10002)
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.0, &keywords::AZURE_STORAGE, false, false)
+    }
+
+    /// Indicates if the connection targets the local storage emulator,
+    /// `UseDevelopmentStorage=true`.
+    pub fn use_development_storage(&self) -> Result<bool, Error> {
+        match self.get("usedevelopmentstorage") {
+            Some(v) => parse_bool(v.trim()),
+            None => Ok(false),
+        }
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        get(&self.0, &keywords::AZURE_STORAGE, name)
+    }
+
+    fn account_key_value(&self) -> Result<Option<&str>, Error> {
+        match self.use_development_storage()? {
+            true => Ok(Some(DEVELOPMENT_ACCOUNT_KEY)),
+            false => Ok(self.get("accountkey")),
+        }
+    }
+
+    /// Gets the explicit endpoint of a service, or builds it from the account, the
+    /// emulator listening on `dev_port` for the service.
+    fn endpoint(&self, key: &str, service: &str, dev_port: u16) -> Result<Option<String>, Error> {
+        if let Some(endpoint) = self.get(key).map(str::trim).filter(|e| !e.is_empty()) {
+            return Ok(Some(endpoint.trim_end_matches('/').to_owned()));
+        }
+
+        if self.use_development_storage()? {
+            let proxy = self
+                .get("developmentstorageproxyuri")
+                .map(|uri| uri.trim().trim_end_matches('/'))
+                .filter(|uri| !uri.is_empty())
+                .unwrap_or("http://127.0.0.1");
+
+            return Ok(Some(format!(
+                "{}:{}/{}",
+                proxy, dev_port, DEVELOPMENT_ACCOUNT_NAME
+            )));
+        }
+
+        Ok(match self.get("accountname").map(str::trim) {
+            Some(account) if !account.is_empty() => Some(format!(
+                "{}://{}.{}.{}",
+                self.protocol()?,
+                account,
+                service,
+                self.endpoint_suffix()
+            )),
+            _ => None,
+        })
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.0, &keywords::AZURE_STORAGE, validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for AzureStorageConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(
+            &self.0,
+            &keywords::AZURE_STORAGE,
+            false,
+            true,
+        ))
+    }
+}
+
+#[test]
+fn explicit_endpoints_replace_the_account_ones() {
+    let conn = AzureStorageConnStr::from_str(
+        "BlobEndpoint=https://cdn.example.com/;SharedAccessSignature=sv=2021&sig=x;AccountName=acct;DefaultEndpointsProtocol=http;EndpointSuffix=core.chinacloudapi.cn",
+    )
+    .unwrap();
+
+    assert_eq!(
+        Some("https://cdn.example.com".to_owned()),
+        conn.blob_endpoint().unwrap()
+    );
+    assert_eq!(
+        Some("http://acct.table.core.chinacloudapi.cn".to_owned()),
+        conn.table_endpoint().unwrap()
+    );
+    assert!(conn.account_key().unwrap().is_none());
+    assert!(conn.shared_access_signature().is_some());
+
+    let conn = AzureStorageConnStr::from_str(
+        "UseDevelopmentStorage=true;DevelopmentStorageProxyUri=http://ipv4.fiddler",
+    )
+    .unwrap();
+    assert_eq!(
+        Some("http://ipv4.fiddler:10000/devstoreaccount1".to_owned()),
+        conn.blob_endpoint().unwrap()
+    );
+    assert!(conn.account_key().unwrap().is_some());
+
+    let conn = AzureStorageConnStr::from_str("AccountName=a;DefaultEndpointsProtocol=ftp").unwrap();
+    assert!(conn.queue_endpoint().is_err());
+    assert_eq!(
+        None,
+        AzureStorageConnStr::from_str("AccountKey=k")
+            .unwrap()
+            .blob_endpoint()
+            .unwrap()
+    );
+}
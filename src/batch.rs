@@ -0,0 +1,299 @@
+//! Batch parsing of large inventories of connection strings.
+
+use crate::{decode_bytes, AnyConnStr, Error, ParseOptions, Provider};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// The result of parsing many connection strings.
+#[derive(Debug)]
+pub struct Batch {
+    /// The result of every non blank line, by order of appearance.
+    pub lines: Vec<Line>,
+
+    pub stats: BatchStats,
+}
+
+/// The result of parsing a single line.
+pub struct Line {
+    /// The line number, starting at 1.
+    pub number: usize,
+
+    pub result: Result<AnyConnStr, Error>,
+}
+
+/// Aggregate statistics of a [`Batch`].
+#[derive(Clone, Debug, Default)]
+pub struct BatchStats {
+    /// The number of lines parsed, blank lines excluded.
+    pub parsed: usize,
+
+    /// The number of connection strings parsed successfully, by provider.
+    pub providers: HashMap<Provider, usize>,
+
+    /// The numbers of the lines that failed to parse.
+    pub error_lines: Vec<usize>,
+}
+
+/// Formats the keys of the connection strings only, the values being possibly
+/// secrets.
+impl fmt::Debug for Line {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Line")
+            .field("number", &self.number)
+            .field("result", &self.result.as_ref().map(Keys))
+            .finish()
+    }
+}
+
+/// Formats the keys of a connection string, masking their values.
+struct Keys<'a>(&'a AnyConnStr);
+
+impl<'a> fmt::Debug for Keys<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map()
+            .entries(self.0.pairs(false).into_iter().map(|(k, _)| (k, "***")))
+            .finish()
+    }
+}
+
+impl Batch {
+    fn from_lines(lines: Vec<Line>) -> Self {
+        let mut stats = BatchStats {
+            parsed: lines.len(),
+            ..Default::default()
+        };
+
+        for line in &lines {
+            match &line.result {
+                Ok(conn) => *stats.providers.entry(conn.provider()).or_insert(0) += 1,
+                Err(_) => stats.error_lines.push(line.number),
+            }
+        }
+
+        Batch { lines, stats }
+    }
+}
+
+/// Parses one connection string per line, detecting the format of each one.
+///
+/// Blank lines are skipped but still counted in the line numbers, like the comment
+/// lines when [`ParseOptions::comments`] is set.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::batch::parse_many;
+/// use conn_str::{ParseOptions, Provider};
+///
+/// let lines = "server=.;database=Db1\n\nprovider connection string='server=.'\nserver='.";
+/// let batch = parse_many(lines.lines(), &ParseOptions::default());
+///
+/// assert_eq!(3, batch.stats.parsed);
+/// assert_eq!(Some(&1), batch.stats.providers.get(&Provider::MsSql));
+/// assert_eq!(Some(&1), batch.stats.providers.get(&Provider::EntityFramework));
+/// assert_eq!(vec![4], batch.stats.error_lines);
+/// ```
+pub fn parse_many<'a, I>(lines: I, options: &ParseOptions) -> Batch
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    Batch::from_lines(
+        lines
+            .into_iter()
+            .map(|s| strip_comment(s, options))
+            .enumerate()
+            .filter(|(_, s)| !s.trim().is_empty())
+            .map(|(i, s)| Line {
+                number: i + 1,
+                result: AnyConnStr::parse_with(s, options),
+            })
+            .collect(),
+    )
+}
+
+/// Strips a comment line or a trailing comment, starting with `#` or `//`, when the
+/// comments are enabled.
+///
+/// A trailing comment must be preceded by whitespace and outside of a quoted value. A
+/// value is quoted only when the quote is its first non blank character, so that an
+/// apostrophe inside an unquoted value does not hide the comment.
+pub(crate) fn strip_comment<'a>(line: &'a str, options: &ParseOptions) -> &'a str {
+    if !options.comments {
+        return line;
+    }
+
+    let mut quote = None;
+    let mut value_start = false;
+    let mut previous = None;
+    let mut chars = line.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        match quote {
+            Some(q) if c == q => {
+                // a doubled quote is an escaped quote inside the value
+                if chars.peek().is_some_and(|&(_, n)| n == q) {
+                    chars.next();
+                } else {
+                    quote = None;
+                }
+            }
+            Some(_) => {}
+            None if value_start && (c == '"' || c == '\'') => quote = Some(c),
+            None => {
+                let rest = &line[index..];
+                let starts_comment = rest.starts_with('#') || rest.starts_with("//");
+
+                if starts_comment && previous.is_none_or(char::is_whitespace) {
+                    return &line[..index];
+                }
+            }
+        }
+
+        if c == '=' && quote.is_none() {
+            value_start = true;
+        } else if !c.is_whitespace() {
+            value_start = false;
+        }
+
+        previous = Some(c);
+    }
+
+    line
+}
+
+/// Reads a text file containing one connection string per line, like [`parse_many`].
+///
+/// The file is decoded with [`decode_bytes`], following its byte order mark. The
+/// errors of the lines carry their line number.
+///
+/// # Example
+///
+/// ```no_run
+/// use conn_str::batch::read_file;
+/// use conn_str::ParseOptions;
+///
+/// let batch = read_file("export.txt", &ParseOptions::default()).unwrap();
+///
+/// for line in &batch.lines {
+///     if let Err(e) = &line.result {
+///         eprintln!("{}", e);
+///     }
+/// }
+/// ```
+pub fn read_file<P: AsRef<Path>>(path: P, options: &ParseOptions) -> Result<Batch, Error> {
+    let path = path.as_ref();
+    let bytes = fs::read(path).map_err(|e| Error::Unreadable {
+        path: path.display().to_string(),
+        message: e.to_string(),
+    })?;
+    let text = decode_bytes(&bytes)?;
+
+    let mut batch = parse_many(text.lines(), options);
+
+    for line in &mut batch.lines {
+        if let Err(error) = &mut line.result {
+            *error = Error::AtLine {
+                line: line.number,
+                error: Box::new(error.clone()),
+            };
+        }
+    }
+
+    Ok(batch)
+}
+
+/// Parses one connection string per line in parallel, like [`parse_many`].
+#[cfg(feature = "rayon")]
+pub fn par_parse_many(lines: &[&str], options: &ParseOptions) -> Batch {
+    use rayon::prelude::*;
+
+    Batch::from_lines(
+        lines
+            .par_iter()
+            .map(|s| strip_comment(s, options))
+            .enumerate()
+            .filter(|(_, s)| !s.trim().is_empty())
+            .map(|(i, s)| Line {
+                number: i + 1,
+                result: AnyConnStr::parse_with(s, options),
+            })
+            .collect(),
+    )
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_parse_many_keeps_line_order() {
+    let lines = vec!["server=a", "", "server='b", "server=c"];
+    let batch = par_parse_many(&lines, &ParseOptions::default());
+
+    assert_eq!(
+        vec![1, 3, 4],
+        batch.lines.iter().map(|l| l.number).collect::<Vec<_>>()
+    );
+    assert_eq!(vec![3], batch.stats.error_lines);
+}
+
+#[test]
+fn read_file_reports_the_line_of_the_errors() {
+    let path = std::env::temp_dir().join("conn_str_read_file.txt");
+    fs::write(&path, "server=a\r\n\r\nserver='b\r\n").unwrap();
+
+    let batch = read_file(&path, &ParseOptions::default()).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(2, batch.stats.parsed);
+    match &batch.lines[1].result {
+        Err(Error::AtLine { line: 3, .. }) => {}
+        _ => panic!("the third line should fail"),
+    }
+}
+
+#[test]
+fn debug_masks_the_values() {
+    let batch = parse_many(["server=.;pwd=secret"], &ParseOptions::default());
+
+    assert_eq!(
+        r#"Line { number: 1, result: Ok({"pwd": "***", "server": "***"}) }"#,
+        format!("{:?}", batch.lines[0])
+    );
+    assert!(!format!("{:?}", batch).contains("secret"));
+}
+
+#[test]
+fn comments_are_stripped_outside_quoted_values() {
+    let options = ParseOptions {
+        comments: true,
+        ..Default::default()
+    };
+
+    assert_eq!("  ", strip_comment("  # a comment", &options));
+    assert_eq!("server=a ", strip_comment("server=a // primary", &options));
+    assert_eq!(
+        "pwd=a#b;x='c #d' ",
+        strip_comment("pwd=a#b;x='c #d' #e", &options)
+    );
+    assert_eq!(
+        "a=1 # b",
+        strip_comment("a=1 # b", &ParseOptions::default())
+    );
+}
+
+#[test]
+fn apostrophes_in_unquoted_values_do_not_hide_comments() {
+    let options = ParseOptions {
+        comments: true,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        "user=O'Brien ",
+        strip_comment("user=O'Brien # note", &options)
+    );
+    assert_eq!(
+        "pwd= 'it''s #1' ",
+        strip_comment("pwd= 'it''s #1' # note", &options)
+    );
+}
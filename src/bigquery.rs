@@ -0,0 +1,221 @@
+//! Google BigQuery connection strings, as passed to the Simba ODBC driver.
+
+use crate::keywords;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{decode_bytes, get, parse, to_connection_string, Error, ParseOptions};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// The `OAuthMechanism` of the Simba BigQuery driver.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum OAuthMechanism {
+    /// `0`, a service account, with the `Email` and the `KeyFilePath`.
+    ServiceAccount,
+
+    /// `1`, a user, with the `RefreshToken` of a prior sign-in.
+    User,
+
+    /// `2`, a pre-generated access token.
+    AccessToken,
+
+    /// `3`, the application default credentials of the environment.
+    ApplicationDefault,
+
+    /// `4`, an external account of a workload identity federation.
+    ExternalAccount,
+}
+
+impl FromStr for OAuthMechanism {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "0" => Ok(OAuthMechanism::ServiceAccount),
+            "1" => Ok(OAuthMechanism::User),
+            "2" => Ok(OAuthMechanism::AccessToken),
+            "3" => Ok(OAuthMechanism::ApplicationDefault),
+            "4" => Ok(OAuthMechanism::ExternalAccount),
+            _ => Err(Error::InvalidValue {
+                key: "oauthmechanism".to_owned(),
+                value: s.to_owned(),
+                expected: keywords::BIGQUERY
+                    .get("oauthmechanism")
+                    .unwrap()
+                    .kind
+                    .to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for OAuthMechanism {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            OAuthMechanism::ServiceAccount => "0",
+            OAuthMechanism::User => "1",
+            OAuthMechanism::AccessToken => "2",
+            OAuthMechanism::ApplicationDefault => "3",
+            OAuthMechanism::ExternalAccount => "4",
+        })
+    }
+}
+
+/// A connection string of the Simba ODBC driver for Google BigQuery, parsed with the
+/// ODBC rules where a value is quoted with braces.
+///
+/// The `Catalog` is the project billed for the queries.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::bigquery::{BigQueryConnStr, OAuthMechanism};
+/// use std::path::Path;
+/// use std::str::FromStr;
+///
+/// let conn = BigQueryConnStr::from_str("Driver={Simba ODBC Driver for Google BigQuery};OAuthMechanism=0;Catalog=project;KeyFilePath=sa.json;Email=svc@proj.iam.gserviceaccount.com").unwrap();
+///
+/// assert_eq!(Some(OAuthMechanism::ServiceAccount), conn.oauth_mechanism().unwrap());
+/// assert_eq!(Some("project"), conn.catalog());
+/// assert_eq!(Some(Path::new("sa.json")), conn.key_file_path());
+/// assert_eq!(Some("svc@proj.iam.gserviceaccount.com"), conn.email());
+/// ```
+#[derive(Clone)]
+pub struct BigQueryConnStr(Arc<HashMap<String, String>>);
+
+impl FromStr for BigQueryConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl BigQueryConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        Ok(BigQueryConnStr(Arc::new(parse(
+            conn_str,
+            true,
+            &keywords::BIGQUERY,
+            options,
+        )?)))
+    }
+
+    /// Gets the projects queried besides the `Catalog`, empty when missing.
+    pub fn additional_projects(&self) -> Vec<&str> {
+        match self.get("additionalprojects") {
+            Some(projects) => projects
+                .split(',')
+                .map(str::trim)
+                .filter(|p| !p.is_empty())
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Gets the project billed for the queries.
+    pub fn catalog(&self) -> Option<&str> {
+        self.get("catalog")
+    }
+
+    /// Gets the dataset of the unqualified table names.
+    pub fn default_dataset(&self) -> Option<&str> {
+        self.get("defaultdataset")
+    }
+
+    /// Gets the email of the service account.
+    pub fn email(&self) -> Option<&str> {
+        self.get("email")
+    }
+
+    /// Gets the path of the JSON or P12 key file of the service account.
+    pub fn key_file_path(&self) -> Option<&Path> {
+        self.get("keyfilepath")
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .map(Path::new)
+    }
+
+    /// Gets the authentication, `None` when missing since the driver requires it.
+    pub fn oauth_mechanism(&self) -> Result<Option<OAuthMechanism>, Error> {
+        self.get("oauthmechanism").map(str::parse).transpose()
+    }
+
+    /// Gets the refresh token of the user authentication.
+    #[cfg(not(feature = "secret"))]
+    pub fn refresh_token(&self) -> Option<&str> {
+        self.get("refreshtoken")
+    }
+
+    /// Gets the refresh token of the user authentication.
+    #[cfg(feature = "secret")]
+    pub fn refresh_token(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("refreshtoken").map(crate::secret::Secret::new)
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.0, &keywords::BIGQUERY, true, false)
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        get(&self.0, &keywords::BIGQUERY, name)
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.0, &keywords::BIGQUERY, validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for BigQueryConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(
+            &self.0,
+            &keywords::BIGQUERY,
+            true,
+            true,
+        ))
+    }
+}
+
+#[test]
+fn user_authentication_redacts_the_refresh_token() {
+    let conn = BigQueryConnStr::from_str(
+        "DSN=bq;OAuthMechanism=1;RefreshToken={1//a;b};AdditionalProjects=p1, p2;KeyFilePath=",
+    )
+    .unwrap();
+
+    assert_eq!(Some(OAuthMechanism::User), conn.oauth_mechanism().unwrap());
+    assert_eq!(vec!["p1", "p2"], conn.additional_projects());
+    assert_eq!(None, conn.key_file_path());
+    assert_eq!(
+        "additionalprojects=p1, p2;dsn=bq;keyfilepath=;oauthmechanism=1;refreshtoken=***",
+        conn.to_string()
+    );
+
+    let conn = BigQueryConnStr::from_str("Catalog=p;OAuthMechanism=5").unwrap();
+    assert!(conn.oauth_mechanism().is_err());
+    assert_eq!(
+        None,
+        BigQueryConnStr::from_str("Catalog=p")
+            .unwrap()
+            .oauth_mechanism()
+            .unwrap()
+    );
+}
@@ -0,0 +1,311 @@
+//! Command line access to the parsing, redaction, validation and conversion of the
+//! connection strings.
+//!
+//! The connection string is the last argument, or the standard input when missing.
+
+use conn_str::convert::{to_jdbc, to_odbc, to_url};
+use conn_str::validate::Severity;
+use conn_str::{AnyConnStr, Error, ParseOptions};
+use std::borrow::Cow;
+use std::env;
+use std::fmt::Write;
+use std::io::{self, Read};
+use std::process;
+
+const USAGE: &str = "usage: conn-str <command> [options] [connection string]
+
+commands:
+    parse [--json] [--show-secrets]   lists the keys and their values
+    get [--show-secrets] <key>        prints the value of a key
+    redact                            prints the connection string without its secrets
+    validate                          reports the issues, failing on the errors
+    convert --to odbc|url|jdbc        converts an MS SQL connection string";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match run(&args, read_stdin) {
+        Ok(Output { text, code }) => {
+            print!("{}", text);
+            process::exit(code);
+        }
+        Err(message) => {
+            eprintln!("conn-str: {}", message);
+            process::exit(2);
+        }
+    }
+}
+
+/// The text printed by a command and its exit code.
+struct Output {
+    text: String,
+    code: i32,
+}
+
+/// Runs a command, reading the connection string with `stdin` when it is not in the
+/// arguments.
+fn run<F>(args: &[String], stdin: F) -> Result<Output, String>
+where
+    F: FnOnce() -> Result<String, String>,
+{
+    let (command, args) = args.split_first().ok_or(USAGE)?;
+
+    if matches!(command.as_str(), "-h" | "--help") {
+        return Ok(Output {
+            text: format!("{}\n", USAGE),
+            code: 0,
+        });
+    }
+
+    let mut flags = Vec::new();
+    let mut values = Vec::new();
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--to" => flags.push((
+                "--to",
+                iter.next().ok_or("`--to` requires a format")?.as_str(),
+            )),
+            "--json" | "--show-secrets" => flags.push((arg.as_str(), "")),
+            "-h" | "--help" => {
+                return Ok(Output {
+                    text: format!("{}\n", USAGE),
+                    code: 0,
+                })
+            }
+            s if s.starts_with("--") => return Err(format!("unknown option `{}`\n\n{}", s, USAGE)),
+            s => values.push(s),
+        }
+    }
+
+    let flag = |name: &str| flags.iter().find(|(n, _)| *n == name).map(|(_, v)| *v);
+
+    let (key, input) = match (command.as_str(), values.as_slice()) {
+        ("get", [key]) => (Some(*key), stdin()?),
+        ("get", [key, conn]) => (Some(*key), (*conn).to_owned()),
+        ("get", _) => return Err(USAGE.to_owned()),
+        (_, []) => (None, stdin()?),
+        (_, [conn]) => (None, (*conn).to_owned()),
+        _ => return Err(USAGE.to_owned()),
+    };
+
+    let input = input.trim();
+    let conn = detect(input)?;
+    let mut out = String::new();
+
+    match command.as_str() {
+        "parse" => {
+            let show_secrets = flag("--show-secrets").is_some();
+            let pairs = conn.pairs(!show_secrets);
+
+            if flag("--json").is_some() {
+                writeln!(out, "{}", to_json(&pairs)).unwrap();
+            } else {
+                for (key, value) in pairs {
+                    writeln!(out, "{} = {}", key, value).unwrap();
+                }
+            }
+        }
+        "get" => {
+            let key = key.unwrap_or_default();
+
+            let value = match flag("--show-secrets") {
+                Some(_) => conn.get(key).map(Cow::Borrowed),
+                None => conn.get_redacted(key),
+            };
+
+            match value {
+                Some(value) => writeln!(out, "{}", value).unwrap(),
+                None => return Ok(Output { text: out, code: 1 }),
+            }
+        }
+        "redact" => writeln!(out, "{}", conn).unwrap(),
+        "validate" => {
+            let issues = conn.validate();
+
+            for issue in &issues {
+                writeln!(out, "{}", issue).unwrap();
+            }
+
+            if issues.iter().any(|i| i.severity == Severity::Error) {
+                return Ok(Output { text: out, code: 1 });
+            }
+        }
+        "convert" => {
+            let conn = match conn {
+                AnyConnStr::MsSql(conn) => conn,
+                _ => return Err("only the MS SQL connection strings can be converted".to_owned()),
+            };
+
+            let converted = match flag("--to") {
+                Some("odbc") => to_odbc(&conn),
+                Some("url") => to_url(&conn),
+                Some("jdbc") => to_jdbc(&conn),
+                _ => return Err("`--to` expects `odbc`, `url` or `jdbc`".to_owned()),
+            };
+
+            writeln!(out, "{}", converted.map_err(describe)?).unwrap();
+        }
+        _ => return Err(format!("unknown command `{}`\n\n{}", command, USAGE)),
+    }
+
+    Ok(Output { text: out, code: 0 })
+}
+
+/// Parses the connection string, rejecting the ones in neither of the supported
+/// formats rather than guessing: the ODBC connection strings, the ones without any
+/// key known to the format and the ones with a key which cannot be a keyword, such as
+/// the rest of an ODBC value quoted with braces.
+fn detect(input: &str) -> Result<AnyConnStr, String> {
+    let conn = AnyConnStr::parse_with(input, &ParseOptions::default()).map_err(describe)?;
+    let keywords = conn.provider().keywords();
+    let pairs = conn.pairs(false);
+
+    let odbc = pairs.iter().any(|(k, _)| matches!(*k, "driver" | "dsn"));
+    let known = pairs.iter().any(|(k, _)| keywords.find(k).is_some());
+    let malformed = pairs.iter().any(|(k, _)| {
+        !k.chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, ' ' | '_' | '-' | '.'))
+    });
+
+    if odbc || !known || malformed {
+        return Err("the format of the connection string was not detected".to_owned());
+    }
+
+    Ok(conn)
+}
+
+fn to_json(pairs: &[(&str, Cow<'_, str>)]) -> String {
+    let mut out = String::from("{");
+
+    for (i, (key, value)) in pairs.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        push_json_string(&mut out, key);
+        out.push(':');
+        push_json_string(&mut out, value);
+    }
+
+    out.push('}');
+    out
+}
+
+fn push_json_string(out: &mut String, s: &str) {
+    out.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+}
+
+fn read_stdin() -> Result<String, String> {
+    let mut input = String::new();
+
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|e| format!("the standard input could not be read: {}", e))?;
+
+    Ok(input)
+}
+
+fn describe(error: Error) -> String {
+    error.to_string()
+}
+
+#[cfg(test)]
+const CONN: &str = "server=.;database=Db1;user id=me;password=x;encrypt=true";
+
+#[cfg(test)]
+fn run_args(args: &[&str]) -> Result<Output, String> {
+    let args: Vec<String> = args.iter().map(|s| (*s).to_owned()).collect();
+    run(&args, || Ok(CONN.to_owned()))
+}
+
+#[test]
+fn help_is_printed_to_the_output() {
+    for args in [&["--help"][..], &["parse", "-h"]] {
+        let out = run_args(args).unwrap();
+
+        assert_eq!(0, out.code);
+        assert!(out.text.starts_with("usage: conn-str"));
+    }
+
+    assert!(run_args(&[]).is_err());
+}
+
+#[test]
+fn parse_lists_the_redacted_pairs() {
+    let out = run_args(&["parse", CONN]).unwrap();
+    assert_eq!(
+        "database = Db1\nencrypt = true\npassword = ***\nserver = .\nuser id = me\n",
+        out.text
+    );
+
+    let out = run_args(&["parse", "--json", "--show-secrets"]).unwrap();
+    assert_eq!(
+        "{\"database\":\"Db1\",\"encrypt\":\"true\",\"password\":\"x\",\"server\":\".\",\"user id\":\"me\"}\n",
+        out.text
+    );
+}
+
+#[test]
+fn get_prints_the_value_of_a_key() {
+    assert_eq!("Db1\n", run_args(&["get", "database"]).unwrap().text);
+    assert_eq!("***\n", run_args(&["get", "pwd", CONN]).unwrap().text);
+    assert_eq!(
+        "x\n",
+        run_args(&["get", "--show-secrets", "pwd"]).unwrap().text
+    );
+
+    let out = run_args(&["get", "missing"]).unwrap();
+    assert_eq!((1, ""), (out.code, out.text.as_str()));
+}
+
+#[test]
+fn redact_hides_the_secrets() {
+    assert_eq!(
+        "database=Db1;encrypt=true;password=***;server=.;user id=me\n",
+        run_args(&["redact"]).unwrap().text
+    );
+}
+
+#[test]
+fn validate_fails_on_the_errors() {
+    let out = run_args(&["validate"]).unwrap();
+    assert_eq!((0, ""), (out.code, out.text.as_str()));
+
+    let out = run_args(&["validate", "server=.;encrypt=maybe"]).unwrap();
+    assert_eq!(1, out.code);
+    assert!(out.text.starts_with("error [invalid-value]"));
+}
+
+#[test]
+fn convert_targets_each_format() {
+    let convert = |to| run_args(&["convert", "--to", to]).unwrap().text;
+
+    assert_eq!(
+        "Driver={ODBC Driver 18 for SQL Server};Server=.;Database=Db1;UID=me;PWD=x;Encrypt=yes\n",
+        convert("odbc")
+    );
+    assert_eq!(
+        "sqlserver://me:x@.?database=Db1&encrypt=true\n",
+        convert("url")
+    );
+    assert_eq!(
+        "jdbc:sqlserver://.;databaseName=Db1;user=me;password=x;encrypt=true\n",
+        convert("jdbc")
+    );
+    assert!(run_args(&["convert", "--to", "xml"]).is_err());
+}
@@ -0,0 +1,108 @@
+//! Building of MS SQL connection strings.
+
+use crate::server::DataSource;
+use crate::{keywords, set_keyword, to_connection_string, MsSqlConnStr};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// A builder of an MS SQL connection string, the keys being stored under their
+/// canonical name.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::MsSqlConnStrBuilder;
+///
+/// let conn = MsSqlConnStrBuilder::secure_defaults()
+///     .set("server", "db.contoso.com")
+///     .set("database", "Db1")
+///     .set("encrypt", "strict")
+///     .build();
+///
+/// assert_eq!(
+///     "command timeout=30;connect timeout=15;data source=db.contoso.com;encrypt=strict;\
+///      initial catalog=Db1;persist security info=false;trust server certificate=false",
+///     conn.to_connection_string_with_secrets(),
+/// );
+/// ```
+#[derive(Clone, Default)]
+pub struct MsSqlConnStrBuilder {
+    map: HashMap<String, String>,
+}
+
+impl MsSqlConnStrBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts from the secure configuration: a mandatory encryption validating the
+    /// server certificate, the password dropped once connected and bounded timeouts.
+    ///
+    /// The encryption is `true` rather than `strict`, which older drivers reject.
+    pub fn secure_defaults() -> Self {
+        Self::new()
+            .set("encrypt", "true")
+            .set("trust server certificate", "false")
+            .set("persist security info", "false")
+            .set("connect timeout", "15")
+            .set("command timeout", "30")
+    }
+
+    /// Builds the connection string.
+    pub fn build(self) -> MsSqlConnStr {
+        MsSqlConnStr(Arc::new(self.map))
+    }
+
+    /// Sets the data source.
+    pub fn data_source(self, data_source: &DataSource) -> Self {
+        self.set("data source", &data_source.to_string())
+    }
+
+    /// Removes a key and its synonyms.
+    pub fn remove(mut self, key: &str) -> Self {
+        set_keyword(&mut self.map, &keywords::MS_SQL, key, None);
+        self
+    }
+
+    /// Sets the value of a key, replacing the value of any of its synonyms.
+    pub fn set(mut self, key: &str, value: &str) -> Self {
+        set_keyword(&mut self.map, &keywords::MS_SQL, key, Some(value));
+        self
+    }
+}
+
+/// Formats the connection string being built with its secrets redacted.
+impl fmt::Debug for MsSqlConnStrBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("MsSqlConnStrBuilder")
+            .field(&to_connection_string(
+                &self.map,
+                &keywords::MS_SQL,
+                false,
+                true,
+            ))
+            .finish()
+    }
+}
+
+impl From<MsSqlConnStr> for MsSqlConnStrBuilder {
+    fn from(conn: MsSqlConnStr) -> Self {
+        MsSqlConnStrBuilder {
+            map: Arc::try_unwrap(conn.0).unwrap_or_else(|map| (*map).clone()),
+        }
+    }
+}
+
+#[test]
+fn debug_redacts_the_secrets() {
+    let builder = MsSqlConnStrBuilder::new()
+        .set("server", ".")
+        .set("pwd", "Pass123")
+        .set("access token", "t0k");
+
+    assert_eq!(
+        r#"MsSqlConnStrBuilder("access token=***;data source=.;password=***")"#,
+        format!("{:?}", builder)
+    );
+}
@@ -0,0 +1,158 @@
+//! Cassandra connection strings, as parsed by the DataStax C# driver.
+
+use crate::host::{Host, HostList};
+use crate::keywords;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{decode_bytes, get, parse, parse_port, to_connection_string, Error, ParseOptions};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// A DataStax C# driver connection string.
+///
+/// The `Contact Points` are the nodes contacted first, the other nodes of the cluster
+/// being discovered from them.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::cassandra::CassandraConnStr;
+/// use conn_str::host::Host;
+/// use std::str::FromStr;
+///
+/// let conn = CassandraConnStr::from_str("Contact Points=h1,h2:9043;Port=9042;Default Keyspace=ks;Username=u;Password=p").unwrap();
+///
+/// assert_eq!(
+///     vec![Host::new("h1", Some(9042)), Host::new("h2", Some(9043))],
+///     conn.contact_points().unwrap(),
+/// );
+/// assert_eq!(Some("ks"), conn.default_keyspace());
+/// assert_eq!(Some("u"), conn.username());
+/// ```
+#[derive(Clone)]
+pub struct CassandraConnStr(Arc<HashMap<String, String>>);
+
+impl FromStr for CassandraConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl CassandraConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        Ok(CassandraConnStr(Arc::new(parse(
+            conn_str,
+            false,
+            &keywords::CASSANDRA,
+            options,
+        )?)))
+    }
+
+    pub fn cluster_name(&self) -> Option<&str> {
+        self.get("cluster name")
+    }
+
+    /// Gets the contact points, the ones without a port using the `Port`, empty when
+    /// missing.
+    pub fn contact_points(&self) -> Result<Vec<Host>, Error> {
+        let points = match self.get("contact points") {
+            Some(points) => points,
+            None => return Ok(Vec::new()),
+        };
+
+        let list = HostList::parse_with(points, ',')?;
+
+        Ok(list.with_default_port(self.port()?).hosts)
+    }
+
+    pub fn default_keyspace(&self) -> Option<&str> {
+        self.get("default keyspace")
+    }
+
+    #[cfg(not(feature = "secret"))]
+    pub fn password(&self) -> Option<&str> {
+        self.get("password")
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("password").map(crate::secret::Secret::new)
+    }
+
+    /// Gets the port of the contact points without one, 9042 when missing.
+    pub fn port(&self) -> Result<u16, Error> {
+        match self.get("port") {
+            Some(port) => parse_port("port", port),
+            None => Ok(9042),
+        }
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.0, &keywords::CASSANDRA, false, false)
+    }
+
+    pub fn username(&self) -> Option<&str> {
+        self.get("username")
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        get(&self.0, &keywords::CASSANDRA, name)
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.0, &keywords::CASSANDRA, validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for CassandraConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(
+            &self.0,
+            &keywords::CASSANDRA,
+            false,
+            true,
+        ))
+    }
+}
+
+#[test]
+fn contact_points_use_the_default_port() {
+    let conn = CassandraConnStr::from_str("ContactPoints= 10.0.0.1 , [::1];Keyspace=ks").unwrap();
+
+    assert_eq!(
+        vec![
+            Host::new("10.0.0.1", Some(9042)),
+            Host::new("::1", Some(9042))
+        ],
+        conn.contact_points().unwrap(),
+    );
+    assert_eq!(Some("ks"), conn.default_keyspace());
+
+    assert!(CassandraConnStr::from_str("Port=9042")
+        .unwrap()
+        .contact_points()
+        .unwrap()
+        .is_empty());
+    assert!(CassandraConnStr::from_str("Contact Points=h1;Port=x")
+        .unwrap()
+        .contact_points()
+        .is_err());
+}
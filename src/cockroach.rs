@@ -0,0 +1,170 @@
+//! CockroachDB connection strings, the PostgreSQL connection strings with the options
+//! of CockroachDB.
+
+use crate::pg::{PgConnStr, SslMode};
+use crate::validate::{ValidationIssue, Validator};
+use crate::{decode_bytes, Error, ParseOptions};
+use std::fmt;
+use std::str::FromStr;
+
+/// A CockroachDB connection string, in the keyword/value form of libpq or as a
+/// `postgresql://` URI.
+///
+/// The cluster of a multi-tenant deployment, such as CockroachDB Serverless, is named
+/// by `--cluster=name` in the `options`, and the client certificates are given by
+/// their paths.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::cockroach::CockroachConnStr;
+/// use conn_str::pg::SslMode;
+/// use std::str::FromStr;
+///
+/// let conn = CockroachConnStr::from_str("postgresql://me@free-tier.gcp-us-central1.cockroachlabs.cloud:26257/defaultdb?sslmode=verify-full&sslrootcert=certs/ca.crt&options=--cluster%3Dpink-dog-123").unwrap();
+///
+/// assert_eq!(Some("pink-dog-123"), conn.cluster());
+/// assert_eq!(Some("certs/ca.crt"), conn.ssl_root_cert());
+/// assert_eq!(SslMode::VerifyFull, conn.ssl_mode().unwrap());
+/// assert_eq!(Some(26257), conn.port().unwrap());
+/// assert_eq!(Some("defaultdb"), conn.dbname());
+/// ```
+#[derive(Clone)]
+pub struct CockroachConnStr(PgConnStr);
+
+impl FromStr for CockroachConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl CockroachConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        PgConnStr::parse_with(conn_str, options).map(CockroachConnStr)
+    }
+
+    /// Gets the PostgreSQL connection string.
+    pub fn as_pg(&self) -> &PgConnStr {
+        &self.0
+    }
+
+    /// Gets the name of the cluster, the `--cluster=name` of the `options`, the last
+    /// one winning.
+    pub fn cluster(&self) -> Option<&str> {
+        let options = self.options()?;
+        let mut words = options.split_whitespace();
+        let mut cluster = None;
+
+        while let Some(word) = words.next() {
+            if let Some(name) = word.strip_prefix("--cluster=") {
+                cluster = Some(name);
+            } else if word == "--cluster" {
+                cluster = words.next();
+            }
+        }
+
+        cluster.filter(|name| !name.is_empty())
+    }
+
+    pub fn dbname(&self) -> Option<&str> {
+        self.0.dbname()
+    }
+
+    /// Gets the hosts as written, a comma separated list when several nodes are tried
+    /// in turn.
+    pub fn host(&self) -> Option<&str> {
+        self.0.host()
+    }
+
+    /// Gets the command line options sent to the server, such as `--cluster=name`.
+    pub fn options(&self) -> Option<&str> {
+        self.0.get("options")
+    }
+
+    #[cfg(not(feature = "secret"))]
+    pub fn password(&self) -> Option<&str> {
+        self.0.password()
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.0.password()
+    }
+
+    /// Gets the port, `None` for the default port 26257 of CockroachDB rather than
+    /// the one of PostgreSQL.
+    pub fn port(&self) -> Result<Option<u16>, Error> {
+        self.0.port()
+    }
+
+    /// Gets the path of the client certificate, `client.<user>.crt` for the
+    /// certificate authentication.
+    pub fn ssl_cert(&self) -> Option<&str> {
+        self.0.get("sslcert")
+    }
+
+    /// Gets the path of the key of the client certificate.
+    pub fn ssl_key(&self) -> Option<&str> {
+        self.0.get("sslkey")
+    }
+
+    /// Gets the `sslmode`, `prefer` when missing.
+    pub fn ssl_mode(&self) -> Result<SslMode, Error> {
+        self.0.ssl_mode()
+    }
+
+    /// Gets the path of the certificate of the cluster authority, `ca.crt`.
+    pub fn ssl_root_cert(&self) -> Option<&str> {
+        self.0.get("sslrootcert")
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        self.0.to_connection_string_with_secrets()
+    }
+
+    pub fn user(&self) -> Option<&str> {
+        self.0.user()
+    }
+
+    /// Validates the connection string, returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        self.0.validate_with(validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for CockroachConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[test]
+fn clusters_are_read_from_the_options() {
+    let conn = CockroachConnStr::from_str(
+        "host=db1 port=26257 user=root sslcert=certs/client.root.crt sslkey=certs/client.root.key options='-c search_path=app --cluster blue-cat-7'",
+    )
+    .unwrap();
+
+    assert_eq!(Some("blue-cat-7"), conn.cluster());
+    assert_eq!(Some("certs/client.root.crt"), conn.ssl_cert());
+    assert_eq!(Some("certs/client.root.key"), conn.ssl_key());
+    assert_eq!(None, conn.ssl_root_cert());
+
+    let conn = CockroachConnStr::from_str("host=db1 options=--cluster=").unwrap();
+    assert_eq!(None, conn.cluster());
+}
@@ -0,0 +1,307 @@
+//! Databricks connection strings, as passed to the Simba Spark ODBC driver or as
+//! `jdbc:databricks://` urls.
+
+use crate::hive::AuthMech;
+use crate::host::Host;
+use crate::keywords;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{
+    decode_bytes, get, parse, parse_pairs, parse_port, to_connection_string, Error, ParseOptions,
+};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// A connection string of a Databricks SQL warehouse or cluster.
+///
+/// The ODBC connection strings are parsed with the ODBC rules where a value is quoted
+/// with braces. The JDBC urls, `jdbc:databricks://host:port/schema;key=value;...` or
+/// the older `jdbc:spark://`, are stored under the same keys.
+///
+/// A personal access token is the password of the `token` user, and is redacted
+/// like the other secrets when the connection string is displayed.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::databricks::DatabricksConnStr;
+/// use conn_str::hive::AuthMech;
+/// use std::str::FromStr;
+///
+/// let conn = DatabricksConnStr::from_str("Driver=Simba Spark;Host=adb-x.azuredatabricks.net;HTTPPath=/sql/1.0/warehouses/abc;AuthMech=3;UID=token;PWD=dapi123").unwrap();
+///
+/// assert_eq!(Some("adb-x.azuredatabricks.net"), conn.host());
+/// assert_eq!(Some("/sql/1.0/warehouses/abc"), conn.http_path());
+/// assert_eq!(AuthMech::UserNameAndPassword, conn.auth_mech().unwrap());
+/// assert!(conn.token().is_some());
+/// assert_eq!(
+///     "authmech=3;driver=Simba Spark;host=adb-x.azuredatabricks.net;httppath=/sql/1.0/warehouses/abc;pwd=***;uid=token",
+///     conn.to_string(),
+/// );
+///
+/// let conn = DatabricksConnStr::from_str("jdbc:databricks://adb-x.azuredatabricks.net:443/default;transportMode=http;ssl=1;httpPath=/sql/1.0/warehouses/abc;AuthMech=3;UID=token;PWD=dapi123").unwrap();
+///
+/// assert_eq!(Some("/sql/1.0/warehouses/abc"), conn.http_path());
+/// assert_eq!(
+///     "jdbc:databricks://adb-x.azuredatabricks.net:443/default;authmech=3;httppath=/sql/1.0/warehouses/abc;pwd=***;ssl=1;transportmode=http;uid=token",
+///     conn.to_string(),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct DatabricksConnStr {
+    jdbc: bool,
+    map: Arc<HashMap<String, String>>,
+}
+
+impl FromStr for DatabricksConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl DatabricksConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    /// Parses a `jdbc:databricks://` or `jdbc:spark://` url, or an ODBC connection
+    /// string.
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        let (jdbc, map) = match jdbc_prefix(conn_str) {
+            Some(prefix) => (
+                true,
+                parse_pairs(
+                    conn_str,
+                    jdbc_pairs(conn_str, prefix)?,
+                    &keywords::DATABRICKS,
+                    options,
+                )?,
+            ),
+            None => (
+                false,
+                parse(conn_str, true, &keywords::DATABRICKS, options)?,
+            ),
+        };
+
+        let conn = DatabricksConnStr {
+            jdbc,
+            map: Arc::new(map),
+        };
+
+        // the port is checked once parsed, so that it is never dropped from the url
+        conn.port()?;
+        Ok(conn)
+    }
+
+    /// Gets the authentication mechanism, no authentication when missing.
+    pub fn auth_mech(&self) -> Result<AuthMech, Error> {
+        match self.get("authmech") {
+            Some(v) => v.parse(),
+            None => Ok(AuthMech::NoAuthentication),
+        }
+    }
+
+    pub fn catalog(&self) -> Option<&str> {
+        self.get("catalog")
+    }
+
+    /// Gets the server hostname of the workspace.
+    pub fn host(&self) -> Option<&str> {
+        self.get("host")
+    }
+
+    /// Gets the HTTP path of the SQL warehouse or of the cluster, such as
+    /// `/sql/1.0/warehouses/<id>`.
+    pub fn http_path(&self) -> Option<&str> {
+        self.get("httppath")
+    }
+
+    /// Indicates if the connection string is a JDBC url.
+    pub fn is_jdbc(&self) -> bool {
+        self.jdbc
+    }
+
+    /// Gets the port, `None` for the default port 443.
+    pub fn port(&self) -> Result<Option<u16>, Error> {
+        match self.get("port") {
+            Some(port) => parse_port("port", port).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    pub fn schema(&self) -> Option<&str> {
+        self.get("schema")
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        self.to_string_with(false)
+    }
+
+    /// Gets the personal access token, the password of the `token` user, or the
+    /// OAuth access token.
+    #[cfg(not(feature = "secret"))]
+    pub fn token(&self) -> Option<&str> {
+        self.token_value()
+    }
+
+    /// Gets the personal access token, the password of the `token` user, or the
+    /// OAuth access token.
+    #[cfg(feature = "secret")]
+    pub fn token(&self) -> Option<crate::secret::Secret<'_>> {
+        self.token_value().map(crate::secret::Secret::new)
+    }
+
+    pub fn uid(&self) -> Option<&str> {
+        self.get("uid")
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        get(&self.map, &keywords::DATABRICKS, name)
+    }
+
+    fn token_value(&self) -> Option<&str> {
+        match self.uid().map(str::trim) {
+            Some(uid) if uid.eq_ignore_ascii_case("token") => self.get("pwd"),
+            _ => self.get("auth_accesstoken"),
+        }
+    }
+
+    fn to_string_with(&self, redact: bool) -> String {
+        if !self.jdbc {
+            return to_connection_string(&self.map, &keywords::DATABRICKS, true, redact);
+        }
+
+        let mut out = "jdbc:databricks://".to_owned();
+        let port = self.port().unwrap_or_default();
+
+        out.push_str(&Host::new(self.host().unwrap_or_default().trim(), port).to_string());
+        out.push('/');
+        out.push_str(self.schema().unwrap_or_default());
+
+        let mut params: Vec<_> = self
+            .map
+            .iter()
+            .map(|(k, v)| match keywords::DATABRICKS.find(k) {
+                Some(keyword) => (keyword.name, v, keyword.is_sensitive()),
+                None => (k.as_str(), v, false),
+            })
+            .filter(|(k, _, _)| !matches!(*k, "host" | "port" | "schema"))
+            .collect();
+
+        params.sort();
+
+        for (key, value, secret) in params {
+            out.push(';');
+            out.push_str(key);
+            out.push('=');
+
+            match secret && redact {
+                true => out.push_str("***"),
+                false => out.push_str(value),
+            }
+        }
+
+        out
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.map, &keywords::DATABRICKS, validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted, a JDBC url being
+/// formatted as a url.
+impl fmt::Display for DatabricksConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_string_with(true))
+    }
+}
+
+/// Gets the length of the `jdbc:databricks://` or `jdbc:spark://` prefix of a url,
+/// `None` for the other connection strings.
+fn jdbc_prefix(conn_str: &str) -> Option<usize> {
+    let lower = conn_str.trim_start().to_lowercase();
+
+    ["jdbc:databricks://", "jdbc:spark://"]
+        .iter()
+        .find(|p| lower.starts_with(*p))
+        .map(|p| p.len())
+}
+
+/// Splits a `jdbc:databricks://host[:port][/schema][;key=value...]` url into the keys
+/// of the [`DATABRICKS`](keywords::DATABRICKS) table.
+fn jdbc_pairs(conn_str: &str, prefix: usize) -> Result<Vec<(String, String, usize)>, Error> {
+    let start = conn_str.len() - conn_str.trim_start().len();
+    let trimmed = conn_str.trim();
+    let rest = &trimmed[prefix..];
+    let (target, props) = rest.split_once(';').unwrap_or((rest, ""));
+    let (server, schema) = target.split_once('/').unwrap_or((target, ""));
+    let host = server.parse::<Host>()?;
+    let mut pairs = vec![("host".to_owned(), host.name, start + prefix)];
+
+    if let Some(port) = host.port {
+        pairs.push(("port".to_owned(), port.to_string(), start + prefix));
+    }
+
+    if !schema.is_empty() {
+        pairs.push((
+            "schema".to_owned(),
+            schema.to_owned(),
+            start + prefix + server.len() + 1,
+        ));
+    }
+
+    let mut index = start + prefix + target.len() + 1;
+
+    for item in props.split(';') {
+        if !item.trim().is_empty() {
+            let (key, value) = item.split_once('=').ok_or(Error::SyntaxError(index))?;
+
+            pairs.push((key.trim().to_owned(), value.trim().to_owned(), index));
+        }
+
+        index += item.len() + 1;
+    }
+
+    Ok(pairs)
+}
+
+#[test]
+fn tokens_are_redacted_in_both_forms() {
+    let conn = DatabricksConnStr::from_str(
+        "jdbc:spark://adb-x.azuredatabricks.net/default;AuthMech=11;Auth_Flow=0;Auth_AccessToken=eyJ",
+    )
+    .unwrap();
+
+    assert!(conn.is_jdbc());
+    assert_eq!(AuthMech::OAuth, conn.auth_mech().unwrap());
+    assert_eq!(None, conn.port().unwrap());
+    assert_eq!(
+        "jdbc:databricks://adb-x.azuredatabricks.net/default;auth_accesstoken=***;auth_flow=0;authmech=11",
+        conn.to_string()
+    );
+
+    let conn =
+        DatabricksConnStr::from_str("Driver={Simba Spark ODBC Driver};UID=me;PWD={a;b}").unwrap();
+    assert!(conn.token().is_none());
+    assert_eq!(
+        "driver={Simba Spark ODBC Driver};pwd=***;uid=me",
+        conn.to_string()
+    );
+
+    assert!(DatabricksConnStr::from_str("jdbc:databricks://h:443/default;ssl").is_err());
+    assert!(DatabricksConnStr::from_str("jdbc:databricks://h/default;Port=90000").is_err());
+}
@@ -0,0 +1,300 @@
+//! Elasticsearch connection settings, as written for the Elastic .NET clients.
+
+use crate::keywords;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{decode_bytes, get, parse, parse_port, to_connection_string, Error, ParseOptions};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// An Elastic Cloud deployment identifier, `<name>:<base64>`, the base64 segment
+/// encoding `<host>[:<port>]$<elasticsearch id>[$<kibana id>]`.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::elasticsearch::CloudId;
+/// use std::str::FromStr;
+///
+/// let id = CloudId::from_str("my-deployment:dXMtZWFzdC0xLmF3cy5mb3VuZC5pbyRlczEyMyRrYjQ1Ng==").unwrap();
+///
+/// assert_eq!("my-deployment", id.name);
+/// assert_eq!("us-east-1.aws.found.io", id.host);
+/// assert_eq!("https://es123.us-east-1.aws.found.io", id.elasticsearch_url());
+/// assert_eq!(Some("https://kb456.us-east-1.aws.found.io".to_owned()), id.kibana_url());
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CloudId {
+    /// The name of the deployment, which is only informative.
+    pub name: String,
+
+    /// The domain of the region, such as `us-east-1.aws.found.io`.
+    pub host: String,
+
+    /// The port of the endpoints, `None` for the port 443.
+    pub port: Option<u16>,
+
+    /// The identifier of the Elasticsearch cluster, the first label of its host.
+    pub elasticsearch_id: String,
+
+    /// The identifier of the Kibana instance, the first label of its host.
+    pub kibana_id: Option<String>,
+}
+
+impl CloudId {
+    /// Gets the url of the Elasticsearch cluster.
+    pub fn elasticsearch_url(&self) -> String {
+        self.url(&self.elasticsearch_id)
+    }
+
+    /// Gets the url of the Kibana instance, `None` when the identifier has none.
+    pub fn kibana_url(&self) -> Option<String> {
+        self.kibana_id.as_deref().map(|id| self.url(id))
+    }
+
+    fn url(&self, id: &str) -> String {
+        match self.port {
+            Some(port) if port != 443 => format!("https://{}.{}:{}", id, self.host, port),
+            _ => format!("https://{}.{}", id, self.host),
+        }
+    }
+}
+
+impl FromStr for CloudId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidValue {
+            key: "cloudid".to_owned(),
+            value: s.to_owned(),
+            expected: "`<name>:<base64 of host$elasticsearch id$kibana id>`".to_owned(),
+        };
+
+        let (name, encoded) = s.trim().rsplit_once(':').ok_or_else(invalid)?;
+        let decoded = decode_base64(encoded)
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or_else(invalid)?;
+
+        let mut parts = decoded.split('$');
+        let host = parts.next().unwrap_or_default();
+        let elasticsearch_id = parts.next().unwrap_or_default();
+        let kibana_id = parts.next().filter(|id| !id.is_empty());
+
+        let (host, port) = match host.rsplit_once(':') {
+            Some((host, port)) => (host, Some(parse_port("port", port).map_err(|_| invalid())?)),
+            None => (host, None),
+        };
+
+        if host.is_empty() || elasticsearch_id.is_empty() || parts.next().is_some() {
+            return Err(invalid());
+        }
+
+        Ok(CloudId {
+            name: name.to_owned(),
+            host: host.to_owned(),
+            port,
+            elasticsearch_id: elasticsearch_id.to_owned(),
+            kibana_id: kibana_id.map(str::to_owned),
+        })
+    }
+}
+
+/// Elasticsearch connection settings, the `Nodes` of a cluster or the `CloudId` of
+/// an Elastic Cloud deployment, with a basic authentication or an API key.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::elasticsearch::ElasticsearchConnStr;
+/// use std::str::FromStr;
+///
+/// let conn = ElasticsearchConnStr::from_str("Nodes=https://n1:9200,https://n2:9200;Username=u;Password=p;CertificateFingerprint=AB:CD").unwrap();
+///
+/// assert_eq!(vec!["https://n1:9200", "https://n2:9200"], conn.nodes().unwrap());
+/// assert_eq!(Some("u"), conn.username());
+/// assert_eq!(Some("AB:CD"), conn.certificate_fingerprint());
+/// assert_eq!(
+///     "certificatefingerprint=AB:CD;nodes=https://n1:9200,https://n2:9200;password=***;username=u",
+///     conn.to_string(),
+/// );
+///
+/// let conn = ElasticsearchConnStr::from_str("CloudId=prod:ZXUtd2VzdC0xLmF3cy5mb3VuZC5pbzo5MjQzJGVzOQ==;ApiKey=a2V5").unwrap();
+///
+/// assert_eq!(vec!["https://es9.eu-west-1.aws.found.io:9243"], conn.nodes().unwrap());
+/// ```
+#[derive(Clone)]
+pub struct ElasticsearchConnStr(Arc<HashMap<String, String>>);
+
+impl FromStr for ElasticsearchConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl ElasticsearchConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        Ok(ElasticsearchConnStr(Arc::new(parse(
+            conn_str,
+            false,
+            &keywords::ELASTICSEARCH,
+            options,
+        )?)))
+    }
+
+    /// Gets the API key, the base64 encoding of `<id>:<key>`.
+    #[cfg(not(feature = "secret"))]
+    pub fn api_key(&self) -> Option<&str> {
+        self.get("apikey")
+    }
+
+    /// Gets the API key, the base64 encoding of `<id>:<key>`.
+    #[cfg(feature = "secret")]
+    pub fn api_key(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("apikey").map(crate::secret::Secret::new)
+    }
+
+    /// Gets the SHA-256 fingerprint of the certificate expected from the nodes.
+    pub fn certificate_fingerprint(&self) -> Option<&str> {
+        self.get("certificatefingerprint")
+    }
+
+    /// Decodes the identifier of the Elastic Cloud deployment.
+    pub fn cloud_id(&self) -> Result<Option<CloudId>, Error> {
+        self.get("cloudid").map(str::parse).transpose()
+    }
+
+    /// Gets the urls of the nodes, the url of the Elasticsearch cluster of the
+    /// `CloudId` when there are none.
+    pub fn nodes(&self) -> Result<Vec<String>, Error> {
+        let nodes: Vec<String> = match self.get("nodes") {
+            Some(nodes) => nodes
+                .split(',')
+                .map(str::trim)
+                .filter(|n| !n.is_empty())
+                .map(str::to_owned)
+                .collect(),
+            None => Vec::new(),
+        };
+
+        if !nodes.is_empty() {
+            return Ok(nodes);
+        }
+
+        Ok(self
+            .cloud_id()?
+            .map(|id| id.elasticsearch_url())
+            .into_iter()
+            .collect())
+    }
+
+    #[cfg(not(feature = "secret"))]
+    pub fn password(&self) -> Option<&str> {
+        self.get("password")
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("password").map(crate::secret::Secret::new)
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.0, &keywords::ELASTICSEARCH, false, false)
+    }
+
+    pub fn username(&self) -> Option<&str> {
+        self.get("username")
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        get(&self.0, &keywords::ELASTICSEARCH, name)
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.0, &keywords::ELASTICSEARCH, validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for ElasticsearchConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(
+            &self.0,
+            &keywords::ELASTICSEARCH,
+            false,
+            true,
+        ))
+    }
+}
+
+/// Decodes the standard base64 alphabet, the padding being optional.
+fn decode_base64(s: &str) -> Option<Vec<u8>> {
+    let s = s.trim().trim_end_matches('=');
+    let mut out = Vec::with_capacity(s.len() * 3 / 4);
+    let mut bits = 0u32;
+    let mut count = 0;
+
+    for b in s.bytes() {
+        let value = match b {
+            b'A'..=b'Z' => b - b'A',
+            b'a'..=b'z' => b - b'a' + 26,
+            b'0'..=b'9' => b - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+
+        bits = bits << 6 | u32::from(value);
+        count += 6;
+
+        if count >= 8 {
+            count -= 8;
+            out.push((bits >> count) as u8);
+        }
+    }
+
+    match count < 6 {
+        true => Some(out),
+        false => None,
+    }
+}
+
+#[test]
+fn cloud_id_is_decoded() {
+    let id: CloudId = "dep:ZXUtd2VzdC0xLmF3cy5mb3VuZC5pbzo5MjQzJGVzOQ"
+        .parse()
+        .unwrap();
+
+    assert_eq!(Some(9243), id.port);
+    assert_eq!(None, id.kibana_url());
+
+    assert!("dep".parse::<CloudId>().is_err());
+    assert!("dep:!!".parse::<CloudId>().is_err());
+    assert!("dep:aG9zdA==".parse::<CloudId>().is_err());
+    assert!("dep:aG9zdDowJGVz".parse::<CloudId>().is_err());
+
+    let conn = ElasticsearchConnStr::from_str("Node=https://n1:9200;CloudId=x").unwrap();
+    assert_eq!(vec!["https://n1:9200"], conn.nodes().unwrap());
+    assert!(conn.cloud_id().is_err());
+    assert!(ElasticsearchConnStr::from_str("Username=u")
+        .unwrap()
+        .nodes()
+        .unwrap()
+        .is_empty());
+}
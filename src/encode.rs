@@ -0,0 +1,114 @@
+//! Encoding of key and value pairs as connection strings.
+
+use crate::append_key_value;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::BuildHasher;
+
+/// Options controlling the encoding of a connection string.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct EncodeOptions {
+    /// Quotes the values with braces, as expected by the ODBC drivers.
+    pub use_odbc_rules: bool,
+
+    /// Sorts the keys, the pairs being otherwise written in their order.
+    ///
+    /// The keys of a `HashMap` are always sorted, to produce the same string
+    /// every time.
+    pub sorted: bool,
+
+    /// Skips the pairs with an empty value.
+    pub skip_empty: bool,
+}
+
+/// Encodes key and value pairs as a connection string, quoting the values with the
+/// rules of [`append_key_value`].
+///
+/// # Example
+///
+/// ```
+/// use conn_str::encode::{EncodeOptions, ToConnStr};
+///
+/// let pairs = [("server", "."), ("password", "a;b"), ("app", "")];
+///
+/// assert_eq!(r#"server=.;password="a;b";app="#, pairs.to_conn_string(false));
+/// assert_eq!("server=.;password={a;b};app=", pairs.to_conn_string(true));
+///
+/// let options = EncodeOptions { sorted: true, skip_empty: true, ..Default::default() };
+/// assert_eq!(r#"password="a;b";server=."#, pairs.to_conn_string_with(&options));
+/// ```
+pub trait ToConnStr {
+    fn to_conn_string_with(&self, options: &EncodeOptions) -> String;
+
+    fn to_conn_string(&self, use_odbc_rules: bool) -> String {
+        self.to_conn_string_with(&EncodeOptions {
+            use_odbc_rules,
+            ..Default::default()
+        })
+    }
+}
+
+impl<K: AsRef<str>, V: AsRef<str>> ToConnStr for [(K, V)] {
+    fn to_conn_string_with(&self, options: &EncodeOptions) -> String {
+        encode(self.iter().map(|(k, v)| (k.as_ref(), v.as_ref())), options)
+    }
+}
+
+impl<K: AsRef<str>, V: AsRef<str>> ToConnStr for Vec<(K, V)> {
+    fn to_conn_string_with(&self, options: &EncodeOptions) -> String {
+        self.as_slice().to_conn_string_with(options)
+    }
+}
+
+impl<K: AsRef<str>, V: AsRef<str>, const N: usize> ToConnStr for [(K, V); N] {
+    fn to_conn_string_with(&self, options: &EncodeOptions) -> String {
+        self.as_slice().to_conn_string_with(options)
+    }
+}
+
+impl<K: AsRef<str>, V: AsRef<str>> ToConnStr for BTreeMap<K, V> {
+    fn to_conn_string_with(&self, options: &EncodeOptions) -> String {
+        encode(self.iter().map(|(k, v)| (k.as_ref(), v.as_ref())), options)
+    }
+}
+
+impl<K: AsRef<str>, V: AsRef<str>, S: BuildHasher> ToConnStr for HashMap<K, V, S> {
+    fn to_conn_string_with(&self, options: &EncodeOptions) -> String {
+        let options = EncodeOptions {
+            sorted: true,
+            ..*options
+        };
+
+        encode(self.iter().map(|(k, v)| (k.as_ref(), v.as_ref())), &options)
+    }
+}
+
+fn encode<'a, I>(pairs: I, options: &EncodeOptions) -> String
+where
+    I: Iterator<Item = (&'a str, &'a str)>,
+{
+    let mut pairs: Vec<_> = pairs
+        .filter(|(_, v)| !options.skip_empty || !v.is_empty())
+        .collect();
+
+    if options.sorted {
+        pairs.sort_by(|a, b| a.0.cmp(b.0));
+    }
+
+    let mut out = String::new();
+
+    for (key, value) in pairs {
+        append_key_value(&mut out, key, value, options.use_odbc_rules);
+    }
+
+    out
+}
+
+#[test]
+fn hash_maps_are_encoded_in_key_order() {
+    let map: HashMap<String, String> = [("uid", "me"), ("driver", "{ODBC Driver 18}")]
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+
+    assert_eq!("driver={ODBC Driver 18};uid=me", map.to_conn_string(true));
+}
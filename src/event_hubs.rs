@@ -0,0 +1,296 @@
+//! Azure Event Hubs connection strings, as parsed by the Azure Event Hubs client
+//! libraries.
+
+use crate::keywords;
+use crate::service_bus::endpoint_host;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{decode_bytes, get, parse, parse_bool, to_connection_string, Error, ParseOptions};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The `TransportType` of an Event Hubs connection.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TransportType {
+    /// `Amqp`, AMQP over TCP on the port 5671.
+    Amqp,
+
+    /// `AmqpWebSockets`, AMQP over web sockets on the port 443.
+    AmqpWebSockets,
+}
+
+impl FromStr for TransportType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "amqp" => Ok(TransportType::Amqp),
+            "amqpwebsockets" => Ok(TransportType::AmqpWebSockets),
+            _ => Err(Error::InvalidValue {
+                key: "transporttype".to_owned(),
+                value: s.to_owned(),
+                expected: keywords::EVENT_HUBS
+                    .get("transporttype")
+                    .unwrap()
+                    .kind
+                    .to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for TransportType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            TransportType::Amqp => "Amqp",
+            TransportType::AmqpWebSockets => "AmqpWebSockets",
+        })
+    }
+}
+
+/// An Azure Event Hubs connection string, written with the grammar of the
+/// [Service Bus](crate::service_bus::ServiceBusConnStr) ones.
+///
+/// The `EntityPath` names the event hub, which is otherwise given apart to the client.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::event_hubs::{EventHubsConnStr, TransportType};
+/// use std::str::FromStr;
+/// use std::time::Duration;
+///
+/// let conn = EventHubsConnStr::from_str("Endpoint=sb://ns.servicebus.windows.net/;SharedAccessKeyName=send;SharedAccessKey=abc=;EntityPath=telemetry;TransportType=AmqpWebSockets;OperationTimeout=00:00:30").unwrap();
+///
+/// assert_eq!(Some("telemetry"), conn.event_hub_name());
+/// assert_eq!(Some("ns.servicebus.windows.net"), conn.fully_qualified_namespace());
+/// assert_eq!(TransportType::AmqpWebSockets, conn.transport_type().unwrap());
+/// assert_eq!(Duration::from_secs(30), conn.operation_timeout().unwrap());
+/// ```
+#[derive(Clone)]
+pub struct EventHubsConnStr(Arc<HashMap<String, String>>);
+
+impl FromStr for EventHubsConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl EventHubsConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    /// Parses the connection string, which requires an `Endpoint`.
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        let map = parse(conn_str, false, &keywords::EVENT_HUBS, options)?;
+
+        match get(&map, &keywords::EVENT_HUBS, "endpoint") {
+            Some(endpoint) if !endpoint.trim().is_empty() => Ok(EventHubsConnStr(Arc::new(map))),
+            _ => Err(Error::MissingKey("endpoint".to_owned())),
+        }
+    }
+
+    /// Gets the url of the namespace, `sb://<namespace>.servicebus.windows.net/`.
+    pub fn endpoint(&self) -> Option<&str> {
+        self.get("endpoint")
+    }
+
+    /// Gets the name of the event hub, the `EntityPath`.
+    pub fn event_hub_name(&self) -> Option<&str> {
+        self.get("entitypath")
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+    }
+
+    /// Gets the host of the `Endpoint`, such as `<namespace>.servicebus.windows.net`.
+    pub fn fully_qualified_namespace(&self) -> Option<&str> {
+        self.endpoint().and_then(endpoint_host)
+    }
+
+    /// Gets the time waited for an operation, one minute when missing.
+    pub fn operation_timeout(&self) -> Result<Duration, Error> {
+        match self.get("operationtimeout") {
+            Some(v) => parse_time_span(v).ok_or_else(|| Error::InvalidValue {
+                key: "operationtimeout".to_owned(),
+                value: v.to_owned(),
+                expected: "a time span written `[d.]hh:mm:ss[.fffffff]`".to_owned(),
+            }),
+            None => Ok(Duration::from_secs(60)),
+        }
+    }
+
+    /// Gets the key of the shared access policy.
+    #[cfg(not(feature = "secret"))]
+    pub fn shared_access_key(&self) -> Option<&str> {
+        self.get("sharedaccesskey")
+    }
+
+    /// Gets the key of the shared access policy.
+    #[cfg(feature = "secret")]
+    pub fn shared_access_key(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("sharedaccesskey").map(crate::secret::Secret::new)
+    }
+
+    /// Gets the name of the shared access policy.
+    pub fn shared_access_key_name(&self) -> Option<&str> {
+        self.get("sharedaccesskeyname")
+    }
+
+    /// Gets the shared access signature used instead of the policy key.
+    #[cfg(not(feature = "secret"))]
+    pub fn shared_access_signature(&self) -> Option<&str> {
+        self.get("sharedaccesssignature")
+    }
+
+    /// Gets the shared access signature used instead of the policy key.
+    #[cfg(feature = "secret")]
+    pub fn shared_access_signature(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("sharedaccesssignature")
+            .map(crate::secret::Secret::new)
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.0, &keywords::EVENT_HUBS, false, false)
+    }
+
+    /// Gets the protocol of the connection, AMQP over TCP when missing.
+    pub fn transport_type(&self) -> Result<TransportType, Error> {
+        match self.get("transporttype") {
+            Some(v) => v.parse(),
+            None => Ok(TransportType::Amqp),
+        }
+    }
+
+    /// Indicates if the connection targets the local emulator,
+    /// `UseDevelopmentEmulator=true`.
+    pub fn use_development_emulator(&self) -> Result<bool, Error> {
+        match self.get("usedevelopmentemulator") {
+            Some(v) => parse_bool(v.trim()),
+            None => Ok(false),
+        }
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        get(&self.0, &keywords::EVENT_HUBS, name)
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.0, &keywords::EVENT_HUBS, validator)
+    }
+}
+
+/// Formats the connection string with its keys masked.
+impl fmt::Debug for EventHubsConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("EventHubsConnStr")
+            .field(&self.to_string())
+            .finish()
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for EventHubsConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(
+            &self.0,
+            &keywords::EVENT_HUBS,
+            false,
+            true,
+        ))
+    }
+}
+
+/// Parses a .NET `TimeSpan` written `[d.]hh:mm:ss[.fffffff]`.
+fn parse_time_span(s: &str) -> Option<Duration> {
+    let mut parts = s.trim().split(':');
+    let (hours, minutes, seconds) = (parts.next()?, parts.next()?, parts.next()?);
+
+    if parts.next().is_some() {
+        return None;
+    }
+
+    let (days, hours) = match hours.split_once('.') {
+        Some((days, hours)) => (digits(days)?, digits(hours)?),
+        None => (0, digits(hours)?),
+    };
+
+    let (seconds, nanos) = match seconds.split_once('.') {
+        Some((seconds, fraction)) if fraction.len() <= 7 => (
+            digits(seconds)?,
+            digits(fraction)? * 10u64.pow(9 - fraction.len() as u32),
+        ),
+        Some(_) => return None,
+        None => (digits(seconds)?, 0),
+    };
+
+    let minutes = digits(minutes)?;
+
+    if hours > 23 || minutes > 59 || seconds > 59 {
+        return None;
+    }
+
+    // the days are not bounded, an overflow being an invalid time span
+    let secs = days
+        .checked_mul(24)?
+        .checked_add(hours)?
+        .checked_mul(60)?
+        .checked_add(minutes)?
+        .checked_mul(60)?
+        .checked_add(seconds)?;
+
+    Some(Duration::new(secs, nanos as u32))
+}
+
+/// Parses a number made only of ASCII digits.
+fn digits(s: &str) -> Option<u64> {
+    match !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+        true => s.parse().ok(),
+        false => None,
+    }
+}
+
+#[test]
+fn operation_timeout_is_a_time_span() {
+    let conn = EventHubsConnStr::from_str(
+        "Endpoint=sb://ns.servicebus.windows.net/;SharedAccessSignature=sig;OperationTimeout=1.02:03:04.5",
+    )
+    .unwrap();
+
+    assert_eq!(None, conn.event_hub_name());
+    assert_eq!(TransportType::Amqp, conn.transport_type().unwrap());
+    assert_eq!(
+        Duration::new(93784, 500_000_000),
+        conn.operation_timeout().unwrap()
+    );
+    assert_eq!(
+        "EventHubsConnStr(\"endpoint=sb://ns.servicebus.windows.net/;operationtimeout=1.02:03:04.5;sharedaccesssignature=***\")",
+        format!("{:?}", conn)
+    );
+
+    let conn =
+        EventHubsConnStr::from_str("Endpoint=sb://h;OperationTimeout=00:60:00;TransportType=http")
+            .unwrap();
+    assert!(conn.operation_timeout().is_err());
+    assert!(conn.transport_type().is_err());
+
+    let conn =
+        EventHubsConnStr::from_str("Endpoint=sb://h;OperationTimeout=1000000000000000000.00:00:00")
+            .unwrap();
+    assert!(conn.operation_timeout().is_err());
+    assert!(EventHubsConnStr::from_str("EntityPath=hub").is_err());
+}
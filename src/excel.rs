@@ -0,0 +1,333 @@
+//! Excel connection strings, opened by the Jet and ACE OLE DB providers or by the
+//! Microsoft Excel ODBC driver.
+
+use crate::odbc::OdbcConnStr;
+use crate::oledb::OleDbConnStr;
+use crate::validate::{ValidationIssue, Validator};
+use crate::{append_key_value, decode_bytes, parse_bool, unquote_value, Error, ParseOptions};
+use std::fmt;
+use std::str::FromStr;
+
+/// The `Extended Properties` of an Excel OLE DB connection string, a nested
+/// semicolon-delimited string such as `Excel 12.0 Xml;HDR=YES;IMEX=1`.
+///
+/// The item without a `=` is the format of the file, the other items being key/value
+/// pairs whose values may be quoted as in the outer connection string.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::excel::ExtendedProperties;
+///
+/// let props: ExtendedProperties = "Excel 12.0 Xml;HDR=NO;IMEX=1".parse().unwrap();
+///
+/// assert_eq!(Some("Excel 12.0 Xml"), props.format.as_deref());
+/// assert_eq!(Some("NO"), props.get("hdr"));
+/// assert!(!props.header().unwrap());
+/// assert_eq!(Some(1), props.imex().unwrap());
+/// assert_eq!("Excel 12.0 Xml;HDR=NO;IMEX=1", props.to_string());
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ExtendedProperties {
+    /// The format of the file, such as `Excel 8.0` for the .xls files or
+    /// `Excel 12.0 Xml` for the .xlsx files.
+    pub format: Option<String>,
+
+    /// The key/value pairs, in their order, with their keys as written.
+    pub properties: Vec<(String, String)>,
+}
+
+impl FromStr for ExtendedProperties {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut props = ExtendedProperties::default();
+
+        for item in split_items(s) {
+            let item = item.trim();
+
+            match item.split_once('=') {
+                Some((key, value)) => {
+                    let key = key.trim();
+
+                    if key.is_empty() {
+                        return Err(invalid(s));
+                    }
+
+                    let value = match value.trim_start().starts_with(['"', '\'']) {
+                        true => unquote_value(value)?,
+                        false => value.trim().to_owned(),
+                    };
+
+                    props.properties.push((key.to_owned(), value));
+                }
+                None if item.is_empty() => {}
+                None if props.format.is_none() => props.format = Some(item.to_owned()),
+                None => return Err(invalid(s)),
+            }
+        }
+
+        Ok(props)
+    }
+}
+
+impl ExtendedProperties {
+    /// Gets the value of a property, ignoring case.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        let key = key.trim();
+
+        self.properties
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Indicates if the first row holds the names of the columns, `HDR`, true when
+    /// missing.
+    pub fn header(&self) -> Result<bool, Error> {
+        match self.get("hdr") {
+            Some(v) => parse_bool(v.trim()),
+            None => Ok(true),
+        }
+    }
+
+    /// Gets the `IMEX` mode, 1 reading the columns of mixed types as text.
+    pub fn imex(&self) -> Result<Option<u8>, Error> {
+        match self.get("imex") {
+            Some(v) => match v.trim().parse::<u8>() {
+                Ok(mode) if mode <= 2 => Ok(Some(mode)),
+                _ => Err(Error::InvalidValue {
+                    key: "imex".to_owned(),
+                    value: v.to_owned(),
+                    expected: "0, 1 or 2".to_owned(),
+                }),
+            },
+            None => Ok(None),
+        }
+    }
+}
+
+/// Formats the properties as written in the `Extended Properties`, the format first.
+impl fmt::Display for ExtendedProperties {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut out = self.format.clone().unwrap_or_default();
+
+        for (key, value) in &self.properties {
+            append_key_value(&mut out, key, value, false);
+        }
+
+        f.write_str(&out)
+    }
+}
+
+#[derive(Clone)]
+enum Source {
+    OleDb(OleDbConnStr),
+    Odbc(OdbcConnStr),
+}
+
+/// An Excel connection string, either an OLE DB connection string of the Jet or ACE
+/// provider or an ODBC connection string of the Microsoft Excel driver.
+///
+/// The OLE DB connection strings are recognized by their `Provider`, the other ones
+/// being parsed with the ODBC rules.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::excel::ExcelConnStr;
+/// use std::str::FromStr;
+///
+/// let conn = ExcelConnStr::from_str(r#"Provider=Microsoft.ACE.OLEDB.12.0;Data Source=file.xlsx;Extended Properties="Excel 12.0 Xml;HDR=YES;IMEX=1""#).unwrap();
+///
+/// assert_eq!(Some("file.xlsx"), conn.workbook());
+/// assert!(conn.header().unwrap());
+///
+/// let props = conn.extended_properties().unwrap().unwrap();
+/// assert_eq!(Some("Excel 12.0 Xml"), props.format.as_deref());
+/// assert_eq!(Some(1), props.imex().unwrap());
+///
+/// let conn = ExcelConnStr::from_str(r"Driver={Microsoft Excel Driver (*.xls, *.xlsx, *.xlsm, *.xlsb)};DBQ=C:\file.xlsx;FirstRowHasNames=0").unwrap();
+///
+/// assert_eq!(Some(r"C:\file.xlsx"), conn.workbook());
+/// assert!(!conn.header().unwrap());
+/// assert!(conn.as_odbc().is_some());
+/// ```
+#[derive(Clone)]
+pub struct ExcelConnStr(Source);
+
+impl FromStr for ExcelConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl ExcelConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    /// Parses the connection string, failing when an OLE DB provider is neither Jet
+    /// nor ACE or when an ODBC connection string has neither `DRIVER` nor `DSN`.
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        if let Ok(conn) = OleDbConnStr::parse_with(conn_str, options) {
+            if let Some(provider) = conn.provider() {
+                if !crate::access::is_jet_or_ace(provider) {
+                    return Err(Error::InvalidValue {
+                        key: "provider".to_owned(),
+                        value: provider.to_owned(),
+                        expected: "`Microsoft.Jet.OLEDB.4.0` or `Microsoft.ACE.OLEDB.12.0`"
+                            .to_owned(),
+                    });
+                }
+
+                return Ok(ExcelConnStr(Source::OleDb(conn)));
+            }
+        }
+
+        let conn = OdbcConnStr::parse_with(conn_str, options)?;
+
+        match conn.source() {
+            Some(_) => Ok(ExcelConnStr(Source::Odbc(conn))),
+            None => Err(Error::MissingKey("provider".to_owned())),
+        }
+    }
+
+    /// Gets the ODBC connection string, `None` for an OLE DB connection string.
+    pub fn as_odbc(&self) -> Option<&OdbcConnStr> {
+        match &self.0 {
+            Source::Odbc(conn) => Some(conn),
+            Source::OleDb(_) => None,
+        }
+    }
+
+    /// Gets the OLE DB connection string, `None` for an ODBC connection string.
+    pub fn as_ole_db(&self) -> Option<&OleDbConnStr> {
+        match &self.0 {
+            Source::OleDb(conn) => Some(conn),
+            Source::Odbc(_) => None,
+        }
+    }
+
+    /// Parses the nested `Extended Properties`, `None` when missing or for an ODBC
+    /// connection string.
+    pub fn extended_properties(&self) -> Result<Option<ExtendedProperties>, Error> {
+        self.as_ole_db()
+            .and_then(OleDbConnStr::extended_properties)
+            .map(str::parse)
+            .transpose()
+    }
+
+    /// Indicates if the first row holds the names of the columns, the `HDR` of the
+    /// `Extended Properties` or the `FirstRowHasNames` of the ODBC driver, true when
+    /// missing.
+    pub fn header(&self) -> Result<bool, Error> {
+        match &self.0 {
+            Source::OleDb(_) => match self.extended_properties()? {
+                Some(props) => props.header(),
+                None => Ok(true),
+            },
+            Source::Odbc(conn) => match conn.get("firstrowhasnames").map(str::trim) {
+                Some("1") | None => Ok(true),
+                Some("0") => Ok(false),
+                Some(v) => parse_bool(v),
+            },
+        }
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        match &self.0 {
+            Source::OleDb(conn) => conn.to_connection_string_with_secrets(),
+            Source::Odbc(conn) => conn.to_connection_string_with_secrets(),
+        }
+    }
+
+    /// Gets the path of the workbook, the `Data Source` of OLE DB or the `DBQ` of
+    /// ODBC.
+    pub fn workbook(&self) -> Option<&str> {
+        match &self.0 {
+            Source::OleDb(conn) => conn.data_source(),
+            Source::Odbc(conn) => conn.get("dbq"),
+        }
+    }
+
+    /// Validates the OLE DB or ODBC connection string, returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        match &self.0 {
+            Source::OleDb(conn) => conn.validate_with(validator),
+            Source::Odbc(conn) => conn.validate_with(validator),
+        }
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for ExcelConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.0 {
+            Source::OleDb(conn) => conn.fmt(f),
+            Source::Odbc(conn) => conn.fmt(f),
+        }
+    }
+}
+
+fn invalid(s: &str) -> Error {
+    Error::InvalidValue {
+        key: "extended properties".to_owned(),
+        value: s.to_owned(),
+        expected: "a format followed by `key=value` pairs".to_owned(),
+    }
+}
+
+/// Splits the items on the semicolons outside of the quoted values.
+fn split_items(s: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let mut quote = None;
+    let mut start = 0;
+
+    for (i, c) in s.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == ';' => {
+                items.push(&s[start..i]);
+                start = i + 1;
+            }
+            None => {}
+        }
+    }
+
+    items.push(&s[start..]);
+    items
+}
+
+#[test]
+fn extended_properties_are_parsed_recursively() {
+    let props: ExtendedProperties = "Text; HDR=No ;FMT='Delimited(;)';".parse().unwrap();
+
+    assert_eq!(Some("Text"), props.format.as_deref());
+    assert_eq!(Some("Delimited(;)"), props.get("fmt"));
+    assert!(!props.header().unwrap());
+    assert_eq!(None, props.imex().unwrap());
+    assert_eq!("Text;HDR=No;FMT=\"Delimited(;)\"", props.to_string());
+
+    assert!("Excel 8.0;Excel 12.0"
+        .parse::<ExtendedProperties>()
+        .is_err());
+    assert!("IMEX=3"
+        .parse::<ExtendedProperties>()
+        .unwrap()
+        .imex()
+        .is_err());
+    assert!(ExcelConnStr::from_str("Provider=SQLOLEDB;Data Source=.").is_err());
+}
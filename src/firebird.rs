@@ -0,0 +1,279 @@
+//! Firebird connection strings, as parsed by FirebirdSql.Data.FirebirdClient.
+
+use crate::keywords;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{
+    decode_bytes, get, parse, parse_bool, parse_port, to_connection_string, Error, ParseOptions,
+};
+use std::collections::HashMap;
+use std::fmt;
+use std::net::Ipv6Addr;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// The `Database` of a Firebird connection string, `[host[/port]:]path`.
+///
+/// The path is a file path on the server or an alias of `databases.conf`. A Windows
+/// path such as `C:\db.fdb` has no host, its drive letter not being mistaken for one.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::firebird::Database;
+///
+/// let db: Database = "db1/3051:C:\\data\\sales.fdb".parse().unwrap();
+///
+/// assert_eq!(Some("db1"), db.host.as_deref());
+/// assert_eq!(Some(3051), db.port);
+/// assert_eq!("C:\\data\\sales.fdb", db.path);
+///
+/// let db: Database = "C:\\data\\sales.fdb".parse().unwrap();
+/// assert_eq!(None, db.host);
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Database {
+    /// The host name or the IP address, an IPv6 address being written without its
+    /// brackets, `None` when the host is the `Data Source`.
+    pub host: Option<String>,
+
+    /// The port following the host, `None` when the port is the `Port`.
+    pub port: Option<u16>,
+
+    pub path: String,
+}
+
+impl FromStr for Database {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidValue {
+            key: "database".to_owned(),
+            value: s.to_owned(),
+            expected: "`[host[/port]:]path`".to_owned(),
+        };
+
+        let s = s.trim();
+
+        // an IPv6 address is written between brackets, holding colons of its own
+        let start = match s.starts_with('[') {
+            true => s.find(']').ok_or_else(invalid)?,
+            false => 0,
+        };
+
+        let (server, path) = match s[start..].find(':') {
+            Some(i) => (&s[..start + i], &s[start + i + 1..]),
+            None => return Ok(Database::local(s)),
+        };
+
+        let drive = server.len() == 1
+            && server.chars().all(|c| c.is_ascii_alphabetic())
+            && path.starts_with(['\\', '/']);
+
+        if drive {
+            return Ok(Database::local(s));
+        }
+
+        let (host, port) = match server.rsplit_once('/') {
+            Some((host, port)) => (host, Some(parse_port("port", port).map_err(|_| invalid())?)),
+            None => (server, None),
+        };
+
+        let host = match host.strip_prefix('[') {
+            Some(ip) => ip
+                .strip_suffix(']')
+                .filter(|ip| ip.parse::<Ipv6Addr>().is_ok())
+                .ok_or_else(invalid)?,
+            None => host,
+        };
+
+        if host.trim().is_empty() || path.trim().is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(Database {
+            host: Some(host.trim().to_owned()),
+            port,
+            path: path.trim().to_owned(),
+        })
+    }
+}
+
+impl Database {
+    fn local(path: &str) -> Self {
+        Database {
+            host: None,
+            port: None,
+            path: path.to_owned(),
+        }
+    }
+}
+
+/// Formats the database as written in a connection string, an IPv6 address being
+/// written between brackets.
+impl fmt::Display for Database {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(host) = &self.host {
+            match host.parse::<Ipv6Addr>() {
+                Ok(_) => write!(f, "[{}]", host)?,
+                Err(_) => f.write_str(host)?,
+            }
+
+            if let Some(port) = self.port {
+                write!(f, "/{}", port)?;
+            }
+
+            f.write_str(":")?;
+        }
+
+        f.write_str(&self.path)
+    }
+}
+
+/// A FirebirdClient connection string.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::firebird::FbConnStr;
+/// use std::str::FromStr;
+///
+/// let conn = FbConnStr::from_str("database=localhost:C:\\db.fdb;user=SYSDBA;password=masterkey;dialect=3;charset=UTF8").unwrap();
+///
+/// assert_eq!(Some("localhost:C:\\db.fdb"), conn.database());
+/// assert_eq!(Some("localhost"), conn.database_location().unwrap().unwrap().host.as_deref());
+/// assert_eq!(Some("SYSDBA"), conn.user_id());
+/// assert_eq!(3, conn.dialect().unwrap());
+/// assert_eq!(Some("UTF8"), conn.charset());
+/// assert_eq!("charset=UTF8;database=localhost:C:\\db.fdb;dialect=3;password=***;user=SYSDBA", conn.to_string());
+/// ```
+#[derive(Clone)]
+pub struct FbConnStr(Arc<HashMap<String, String>>);
+
+impl FromStr for FbConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl FbConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        Ok(FbConnStr(Arc::new(parse(
+            conn_str,
+            false,
+            &keywords::FIREBIRD,
+            options,
+        )?)))
+    }
+
+    pub fn charset(&self) -> Option<&str> {
+        self.get("charset")
+    }
+
+    /// Gets the host of the server when the database does not name it.
+    pub fn data_source(&self) -> Option<&str> {
+        self.get("data source")
+    }
+
+    /// Gets the database as written, `[host[/port]:]path`.
+    pub fn database(&self) -> Option<&str> {
+        self.get("database")
+    }
+
+    /// Splits the database into its host, its port and its path.
+    pub fn database_location(&self) -> Result<Option<Database>, Error> {
+        self.database().map(str::parse).transpose()
+    }
+
+    /// Gets the SQL dialect, 3 when missing.
+    pub fn dialect(&self) -> Result<u8, Error> {
+        match self.get("dialect").map(str::trim) {
+            Some("1") => Ok(1),
+            Some("3") | None => Ok(3),
+            Some(v) => Err(Error::InvalidValue {
+                key: "dialect".to_owned(),
+                value: v.to_owned(),
+                expected: keywords::FIREBIRD.get("dialect").unwrap().kind.to_string(),
+            }),
+        }
+    }
+
+    #[cfg(not(feature = "secret"))]
+    pub fn password(&self) -> Option<&str> {
+        self.get("password")
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("password").map(crate::secret::Secret::new)
+    }
+
+    pub fn pooling(&self) -> Result<bool, Error> {
+        match self.get("pooling") {
+            Some(v) => parse_bool(v),
+            None => Ok(true),
+        }
+    }
+
+    pub fn role(&self) -> Option<&str> {
+        self.get("role")
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.0, &keywords::FIREBIRD, false, false)
+    }
+
+    pub fn user_id(&self) -> Option<&str> {
+        self.get("user id")
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        get(&self.0, &keywords::FIREBIRD, name)
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.0, &keywords::FIREBIRD, validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for FbConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(
+            &self.0,
+            &keywords::FIREBIRD,
+            false,
+            true,
+        ))
+    }
+}
+
+#[test]
+fn databases_split_their_host_from_their_path() {
+    let db: Database = "[::1]/3050:/var/lib/firebird/sales.fdb".parse().unwrap();
+
+    assert_eq!(Some("::1"), db.host.as_deref());
+    assert_eq!("/var/lib/firebird/sales.fdb", db.path);
+    assert_eq!("[::1]/3050:/var/lib/firebird/sales.fdb", db.to_string());
+
+    let db: Database = "sales".parse().unwrap();
+    assert_eq!((None, "sales"), (db.host, db.path.as_str()));
+
+    assert!("db1/port:sales".parse::<Database>().is_err());
+    assert!(FbConnStr::from_str("dialect=2").unwrap().dialect().is_err());
+}
@@ -0,0 +1,242 @@
+//! Hive and Impala connection strings, as passed to the Cloudera ODBC drivers.
+
+use crate::keywords;
+use crate::odbc::OdbcConnStr;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{decode_bytes, parse_bool, parse_port, to_connection_string, Error, ParseOptions};
+use std::fmt;
+use std::str::FromStr;
+
+/// The `AuthMech` of the Cloudera and Simba drivers of Hive, Impala and Spark.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AuthMech {
+    /// `0`, no authentication.
+    NoAuthentication,
+
+    /// `1`, Kerberos.
+    Kerberos,
+
+    /// `2`, the user name alone.
+    UserName,
+
+    /// `3`, the user name and the password, or the `token` user and a personal
+    /// access token for Databricks.
+    UserNameAndPassword,
+
+    /// `6`, the Windows Azure HDInsight Emulator.
+    HdInsightEmulator,
+
+    /// `7`, the Windows Azure HDInsight Service.
+    HdInsightService,
+
+    /// `11`, OAuth 2.0.
+    OAuth,
+}
+
+impl FromStr for AuthMech {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "0" => Ok(AuthMech::NoAuthentication),
+            "1" => Ok(AuthMech::Kerberos),
+            "2" => Ok(AuthMech::UserName),
+            "3" => Ok(AuthMech::UserNameAndPassword),
+            "6" => Ok(AuthMech::HdInsightEmulator),
+            "7" => Ok(AuthMech::HdInsightService),
+            "11" => Ok(AuthMech::OAuth),
+            _ => Err(Error::InvalidValue {
+                key: "authmech".to_owned(),
+                value: s.to_owned(),
+                expected: "0, 1, 2, 3, 6, 7 or 11".to_owned(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for AuthMech {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            AuthMech::NoAuthentication => "0",
+            AuthMech::Kerberos => "1",
+            AuthMech::UserName => "2",
+            AuthMech::UserNameAndPassword => "3",
+            AuthMech::HdInsightEmulator => "6",
+            AuthMech::HdInsightService => "7",
+            AuthMech::OAuth => "11",
+        })
+    }
+}
+
+/// A connection string of the Cloudera ODBC drivers for Apache Hive and Impala,
+/// parsed with the ODBC rules where a value is quoted with braces.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::hive::{AuthMech, HiveConnStr};
+/// use std::str::FromStr;
+///
+/// let conn = HiveConnStr::from_str("Driver={Cloudera ODBC Driver for Apache Hive};Host=h;Port=10000;AuthMech=3;UID=u;PWD=p;SSL=1").unwrap();
+///
+/// assert_eq!(Some("h"), conn.host());
+/// assert_eq!(10000, conn.port().unwrap());
+/// assert_eq!(AuthMech::UserNameAndPassword, conn.auth_mech().unwrap());
+/// assert_eq!(Some("u"), conn.uid());
+/// assert!(conn.ssl().unwrap());
+/// assert!(!conn.is_impala());
+/// ```
+#[derive(Clone)]
+pub struct HiveConnStr(OdbcConnStr);
+
+impl FromStr for HiveConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl HiveConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        OdbcConnStr::parse_with(conn_str, options).map(HiveConnStr)
+    }
+
+    /// Gets the ODBC connection string.
+    pub fn as_odbc(&self) -> &OdbcConnStr {
+        &self.0
+    }
+
+    /// Gets the authentication mechanism, no authentication when missing.
+    pub fn auth_mech(&self) -> Result<AuthMech, Error> {
+        match self.0.get("authmech") {
+            Some(v) => v.parse(),
+            None => Ok(AuthMech::NoAuthentication),
+        }
+    }
+
+    /// Gets the host of the server, or of the ZooKeeper ensemble with service
+    /// discovery.
+    pub fn host(&self) -> Option<&str> {
+        self.0.get("host")
+    }
+
+    /// Indicates if the driver is the one of Impala, from its name.
+    pub fn is_impala(&self) -> bool {
+        self.0
+            .driver_info()
+            .is_some_and(|d| d.name.to_lowercase().contains("impala"))
+    }
+
+    #[cfg(not(feature = "secret"))]
+    pub fn password(&self) -> Option<&str> {
+        self.0.password()
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.0.password()
+    }
+
+    /// Gets the port, 10000 for Hive and 21050 for Impala when missing.
+    pub fn port(&self) -> Result<u16, Error> {
+        match self.0.get("port") {
+            Some(port) => parse_port("port", port),
+            None if self.is_impala() => Ok(21050),
+            None => Ok(10000),
+        }
+    }
+
+    /// Gets the default database of the queries.
+    pub fn schema(&self) -> Option<&str> {
+        self.0.get("schema")
+    }
+
+    /// Indicates if the connection uses TLS, `SSL=1`.
+    pub fn ssl(&self) -> Result<bool, Error> {
+        flag(&self.0, "ssl")
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(self.0.map(), &keywords::HIVE, true, false)
+    }
+
+    pub fn uid(&self) -> Option<&str> {
+        self.0.user_id()
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(self.0.map(), &keywords::HIVE, validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted, including the tokens
+/// and the passwords of the key stores.
+impl fmt::Display for HiveConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(
+            self.0.map(),
+            &keywords::HIVE,
+            true,
+            true,
+        ))
+    }
+}
+
+/// Reads a boolean written `1` or `0` by the Simba based drivers, or `true` or
+/// `false`, false when missing.
+pub(crate) fn flag(conn: &OdbcConnStr, name: &str) -> Result<bool, Error> {
+    match conn.get(name).map(str::trim) {
+        Some("1") => Ok(true),
+        Some("0") => Ok(false),
+        Some(v) => parse_bool(v),
+        None => Ok(false),
+    }
+}
+
+#[test]
+fn impala_uses_its_own_default_port() {
+    let conn =
+        HiveConnStr::from_str("DRIVER={Cloudera ODBC Driver for Impala};HOST=h;AUTHMECH=1;SSL=0")
+            .unwrap();
+
+    assert!(conn.is_impala());
+    assert_eq!(21050, conn.port().unwrap());
+    assert_eq!(AuthMech::Kerberos, conn.auth_mech().unwrap());
+    assert!(!conn.ssl().unwrap());
+
+    let conn =
+        HiveConnStr::from_str("Driver={Cloudera ODBC Driver for Apache Hive};AuthMech=4").unwrap();
+    assert!(conn.auth_mech().is_err());
+    assert_eq!(AuthMech::OAuth, "11".parse().unwrap());
+}
+
+#[test]
+fn tokens_and_key_store_passwords_are_redacted() {
+    let conn = HiveConnStr::from_str(
+        "Host=h;AuthMech=11;Auth_AccessToken=t0k;SSLKeyStorePwd=k1;TrustStorePwd=k2;Auth_Client_Secret=s3;PWD=p",
+    )
+    .unwrap();
+
+    assert_eq!(
+        "auth_accesstoken=***;auth_client_secret=***;authmech=11;host=h;pwd=***;sslkeystorepwd=***;truststorepwd=***",
+        conn.to_string()
+    );
+    assert!(conn
+        .to_connection_string_with_secrets()
+        .contains("auth_accesstoken=t0k"));
+}
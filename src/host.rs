@@ -0,0 +1,234 @@
+//! Lists of `host[:port]` endpoints, as found in the URLs and connection strings of
+//! the clustered databases.
+//!
+//! Each host may carry its own options, written `host[:port]?key=value&...`, such as
+//! the data center of a contact point.
+
+use crate::{parse_port, Error};
+use std::fmt;
+use std::net::Ipv6Addr;
+use std::str::FromStr;
+
+/// A host name or an IP address, with its port.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Host {
+    /// The host name or the IP address, an IPv6 address being written without its
+    /// brackets.
+    pub name: String,
+
+    pub port: Option<u16>,
+
+    /// The options of this host only, as written, in their order.
+    pub options: Vec<(String, String)>,
+}
+
+impl Host {
+    pub fn new(name: &str, port: Option<u16>) -> Self {
+        Host {
+            name: name.to_owned(),
+            port,
+            options: Vec::new(),
+        }
+    }
+
+    /// Gets the value of an option of the host, ignoring case.
+    pub fn option(&self, key: &str) -> Option<&str> {
+        let key = key.trim();
+
+        self.options
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Indicates if the host is an IPv6 address, written between brackets.
+    pub fn is_ipv6(&self) -> bool {
+        self.name.parse::<Ipv6Addr>().is_ok()
+    }
+}
+
+impl FromStr for Host {
+    type Err = Error;
+
+    /// Parses `host`, `host:port`, `[ipv6]`, `[ipv6]:port` or a bare IPv6 address,
+    /// followed by the options of the host, `?key=value&...`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidValue {
+            key: "host".to_owned(),
+            value: s.to_owned(),
+            expected:
+                "`host[:port][?key=value&...]`, IPv6 addresses being written between brackets"
+                    .to_owned(),
+        };
+
+        let (s, options) = s.split_once('?').unwrap_or((s, ""));
+        let s = s.trim();
+
+        let options = options
+            .split('&')
+            .filter(|o| !o.trim().is_empty())
+            .map(|o| match o.split_once('=') {
+                Some((k, v)) if !k.trim().is_empty() => {
+                    Ok((k.trim().to_owned(), v.trim().to_owned()))
+                }
+                _ => Err(invalid()),
+            })
+            .collect::<Result<_, _>>()?;
+
+        let (name, port) = if let Some(rest) = s.strip_prefix('[') {
+            let (name, rest) = rest.split_once(']').ok_or_else(invalid)?;
+
+            if name.parse::<Ipv6Addr>().is_err() {
+                return Err(invalid());
+            }
+
+            match rest {
+                "" => (name, None),
+                _ => (name, Some(rest.strip_prefix(':').ok_or_else(invalid)?)),
+            }
+        } else if s.parse::<Ipv6Addr>().is_ok() {
+            (s, None)
+        } else {
+            match s.split_once(':') {
+                Some((name, port)) => (name, Some(port)),
+                None => (s, None),
+            }
+        };
+
+        let port = match port {
+            Some(port) => Some(parse_port("port", port).map_err(|_| invalid())?),
+            None => None,
+        };
+
+        let name = name.trim();
+
+        if name.is_empty() || name.contains(|c: char| c.is_whitespace() || c == '/') {
+            return Err(invalid());
+        }
+
+        Ok(Host {
+            options,
+            ..Host::new(name, port)
+        })
+    }
+}
+
+impl fmt::Display for Host {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_ipv6() {
+            write!(f, "[{}]", self.name)?;
+        } else {
+            f.write_str(&self.name)?;
+        }
+
+        if let Some(port) = self.port {
+            write!(f, ":{}", port)?;
+        }
+
+        for (i, (key, value)) in self.options.iter().enumerate() {
+            write!(f, "{}{}={}", if i == 0 { '?' } else { '&' }, key, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A comma separated list of hosts, such as the hosts of a PostgreSQL connection
+/// string or the seed list of a MongoDB URL.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::host::{Host, HostList};
+///
+/// let list: HostList = "db1:5433, [2001:db8::1]:5432,db3".parse().unwrap();
+///
+/// assert_eq!(Host::new("2001:db8::1", Some(5432)), list.hosts[1]);
+/// assert_eq!("db1:5433,[2001:db8::1]:5432,db3:5432", list.with_default_port(5432).to_string());
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct HostList {
+    pub hosts: Vec<Host>,
+}
+
+impl HostList {
+    /// Parses a list of hosts separated by another character than a comma.
+    pub fn parse_with(s: &str, separator: char) -> Result<Self, Error> {
+        let hosts = s
+            .split(separator)
+            .map(Host::from_str)
+            .collect::<Result<_, _>>()?;
+
+        Ok(HostList { hosts })
+    }
+
+    /// Returns a copy of the list, the hosts without a port using the default one.
+    pub fn with_default_port(&self, port: u16) -> Self {
+        HostList {
+            hosts: self
+                .hosts
+                .iter()
+                .map(|h| Host {
+                    port: Some(h.port.unwrap_or(port)),
+                    ..h.clone()
+                })
+                .collect(),
+        }
+    }
+}
+
+impl FromStr for HostList {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, ',')
+    }
+}
+
+impl fmt::Display for HostList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, host) in self.hosts.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            host.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn hosts_keep_their_ipv6_brackets_and_reject_bad_ports() {
+    assert_eq!(Host::new("::1", None), "::1".parse().unwrap());
+    assert_eq!("[::1]:6379", Host::new("::1", Some(6379)).to_string());
+    assert_eq!(
+        Host::new("db", Some(27017)),
+        "db:27017".parse::<Host>().unwrap()
+    );
+
+    assert!("db:0".parse::<Host>().is_err());
+    assert!("[db]:1".parse::<Host>().is_err());
+    assert!("[::1]6379".parse::<Host>().is_err());
+    assert!("db1,,db2".parse::<HostList>().is_err());
+}
+
+#[test]
+fn hosts_carry_their_own_options() {
+    let list: HostList = "db1:9042?dc=east&rack=r1,[::1]?dc=west,db3"
+        .parse()
+        .unwrap();
+
+    assert_eq!(Some(9042), list.hosts[0].port);
+    assert_eq!(Some("east"), list.hosts[0].option("DC"));
+    assert_eq!(Some("r1"), list.hosts[0].option("rack"));
+    assert_eq!(Some("west"), list.hosts[1].option("dc"));
+    assert!(list.hosts[2].options.is_empty());
+    assert_eq!(
+        "db1:9042?dc=east&rack=r1,[::1]:9042?dc=west,db3:9042",
+        list.with_default_port(9042).to_string()
+    );
+
+    assert!("db1?dc".parse::<Host>().is_err());
+    assert!("db1?=east".parse::<Host>().is_err());
+}
@@ -0,0 +1,190 @@
+//! InfluxDB connection strings, with the settings of the 2.x API or of the 1.x API.
+
+use crate::keywords;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{decode_bytes, get, parse, to_connection_string, Error, ParseOptions};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// The version of the InfluxDB API a connection string is written for.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ApiVersion {
+    /// The 1.x API, a `database` accessed by a `username` with a `password`.
+    V1,
+
+    /// The 2.x API, a `bucket` of an `org` accessed with a `token`.
+    V2,
+}
+
+/// An InfluxDB connection string, such as `url=https://influx:8086;org=o;bucket=b;token=t`
+/// for the 2.x API or `url=http://influx:8086;database=db;username=u;password=p` for
+/// the 1.x API.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::influx::{ApiVersion, InfluxConnStr};
+/// use std::str::FromStr;
+///
+/// let conn = InfluxConnStr::from_str("url=https://influx:8086;org=myorg;bucket=b;token=abc==").unwrap();
+///
+/// assert_eq!(ApiVersion::V2, conn.api_version());
+/// assert_eq!("https://influx:8086", conn.url());
+/// assert_eq!(Some("myorg"), conn.org());
+/// assert_eq!(Some("b"), conn.bucket());
+/// assert_eq!("bucket=b;org=myorg;token=***;url=https://influx:8086", conn.to_string());
+///
+/// let conn = InfluxConnStr::from_str("url=http://influx:8086;username=u;password=p;database=telegraf").unwrap();
+///
+/// assert_eq!(ApiVersion::V1, conn.api_version());
+/// assert_eq!(Some("telegraf"), conn.database());
+/// ```
+#[derive(Clone)]
+pub struct InfluxConnStr(Arc<HashMap<String, String>>);
+
+impl FromStr for InfluxConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl InfluxConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        Ok(InfluxConnStr(Arc::new(parse(
+            conn_str,
+            false,
+            &keywords::INFLUX,
+            options,
+        )?)))
+    }
+
+    /// Detects the version of the API, the 1.x API when the connection string has a
+    /// `database`, a `username` or a `password` and neither an `org`, a `bucket` nor a
+    /// `token`, and the 2.x API otherwise.
+    pub fn api_version(&self) -> ApiVersion {
+        let has = |key| self.get(key).is_some();
+
+        match ["org", "bucket", "token"].iter().any(|k| has(k))
+            || !["database", "username", "password"].iter().any(|k| has(k))
+        {
+            true => ApiVersion::V2,
+            false => ApiVersion::V1,
+        }
+    }
+
+    /// Gets the bucket written and queried, with the 2.x API.
+    pub fn bucket(&self) -> Option<&str> {
+        self.get("bucket")
+    }
+
+    /// Gets the database written and queried, with the 1.x API.
+    pub fn database(&self) -> Option<&str> {
+        self.get("database")
+    }
+
+    /// Gets the organization of the bucket, with the 2.x API.
+    pub fn org(&self) -> Option<&str> {
+        self.get("org")
+    }
+
+    #[cfg(not(feature = "secret"))]
+    pub fn password(&self) -> Option<&str> {
+        self.get("password")
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("password").map(crate::secret::Secret::new)
+    }
+
+    /// Gets the retention policy of the database, with the 1.x API.
+    pub fn retention_policy(&self) -> Option<&str> {
+        self.get("retentionpolicy")
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.0, &keywords::INFLUX, false, false)
+    }
+
+    /// Gets the API token, with the 2.x API.
+    #[cfg(not(feature = "secret"))]
+    pub fn token(&self) -> Option<&str> {
+        self.get("token")
+    }
+
+    /// Gets the API token, with the 2.x API.
+    #[cfg(feature = "secret")]
+    pub fn token(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("token").map(crate::secret::Secret::new)
+    }
+
+    /// Gets the url of the server, `http://localhost:8086` when missing.
+    pub fn url(&self) -> &str {
+        match self.get("url").map(str::trim) {
+            Some(url) if !url.is_empty() => url,
+            _ => "http://localhost:8086",
+        }
+    }
+
+    pub fn username(&self) -> Option<&str> {
+        self.get("username")
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        get(&self.0, &keywords::INFLUX, name)
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.0, &keywords::INFLUX, validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for InfluxConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(
+            &self.0,
+            &keywords::INFLUX,
+            false,
+            true,
+        ))
+    }
+}
+
+#[test]
+fn api_version_defaults_to_v2() {
+    let conn = InfluxConnStr::from_str("User=u;Password=p;DB=db;RP=autogen").unwrap();
+
+    assert_eq!(ApiVersion::V1, conn.api_version());
+    assert_eq!(Some("autogen"), conn.retention_policy());
+    assert_eq!("http://localhost:8086", conn.url());
+    assert_eq!("db=db;password=***;rp=autogen;user=u", conn.to_string());
+
+    let conn = InfluxConnStr::from_str("Database=db;Token=u:p").unwrap();
+    assert_eq!(ApiVersion::V2, conn.api_version());
+    assert!(conn.token().is_some());
+    assert_eq!(
+        ApiVersion::V2,
+        InfluxConnStr::from_str("url=http://h:8086")
+            .unwrap()
+            .api_version()
+    );
+}
@@ -0,0 +1,231 @@
+//! Informix connection strings, as parsed by the IBM Informix .NET provider.
+
+use crate::keywords;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{decode_bytes, get, parse, parse_bool, to_connection_string, Error, ParseOptions};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// The `Protocol` of an Informix connection.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Protocol {
+    /// `onsoctcp`, TCP/IP sockets.
+    SocTcp,
+
+    /// `ontlitcp`, TCP/IP through the transport layer interface.
+    TliTcp,
+
+    /// `onsocssl`, TCP/IP sockets encrypted with SSL.
+    SocSsl,
+
+    /// `onipcshm`, shared memory, the server being on the same host.
+    IpcShm,
+
+    /// `onipcstr`, stream pipes, the server being on the same host.
+    IpcStr,
+
+    /// `onipcnmp`, named pipes, the server being on the same host.
+    IpcNmp,
+
+    /// `drsoctcp`, the DRDA protocol over TCP/IP sockets.
+    DrdaSocTcp,
+
+    /// `drtlitcp`, the DRDA protocol through the transport layer interface.
+    DrdaTliTcp,
+
+    /// `drsocssl`, the DRDA protocol over TCP/IP sockets encrypted with SSL.
+    DrdaSocSsl,
+}
+
+impl Protocol {
+    /// Indicates if the connections are encrypted with SSL.
+    pub fn is_ssl(self) -> bool {
+        matches!(self, Protocol::SocSsl | Protocol::DrdaSocSsl)
+    }
+}
+
+impl FromStr for Protocol {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "onsoctcp" => Ok(Protocol::SocTcp),
+            "ontlitcp" => Ok(Protocol::TliTcp),
+            "onsocssl" => Ok(Protocol::SocSsl),
+            "onipcshm" => Ok(Protocol::IpcShm),
+            "onipcstr" => Ok(Protocol::IpcStr),
+            "onipcnmp" => Ok(Protocol::IpcNmp),
+            "drsoctcp" => Ok(Protocol::DrdaSocTcp),
+            "drtlitcp" => Ok(Protocol::DrdaTliTcp),
+            "drsocssl" => Ok(Protocol::DrdaSocSsl),
+            _ => Err(Error::InvalidValue {
+                key: "protocol".to_owned(),
+                value: s.to_owned(),
+                expected: keywords::INFORMIX.get("protocol").unwrap().kind.to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Protocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Protocol::SocTcp => "onsoctcp",
+            Protocol::TliTcp => "ontlitcp",
+            Protocol::SocSsl => "onsocssl",
+            Protocol::IpcShm => "onipcshm",
+            Protocol::IpcStr => "onipcstr",
+            Protocol::IpcNmp => "onipcnmp",
+            Protocol::DrdaSocTcp => "drsoctcp",
+            Protocol::DrdaTliTcp => "drtlitcp",
+            Protocol::DrdaSocSsl => "drsocssl",
+        })
+    }
+}
+
+/// An Informix .NET provider connection string.
+///
+/// The `Server` names the database server instance, the `INFORMIXSERVER`, the machine
+/// being the `Host` and its port the `Service`.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::informix::{IfxConnStr, Protocol};
+/// use std::str::FromStr;
+///
+/// let conn = IfxConnStr::from_str("Host=h;Service=9088;Server=ol_informix;Database=db;User ID=u;Password=p;Protocol=onsoctcp").unwrap();
+///
+/// assert_eq!(Some("h"), conn.host());
+/// assert_eq!(Some("9088"), conn.service());
+/// assert_eq!(Some(9088), conn.port());
+/// assert_eq!(Some("ol_informix"), conn.server());
+/// assert_eq!(Some(Protocol::SocTcp), conn.protocol().unwrap());
+/// assert_eq!(Some("db"), conn.database());
+/// assert_eq!(Some("u"), conn.user_id());
+/// ```
+#[derive(Clone)]
+pub struct IfxConnStr(Arc<HashMap<String, String>>);
+
+impl FromStr for IfxConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl IfxConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        Ok(IfxConnStr(Arc::new(parse(
+            conn_str,
+            false,
+            &keywords::INFORMIX,
+            options,
+        )?)))
+    }
+
+    pub fn database(&self) -> Option<&str> {
+        self.get("database")
+    }
+
+    /// Gets the machine of the database server.
+    pub fn host(&self) -> Option<&str> {
+        self.get("host")
+    }
+
+    #[cfg(not(feature = "secret"))]
+    pub fn password(&self) -> Option<&str> {
+        self.get("password")
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("password").map(crate::secret::Secret::new)
+    }
+
+    pub fn pooling(&self) -> Result<bool, Error> {
+        match self.get("pooling") {
+            Some(v) => parse_bool(v),
+            None => Ok(true),
+        }
+    }
+
+    /// Gets the port of the `Service`, `None` when the service is missing or is a
+    /// service name of the services file.
+    pub fn port(&self) -> Option<u16> {
+        self.service()?.trim().parse().ok().filter(|p| *p > 0)
+    }
+
+    pub fn protocol(&self) -> Result<Option<Protocol>, Error> {
+        self.get("protocol").map(str::parse).transpose()
+    }
+
+    /// Gets the name of the database server instance, the `INFORMIXSERVER`.
+    pub fn server(&self) -> Option<&str> {
+        self.get("server")
+    }
+
+    /// Gets the port number or the service name of the database server.
+    pub fn service(&self) -> Option<&str> {
+        self.get("service")
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.0, &keywords::INFORMIX, false, false)
+    }
+
+    pub fn user_id(&self) -> Option<&str> {
+        self.get("user id")
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        get(&self.0, &keywords::INFORMIX, name)
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.0, &keywords::INFORMIX, validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for IfxConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(
+            &self.0,
+            &keywords::INFORMIX,
+            false,
+            true,
+        ))
+    }
+}
+
+#[test]
+fn services_may_be_named() {
+    let conn =
+        IfxConnStr::from_str("Host=h;Serv=sqlexec;Srv=ol_informix;Pro=ONSOCSSL;UID=u").unwrap();
+
+    assert_eq!(Some("sqlexec"), conn.service());
+    assert_eq!(None, conn.port());
+    assert!(conn.protocol().unwrap().unwrap().is_ssl());
+    assert_eq!(Some("u"), conn.user_id());
+
+    let conn = IfxConnStr::from_str("Protocol=tcp").unwrap();
+    assert!(conn.protocol().is_err());
+}
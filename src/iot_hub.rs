@@ -0,0 +1,230 @@
+//! Azure IoT Hub connection strings, as parsed by the Azure IoT device and service
+//! SDKs.
+
+use crate::keywords;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{decode_bytes, get, parse, parse_bool, to_connection_string, Error, ParseOptions};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// The identity authenticated by an IoT Hub connection string.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Identity {
+    /// A device, with a `DeviceId`.
+    Device,
+
+    /// A module of a device, with a `DeviceId` and a `ModuleId`.
+    Module,
+
+    /// A back-end service, with the `SharedAccessKeyName` of a policy of the hub.
+    Service,
+}
+
+/// An Azure IoT Hub connection string of a device, of a module or of a service.
+///
+/// The parsing checks that the connection string names its hub, its identity and
+/// its credentials, a `SharedAccessKey`, a `SharedAccessSignature` or `x509=true`.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::iot_hub::{Identity, IotHubConnStr};
+/// use std::str::FromStr;
+///
+/// let conn = IotHubConnStr::from_str("HostName=hub.azure-devices.net;DeviceId=dev1;SharedAccessKey=abc=").unwrap();
+///
+/// assert_eq!(Some("hub.azure-devices.net"), conn.host_name());
+/// assert_eq!(Some("hub"), conn.hub_name());
+/// assert_eq!(Some("dev1"), conn.device_id());
+/// assert_eq!(Identity::Device, conn.identity());
+/// assert_eq!("deviceid=dev1;hostname=hub.azure-devices.net;sharedaccesskey=***", conn.to_string());
+///
+/// let conn = IotHubConnStr::from_str("HostName=hub.azure-devices.net;SharedAccessKeyName=iothubowner;SharedAccessKey=abc=").unwrap();
+///
+/// assert_eq!(Identity::Service, conn.identity());
+/// assert!(IotHubConnStr::from_str("HostName=hub.azure-devices.net;DeviceId=dev1").is_err());
+/// ```
+#[derive(Clone)]
+pub struct IotHubConnStr(Arc<HashMap<String, String>>);
+
+impl FromStr for IotHubConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl IotHubConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    /// Parses the connection string, which requires a `HostName`, a `DeviceId` or a
+    /// `SharedAccessKeyName`, and credentials.
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        let conn = IotHubConnStr(Arc::new(parse(
+            conn_str,
+            false,
+            &keywords::IOT_HUB,
+            options,
+        )?));
+
+        let missing = |key: &str| Err(Error::MissingKey(key.to_owned()));
+        let has = |key: &str| conn.get(key).is_some_and(|v| !v.trim().is_empty());
+
+        if !has("hostname") {
+            return missing("hostname");
+        }
+
+        if !has("deviceid") && (has("moduleid") || !has("sharedaccesskeyname")) {
+            return missing("deviceid");
+        }
+
+        if !has("sharedaccesskey") && !has("sharedaccesssignature") && !conn.x509()? {
+            return missing("sharedaccesskey");
+        }
+
+        Ok(conn)
+    }
+
+    /// Gets the identifier of the device.
+    pub fn device_id(&self) -> Option<&str> {
+        self.get("deviceid")
+    }
+
+    /// Gets the host of the IoT Edge gateway relaying the device.
+    pub fn gateway_host_name(&self) -> Option<&str> {
+        self.get("gatewayhostname")
+    }
+
+    /// Gets the host of the hub, `<hub>.azure-devices.net`.
+    pub fn host_name(&self) -> Option<&str> {
+        self.get("hostname")
+    }
+
+    /// Gets the name of the hub, the first label of the `HostName`.
+    pub fn hub_name(&self) -> Option<&str> {
+        self.host_name()
+            .and_then(|host| host.trim().split('.').next())
+            .filter(|name| !name.is_empty())
+    }
+
+    /// Gets the identity authenticated, a module when a `ModuleId` is set.
+    pub fn identity(&self) -> Identity {
+        match (self.device_id(), self.module_id()) {
+            (Some(_), Some(module)) if !module.trim().is_empty() => Identity::Module,
+            (Some(device), _) if !device.trim().is_empty() => Identity::Device,
+            _ => Identity::Service,
+        }
+    }
+
+    /// Gets the identifier of the module of the device.
+    pub fn module_id(&self) -> Option<&str> {
+        self.get("moduleid")
+    }
+
+    /// Gets the symmetric key of the device, of the module or of the policy.
+    #[cfg(not(feature = "secret"))]
+    pub fn shared_access_key(&self) -> Option<&str> {
+        self.get("sharedaccesskey")
+    }
+
+    /// Gets the symmetric key of the device, of the module or of the policy.
+    #[cfg(feature = "secret")]
+    pub fn shared_access_key(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("sharedaccesskey").map(crate::secret::Secret::new)
+    }
+
+    /// Gets the name of the shared access policy of a service.
+    pub fn shared_access_key_name(&self) -> Option<&str> {
+        self.get("sharedaccesskeyname")
+    }
+
+    /// Gets the shared access signature used instead of the key.
+    #[cfg(not(feature = "secret"))]
+    pub fn shared_access_signature(&self) -> Option<&str> {
+        self.get("sharedaccesssignature")
+    }
+
+    /// Gets the shared access signature used instead of the key.
+    #[cfg(feature = "secret")]
+    pub fn shared_access_signature(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("sharedaccesssignature")
+            .map(crate::secret::Secret::new)
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.0, &keywords::IOT_HUB, false, false)
+    }
+
+    /// Indicates if the device authenticates with its X.509 certificate,
+    /// `x509=true`.
+    pub fn x509(&self) -> Result<bool, Error> {
+        match self.get("x509") {
+            Some(v) => parse_bool(v.trim()),
+            None => Ok(false),
+        }
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        get(&self.0, &keywords::IOT_HUB, name)
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.0, &keywords::IOT_HUB, validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for IotHubConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(
+            &self.0,
+            &keywords::IOT_HUB,
+            false,
+            true,
+        ))
+    }
+}
+
+#[test]
+fn modules_and_certificates_are_recognized() {
+    let conn = IotHubConnStr::from_str(
+        "HostName=hub.azure-devices.net;DeviceId=dev1;ModuleId=mod1;x509=true;GatewayHostName=edge.local",
+    )
+    .unwrap();
+
+    assert_eq!(Identity::Module, conn.identity());
+    assert!(conn.x509().unwrap());
+    assert_eq!(Some("edge.local"), conn.gateway_host_name());
+
+    let missing = |s: &str| match IotHubConnStr::from_str(s) {
+        Err(Error::MissingKey(key)) => key,
+        _ => panic!("{} should be missing a key", s),
+    };
+
+    assert_eq!("hostname", missing("DeviceId=d;SharedAccessKey=k"));
+    assert_eq!(
+        "deviceid",
+        missing("HostName=h;ModuleId=m;SharedAccessKey=k")
+    );
+    assert_eq!("deviceid", missing("HostName=h;SharedAccessKey=k"));
+    assert_eq!(
+        "sharedaccesskey",
+        missing("HostName=h;DeviceId=d;x509=false")
+    );
+    assert!(IotHubConnStr::from_str("HostName=h;DeviceId=d;x509=maybe").is_err());
+}
@@ -0,0 +1,294 @@
+//! Kafka client configurations written as connection strings, the properties of
+//! librdkafka and of the Java client separated by semicolons.
+
+use crate::host::{Host, HostList};
+use crate::keywords;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{decode_bytes, get, parse, to_connection_string, Error, ParseOptions};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// The `security.protocol` of the connections to the brokers.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SecurityProtocol {
+    /// `PLAINTEXT`, neither encrypted nor authenticated.
+    Plaintext,
+
+    /// `SSL`, encrypted with TLS.
+    Ssl,
+
+    /// `SASL_PLAINTEXT`, authenticated with SASL without TLS.
+    SaslPlaintext,
+
+    /// `SASL_SSL`, authenticated with SASL and encrypted with TLS.
+    SaslSsl,
+}
+
+impl SecurityProtocol {
+    /// Indicates if the protocol authenticates with SASL.
+    pub fn is_sasl(self) -> bool {
+        matches!(
+            self,
+            SecurityProtocol::SaslPlaintext | SecurityProtocol::SaslSsl
+        )
+    }
+
+    /// Indicates if the protocol encrypts with TLS.
+    pub fn is_tls(self) -> bool {
+        matches!(self, SecurityProtocol::Ssl | SecurityProtocol::SaslSsl)
+    }
+}
+
+impl FromStr for SecurityProtocol {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_uppercase().as_str() {
+            "PLAINTEXT" => Ok(SecurityProtocol::Plaintext),
+            "SSL" => Ok(SecurityProtocol::Ssl),
+            "SASL_PLAINTEXT" => Ok(SecurityProtocol::SaslPlaintext),
+            "SASL_SSL" => Ok(SecurityProtocol::SaslSsl),
+            _ => Err(Error::InvalidValue {
+                key: "security.protocol".to_owned(),
+                value: s.to_owned(),
+                expected: keywords::KAFKA
+                    .get("security.protocol")
+                    .unwrap()
+                    .kind
+                    .to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for SecurityProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SecurityProtocol::Plaintext => "PLAINTEXT",
+            SecurityProtocol::Ssl => "SSL",
+            SecurityProtocol::SaslPlaintext => "SASL_PLAINTEXT",
+            SecurityProtocol::SaslSsl => "SASL_SSL",
+        })
+    }
+}
+
+/// The `sasl.mechanisms` of the authentication.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SaslMechanism {
+    /// `GSSAPI`, Kerberos.
+    Gssapi,
+
+    /// `PLAIN`, the user and the password in clear, within TLS.
+    Plain,
+
+    /// `SCRAM-SHA-256`, a challenge on the password.
+    ScramSha256,
+
+    /// `SCRAM-SHA-512`, a challenge on the password.
+    ScramSha512,
+
+    /// `OAUTHBEARER`, an OAuth token.
+    OAuthBearer,
+}
+
+impl FromStr for SaslMechanism {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_uppercase().as_str() {
+            "GSSAPI" => Ok(SaslMechanism::Gssapi),
+            "PLAIN" => Ok(SaslMechanism::Plain),
+            "SCRAM-SHA-256" => Ok(SaslMechanism::ScramSha256),
+            "SCRAM-SHA-512" => Ok(SaslMechanism::ScramSha512),
+            "OAUTHBEARER" => Ok(SaslMechanism::OAuthBearer),
+            _ => Err(Error::InvalidValue {
+                key: "sasl.mechanisms".to_owned(),
+                value: s.to_owned(),
+                expected: keywords::KAFKA
+                    .get("sasl.mechanisms")
+                    .unwrap()
+                    .kind
+                    .to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for SaslMechanism {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SaslMechanism::Gssapi => "GSSAPI",
+            SaslMechanism::Plain => "PLAIN",
+            SaslMechanism::ScramSha256 => "SCRAM-SHA-256",
+            SaslMechanism::ScramSha512 => "SCRAM-SHA-512",
+            SaslMechanism::OAuthBearer => "OAUTHBEARER",
+        })
+    }
+}
+
+/// A Kafka client configuration, `property=value` pairs separated by semicolons.
+///
+/// The properties keep the names of librdkafka and of the Java client, such as
+/// `bootstrap.servers` or `sasl.username`, `sasl.mechanism` being a synonym of
+/// `sasl.mechanisms`.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::host::Host;
+/// use conn_str::kafka::{KafkaConnStr, SecurityProtocol};
+/// use std::str::FromStr;
+///
+/// let conn = KafkaConnStr::from_str("bootstrap.servers=b1:9092,b2:9092;security.protocol=SASL_SSL;sasl.username=u;sasl.password=p").unwrap();
+///
+/// assert_eq!(
+///     vec![Host::new("b1", Some(9092)), Host::new("b2", Some(9092))],
+///     conn.brokers().unwrap(),
+/// );
+/// assert_eq!(SecurityProtocol::SaslSsl, conn.security_protocol().unwrap());
+/// assert_eq!(Some("u"), conn.sasl_username());
+/// assert_eq!(
+///     "bootstrap.servers=b1:9092,b2:9092;sasl.password=***;sasl.username=u;security.protocol=SASL_SSL",
+///     conn.to_string(),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct KafkaConnStr(Arc<HashMap<String, String>>);
+
+impl FromStr for KafkaConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl KafkaConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        Ok(KafkaConnStr(Arc::new(parse(
+            conn_str,
+            false,
+            &keywords::KAFKA,
+            options,
+        )?)))
+    }
+
+    /// Gets the brokers contacted first, empty when missing, the port 9092 being used
+    /// for the brokers without one.
+    pub fn brokers(&self) -> Result<Vec<Host>, Error> {
+        match self.get("bootstrap.servers") {
+            Some(servers) if !servers.trim().is_empty() => Ok(HostList::parse_with(servers, ',')?
+                .with_default_port(9092)
+                .hosts),
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    pub fn client_id(&self) -> Option<&str> {
+        self.get("client.id")
+    }
+
+    /// Gets the consumer group of a consumer.
+    pub fn group_id(&self) -> Option<&str> {
+        self.get("group.id")
+    }
+
+    /// Gets the SASL mechanism, `GSSAPI` when missing.
+    pub fn sasl_mechanism(&self) -> Result<SaslMechanism, Error> {
+        match self.get("sasl.mechanisms") {
+            Some(v) => v.parse(),
+            None => Ok(SaslMechanism::Gssapi),
+        }
+    }
+
+    /// Gets the password of the PLAIN and SCRAM mechanisms.
+    #[cfg(not(feature = "secret"))]
+    pub fn sasl_password(&self) -> Option<&str> {
+        self.get("sasl.password")
+    }
+
+    /// Gets the password of the PLAIN and SCRAM mechanisms.
+    #[cfg(feature = "secret")]
+    pub fn sasl_password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("sasl.password").map(crate::secret::Secret::new)
+    }
+
+    /// Gets the user of the PLAIN and SCRAM mechanisms.
+    pub fn sasl_username(&self) -> Option<&str> {
+        self.get("sasl.username")
+    }
+
+    /// Gets the protocol of the connections to the brokers, `PLAINTEXT` when
+    /// missing.
+    pub fn security_protocol(&self) -> Result<SecurityProtocol, Error> {
+        match self.get("security.protocol") {
+            Some(v) => v.parse(),
+            None => Ok(SecurityProtocol::Plaintext),
+        }
+    }
+
+    /// Gets the path of the certificates of the authorities trusted.
+    pub fn ssl_ca_location(&self) -> Option<&str> {
+        self.get("ssl.ca.location")
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.0, &keywords::KAFKA, false, false)
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        get(&self.0, &keywords::KAFKA, name)
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.0, &keywords::KAFKA, validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for KafkaConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(
+            &self.0,
+            &keywords::KAFKA,
+            false,
+            true,
+        ))
+    }
+}
+
+#[test]
+fn brokers_use_the_default_port() {
+    let conn = KafkaConnStr::from_str(
+        "metadata.broker.list=b1, [::1]:9093;sasl.mechanism=scram-sha-512;sasl.jaas.config=\"x required password='p';\"",
+    )
+    .unwrap();
+
+    assert_eq!(
+        vec![Host::new("b1", Some(9092)), Host::new("::1", Some(9093))],
+        conn.brokers().unwrap(),
+    );
+    assert_eq!(SaslMechanism::ScramSha512, conn.sasl_mechanism().unwrap());
+    assert!(!conn.security_protocol().unwrap().is_sasl());
+    assert!(conn.to_string().contains("sasl.jaas.config=***"));
+
+    let conn = KafkaConnStr::from_str("security.protocol=TLS").unwrap();
+    assert!(conn.security_protocol().is_err());
+    assert!(conn.brokers().unwrap().is_empty());
+}
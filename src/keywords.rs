@@ -0,0 +1,4151 @@
+//! Keyword tables of the supported connection string formats.
+//!
+//! The tables are static sorted slices searched with a binary search, so they cost
+//! nothing at startup. They are used both by the getters of the typed connection
+//! strings and by the strict parsing, which keeps the synonyms consistent.
+//!
+//! Each keyword also carries metadata (kind of value, default value and description)
+//! so that tools can generate forms and tooltips from the tables.
+
+use std::fmt;
+
+/// A connection string keyword and its synonyms.
+#[derive(Debug)]
+pub struct Keyword {
+    /// The canonical name of the keyword, in lower case.
+    pub name: &'static str,
+
+    /// The synonyms of the keyword, in lower case, by order of precedence.
+    pub synonyms: &'static [&'static str],
+
+    /// A short description of the keyword.
+    pub description: &'static str,
+
+    /// The kind of value accepted by the keyword.
+    pub kind: ValueKind,
+
+    /// The value used by the provider when the keyword is missing.
+    pub default: Option<&'static str>,
+
+    /// Set when the keyword is deprecated or ignored by the provider.
+    pub deprecated: Option<Deprecation>,
+
+    /// The synonyms that were renamed to the canonical name and should be replaced by it.
+    pub deprecated_synonyms: &'static [&'static str],
+
+    /// Set when the value identifies the user, such as a user name or a key name.
+    pub credential: bool,
+}
+
+impl Keyword {
+    const fn new(
+        name: &'static str,
+        synonyms: &'static [&'static str],
+        description: &'static str,
+    ) -> Self {
+        Keyword {
+            name,
+            synonyms,
+            description,
+            kind: ValueKind::Text,
+            default: None,
+            deprecated: None,
+            deprecated_synonyms: &[],
+            credential: false,
+        }
+    }
+
+    const fn default_value(self, default: &'static str) -> Self {
+        Keyword {
+            default: Some(default),
+            ..self
+        }
+    }
+
+    const fn deprecated(self, replacement: Option<&'static str>, reason: &'static str) -> Self {
+        Keyword {
+            deprecated: Some(Deprecation {
+                replacement,
+                reason,
+            }),
+            ..self
+        }
+    }
+
+    const fn deprecated_synonyms(self, deprecated_synonyms: &'static [&'static str]) -> Self {
+        Keyword {
+            deprecated_synonyms,
+            ..self
+        }
+    }
+
+    const fn kind(self, kind: ValueKind) -> Self {
+        Keyword { kind, ..self }
+    }
+
+    const fn credential(self) -> Self {
+        Keyword {
+            credential: true,
+            ..self
+        }
+    }
+
+    /// Indicates if the value is a secret, which should be redacted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::keywords::{ValueKind, MS_SQL};
+    ///
+    /// assert!(MS_SQL.find("pwd").unwrap().is_sensitive());
+    /// assert_eq!(
+    ///     ValueKind::Duration { min: 1, max: 60 },
+    ///     MS_SQL.find("connect retry interval").unwrap().value_kind(),
+    /// );
+    /// ```
+    pub fn is_sensitive(&self) -> bool {
+        self.kind == ValueKind::Secret
+    }
+
+    /// Indicates if the value is a credential, either a secret or the identity of the
+    /// user.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::keywords::LDAP;
+    ///
+    /// assert!(LDAP.find("user").unwrap().is_credential());
+    /// assert!(!LDAP.find("basedn").unwrap().is_credential());
+    /// ```
+    pub fn is_credential(&self) -> bool {
+        self.credential || self.is_sensitive()
+    }
+
+    /// Gets the kind of value accepted by the keyword.
+    pub fn value_kind(&self) -> ValueKind {
+        self.kind
+    }
+
+    /// Iterates over the canonical name followed by the synonyms.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> {
+        Some(self.name)
+            .into_iter()
+            .chain(self.synonyms.iter().cloned())
+    }
+}
+
+/// Describes why a keyword is deprecated and what should be used instead.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Deprecation {
+    /// The keyword to use instead, if any.
+    pub replacement: Option<&'static str>,
+
+    pub reason: &'static str,
+}
+
+/// The kind of value accepted by a keyword.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ValueKind {
+    /// `true`, `false`, `yes` or `no`, ignoring case.
+    Bool,
+
+    /// One of the listed values, ignoring case.
+    Enum(&'static [&'static str]),
+
+    /// A number of seconds between `min` and `max` inclusively.
+    Duration { min: i64, max: i64 },
+
+    /// An integer between `min` and `max` inclusively.
+    Int { min: i64, max: i64 },
+
+    /// A list of values delimited by a separator.
+    List { separator: char },
+
+    /// A file or directory path.
+    Path,
+
+    /// A secret such as a password, which should never be displayed.
+    Secret,
+
+    /// Any text.
+    Text,
+}
+
+impl ValueKind {
+    /// Indicates if a value is accepted by this kind of keyword.
+    pub fn accepts(&self, value: &str) -> bool {
+        match self {
+            ValueKind::Bool => crate::parse_bool(value).is_ok(),
+            ValueKind::Enum(values) => values.iter().any(|v| v.eq_ignore_ascii_case(value.trim())),
+            ValueKind::Duration { min, max } | ValueKind::Int { min, max } => {
+                match value.trim().parse::<i64>() {
+                    Ok(v) => *min <= v && v <= *max,
+                    Err(_) => false,
+                }
+            }
+            ValueKind::List { .. } | ValueKind::Path | ValueKind::Secret | ValueKind::Text => true,
+        }
+    }
+}
+
+impl fmt::Display for ValueKind {
+    /// Describes the values accepted, for use in error messages.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValueKind::Bool => f.write_str("`true`, `false`, `yes` or `no`"),
+            ValueKind::Enum(values) => {
+                f.write_str("one of ")?;
+
+                for (i, v) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "`{}`", v)?;
+                }
+
+                Ok(())
+            }
+            ValueKind::Duration { min, max } if *max == i64::from(i32::MAX) => {
+                write!(f, "a number of seconds greater than or equal to {}", min)
+            }
+            ValueKind::Duration { min, max } => {
+                write!(f, "a number of seconds between {} and {}", min, max)
+            }
+            ValueKind::Int { min, max } if *max == i64::from(i32::MAX) => {
+                write!(f, "an integer greater than or equal to {}", min)
+            }
+            ValueKind::Int { min, max } => write!(f, "an integer between {} and {}", min, max),
+            ValueKind::List { separator } => write!(f, "a list separated by `{}`", separator),
+            ValueKind::Path => f.write_str("a path"),
+            ValueKind::Secret => f.write_str("a secret"),
+            ValueKind::Text => f.write_str("any text"),
+        }
+    }
+}
+
+/// Any non negative 32 bits integer.
+const POSITIVE_INT: ValueKind = ValueKind::Int {
+    min: 0,
+    max: i32::MAX as i64,
+};
+
+/// A boolean written `1` or `0` by the ODBC and JDBC drivers, or `true` or `false`.
+const FLAG: ValueKind = ValueKind::Enum(&["1", "0", "true", "false", "yes", "no"]);
+
+/// Any non negative 32 bits number of seconds.
+const POSITIVE_SECONDS: ValueKind = ValueKind::Duration {
+    min: 0,
+    max: i32::MAX as i64,
+};
+
+/// The keyword table of a connection string format.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::keywords::MS_SQL;
+///
+/// assert_eq!("data source", MS_SQL.find("Server").unwrap().name);
+/// assert!(MS_SQL.find("not a keyword").is_none());
+/// ```
+#[derive(Debug)]
+pub struct Keywords {
+    /// Keywords sorted by canonical name.
+    keywords: &'static [Keyword],
+
+    /// Every canonical name and synonym sorted, with its canonical name.
+    lookup: &'static [(&'static str, &'static str)],
+}
+
+impl Keywords {
+    /// Finds a keyword by its canonical name or one of its synonyms, ignoring case.
+    pub fn find(&self, name: &str) -> Option<&'static Keyword> {
+        let name = name.trim().to_lowercase();
+        let canonical = self
+            .lookup
+            .binary_search_by(|(n, _)| (*n).cmp(name.as_str()))
+            .ok()
+            .map(|i| self.lookup[i].1)?;
+
+        self.get(canonical)
+    }
+
+    /// Gets a keyword by its canonical name.
+    pub fn get(&self, name: &str) -> Option<&'static Keyword> {
+        let keywords = self.keywords;
+
+        keywords
+            .binary_search_by(|k| k.name.cmp(name))
+            .ok()
+            .map(|i| &keywords[i])
+    }
+
+    /// Iterates over the deprecated keywords.
+    pub fn deprecated(&self) -> impl Iterator<Item = &'static Keyword> {
+        self.iter().filter(|k| k.deprecated.is_some())
+    }
+
+    /// Iterates over the keywords, sorted by canonical name.
+    pub fn iter(&self) -> impl Iterator<Item = &'static Keyword> {
+        self.keywords.iter()
+    }
+}
+
+/// The lookup of the keys used by the parsing.
+pub(crate) trait Lookup {
+    /// Finds the canonical name and the kind of value of a key, and whether it holds
+    /// a credential.
+    fn lookup(&self, key: &str) -> Option<(&str, ValueKind, bool)>;
+
+    /// Gets the deprecation of a key, or of the synonym it was renamed from.
+    fn deprecation(&self, _key: &str) -> Option<Deprecation> {
+        None
+    }
+}
+
+impl Lookup for Keywords {
+    fn lookup(&self, key: &str) -> Option<(&str, ValueKind, bool)> {
+        self.find(key).map(|k| (k.name, k.kind, k.is_credential()))
+    }
+
+    fn deprecation(&self, key: &str) -> Option<Deprecation> {
+        let keyword = self.find(key)?;
+
+        match keyword.deprecated {
+            Some(deprecation) => Some(deprecation),
+            None if keyword.deprecated_synonyms.contains(&key.trim()) => Some(Deprecation {
+                replacement: Some(keyword.name),
+                reason: "the synonym was renamed",
+            }),
+            None => None,
+        }
+    }
+}
+
+/// Keywords of the Entity Framework connection string.
+pub static EF: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "metadata",
+            &[],
+            "Pipe separated list of directories, files and resources of the model metadata.",
+        )
+        .kind(ValueKind::List { separator: '|' }),
+        Keyword::new(
+            "name",
+            &[],
+            "Name of a connection string defined in the configuration file.",
+        ),
+        Keyword::new(
+            "provider",
+            &[],
+            "Invariant name of the underlying ADO.NET provider.",
+        ),
+        Keyword::new(
+            "provider connection string",
+            &[],
+            "Connection string of the underlying ADO.NET provider.",
+        ),
+    ],
+    lookup: &[
+        ("metadata", "metadata"),
+        ("name", "name"),
+        ("provider", "provider"),
+        ("provider connection string", "provider connection string"),
+    ],
+};
+
+/// Keywords of the MS SQL connection string (System.Data.SqlClient).
+pub static MS_SQL: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "access token",
+            &["accesstoken"],
+            "Azure Active Directory access token used instead of a user id and a password.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new(
+            "application intent",
+            &["applicationintent"],
+            "Application workload type when connecting to an availability group.",
+        )
+        .kind(ValueKind::Enum(&["ReadOnly", "ReadWrite"]))
+        .default_value("ReadWrite")
+        .deprecated_synonyms(&["applicationintent"]),
+        Keyword::new(
+            "application name",
+            &["app"],
+            "Name of the application reported to the server.",
+        )
+        .default_value(".Net SqlClient Data Provider"),
+        Keyword::new(
+            "asynchronous processing",
+            &["async"],
+            "Enables asynchronous operations.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false")
+        .deprecated(
+            None,
+            "ignored since .NET Framework 4.5, asynchronous operations are always enabled",
+        ),
+        Keyword::new(
+            "attachdbfilename",
+            &["extended properties", "initial file name"],
+            "Path of the primary database file to attach.",
+        )
+        .kind(ValueKind::Path),
+        Keyword::new(
+            "authentication",
+            &[],
+            "Authentication method used to connect to the server.",
+        )
+        .kind(ValueKind::Enum(&[
+            "Sql Password",
+            "SqlPassword",
+            "Active Directory Password",
+            "ActiveDirectoryPassword",
+            "Active Directory Integrated",
+            "ActiveDirectoryIntegrated",
+            "Active Directory Interactive",
+            "ActiveDirectoryInteractive",
+            "Active Directory Service Principal",
+            "ActiveDirectoryServicePrincipal",
+            "Active Directory Device Code Flow",
+            "ActiveDirectoryDeviceCodeFlow",
+            "Active Directory Managed Identity",
+            "ActiveDirectoryManagedIdentity",
+            "Active Directory MSI",
+            "ActiveDirectoryMSI",
+            "Active Directory Default",
+            "ActiveDirectoryDefault",
+            "Active Directory Workload Identity",
+            "ActiveDirectoryWorkloadIdentity",
+        ])),
+        Keyword::new(
+            "column encryption setting",
+            &[],
+            "Enables the Always Encrypted functionality.",
+        )
+        .kind(ValueKind::Enum(&["Enabled", "Disabled"]))
+        .default_value("Disabled"),
+        Keyword::new(
+            "command timeout",
+            &[],
+            "Default wait time in seconds before terminating a command.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("30"),
+        Keyword::new(
+            "connect retry count",
+            &["connectretrycount"],
+            "Number of reconnections attempted after an idle connection failure.",
+        )
+        .kind(ValueKind::Int { min: 0, max: 255 })
+        .default_value("1")
+        .deprecated_synonyms(&["connectretrycount"]),
+        Keyword::new(
+            "connect retry interval",
+            &["connectretryinterval"],
+            "Time in seconds between each reconnection attempt.",
+        )
+        .kind(ValueKind::Duration { min: 1, max: 60 })
+        .default_value("10")
+        .deprecated_synonyms(&["connectretryinterval"]),
+        Keyword::new(
+            "connect timeout",
+            &["connection timeout", "timeout"],
+            "Wait time in seconds before terminating a connection attempt.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("15"),
+        Keyword::new(
+            "connection reset",
+            &[],
+            "Resets the connection state when it is taken from the pool.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("true")
+        .deprecated(None, "ignored, the connection state is always reset"),
+        Keyword::new(
+            "context connection",
+            &[],
+            "Connects to the server hosting the CLR routine.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false")
+        .deprecated(None, "not supported by Microsoft.Data.SqlClient"),
+        Keyword::new(
+            "current language",
+            &["language"],
+            "Language used by the server for messages and date formats.",
+        ),
+        Keyword::new(
+            "data source",
+            &["addr", "address", "network address", "server"],
+            "Name or network address of the server instance.",
+        ),
+        Keyword::new(
+            "encrypt",
+            &[],
+            "Encrypts the data sent between the client and the server, `strict` for TDS 8.0.",
+        )
+        .kind(ValueKind::Enum(&[
+            "true",
+            "false",
+            "yes",
+            "no",
+            "optional",
+            "mandatory",
+            "strict",
+        ]))
+        .default_value("false"),
+        Keyword::new(
+            "enlist",
+            &[],
+            "Enlists the connection in the current transaction context.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("true"),
+        Keyword::new(
+            "failover partner",
+            &[],
+            "Name of the failover partner server of a database mirroring session.",
+        ),
+        Keyword::new(
+            "host name in certificate",
+            &["hostnameincertificate"],
+            "Host name expected in the server certificate, when it differs from the data source.",
+        ),
+        Keyword::new("initial catalog", &["database"], "Name of the database."),
+        Keyword::new(
+            "integrated security",
+            &["integratedsecurity", "trusted_connection"],
+            "Uses the current Windows account credentials for authentication.",
+        )
+        .kind(ValueKind::Enum(&["true", "false", "yes", "no", "sspi"]))
+        .default_value("false"),
+        Keyword::new(
+            "load balance timeout",
+            &["connection lifetime"],
+            "Minimum time in seconds a connection lives in the pool before being destroyed.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("0"),
+        Keyword::new(
+            "max pool size",
+            &[],
+            "Maximum number of connections in the pool.",
+        )
+        .kind(ValueKind::Int {
+            min: 1,
+            max: i32::MAX as i64,
+        })
+        .default_value("100"),
+        Keyword::new(
+            "min pool size",
+            &[],
+            "Minimum number of connections in the pool.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("0"),
+        Keyword::new(
+            "multi subnet failover",
+            &["multisubnetfailover"],
+            "Speeds up the detection of the active server of an availability group listener.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false")
+        .deprecated_synonyms(&["multisubnetfailover"]),
+        Keyword::new(
+            "multiple active result sets",
+            &["multipleactiveresultsets"],
+            "Allows multiple active result sets on a single connection.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false")
+        .deprecated_synonyms(&["multipleactiveresultsets"]),
+        Keyword::new(
+            "network library",
+            &["net", "network"],
+            "Network library used to connect to the server.",
+        )
+        .deprecated(
+            Some("data source"),
+            "not supported on .NET Core, prefix the data source with the protocol instead (ex: `tcp:`)",
+        ),
+        Keyword::new(
+            "packet size",
+            &[],
+            "Size in bytes of the network packets.",
+        )
+        .kind(ValueKind::Int {
+            min: 512,
+            max: 32767,
+        })
+        .default_value("8000"),
+        Keyword::new(
+            "password",
+            &["pwd"],
+            "Password of the SQL Server account.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new(
+            "persist security info",
+            &["persistsecurityinfo"],
+            "Keeps the password in the connection string once the connection is opened.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new("pooling", &[], "Enables the connection pooling.")
+            .kind(ValueKind::Bool)
+            .default_value("true"),
+        Keyword::new(
+            "replication",
+            &[],
+            "Indicates that the connection is used for replication.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "server certificate",
+            &["servercertificate"],
+            "Path of the certificate expected from the server.",
+        )
+        .kind(ValueKind::Path),
+        Keyword::new(
+            "transaction binding",
+            &[],
+            "Binding of the connection with an enlisted transaction.",
+        )
+        .kind(ValueKind::Enum(&["Implicit Unbind", "Explicit Unbind"]))
+        .default_value("Implicit Unbind"),
+        Keyword::new(
+            "trust server certificate",
+            &["trustservercertificate"],
+            "Accepts the server certificate without validating it.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false")
+        .deprecated_synonyms(&["trustservercertificate"]),
+        Keyword::new(
+            "type system version",
+            &[],
+            "Type system version expected by the application.",
+        )
+        .default_value("Latest"),
+        Keyword::new(
+            "user id",
+            &["uid", "user"],
+            "Name of the SQL Server account.",
+        )
+        .credential(),
+        Keyword::new(
+            "user instance",
+            &[],
+            "Redirects the connection to a user instance of SQL Server Express.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false")
+        .deprecated(
+            Some("attachdbfilename"),
+            "user instances are deprecated, attach the database file to LocalDB instead",
+        ),
+        Keyword::new(
+            "workstation id",
+            &["wsid"],
+            "Name of the workstation connecting to the server.",
+        ),
+    ],
+    lookup: &[
+        ("access token", "access token"),
+        ("accesstoken", "access token"),
+        ("addr", "data source"),
+        ("address", "data source"),
+        ("app", "application name"),
+        ("application intent", "application intent"),
+        ("application name", "application name"),
+        ("applicationintent", "application intent"),
+        ("async", "asynchronous processing"),
+        ("asynchronous processing", "asynchronous processing"),
+        ("attachdbfilename", "attachdbfilename"),
+        ("authentication", "authentication"),
+        ("column encryption setting", "column encryption setting"),
+        ("command timeout", "command timeout"),
+        ("connect retry count", "connect retry count"),
+        ("connect retry interval", "connect retry interval"),
+        ("connect timeout", "connect timeout"),
+        ("connection lifetime", "load balance timeout"),
+        ("connection reset", "connection reset"),
+        ("connection timeout", "connect timeout"),
+        ("connectretrycount", "connect retry count"),
+        ("connectretryinterval", "connect retry interval"),
+        ("context connection", "context connection"),
+        ("current language", "current language"),
+        ("data source", "data source"),
+        ("database", "initial catalog"),
+        ("encrypt", "encrypt"),
+        ("enlist", "enlist"),
+        ("extended properties", "attachdbfilename"),
+        ("failover partner", "failover partner"),
+        ("host name in certificate", "host name in certificate"),
+        ("hostnameincertificate", "host name in certificate"),
+        ("initial catalog", "initial catalog"),
+        ("initial file name", "attachdbfilename"),
+        ("integrated security", "integrated security"),
+        ("integratedsecurity", "integrated security"),
+        ("language", "current language"),
+        ("load balance timeout", "load balance timeout"),
+        ("max pool size", "max pool size"),
+        ("min pool size", "min pool size"),
+        ("multi subnet failover", "multi subnet failover"),
+        ("multiple active result sets", "multiple active result sets"),
+        ("multipleactiveresultsets", "multiple active result sets"),
+        ("multisubnetfailover", "multi subnet failover"),
+        ("net", "network library"),
+        ("network", "network library"),
+        ("network address", "data source"),
+        ("network library", "network library"),
+        ("packet size", "packet size"),
+        ("password", "password"),
+        ("persist security info", "persist security info"),
+        ("persistsecurityinfo", "persist security info"),
+        ("pooling", "pooling"),
+        ("pwd", "password"),
+        ("replication", "replication"),
+        ("server", "data source"),
+        ("server certificate", "server certificate"),
+        ("servercertificate", "server certificate"),
+        ("timeout", "connect timeout"),
+        ("transaction binding", "transaction binding"),
+        ("trust server certificate", "trust server certificate"),
+        ("trusted_connection", "integrated security"),
+        ("trustservercertificate", "trust server certificate"),
+        ("type system version", "type system version"),
+        ("uid", "user id"),
+        ("user", "user id"),
+        ("user id", "user id"),
+        ("user instance", "user instance"),
+        ("workstation id", "workstation id"),
+        ("wsid", "workstation id"),
+    ],
+};
+
+/// Keywords defined by the ODBC specification, common to every driver, with the
+/// `SERVER`, `DATABASE` and `Trusted_Connection` of the drivers of SQL Server.
+///
+/// The drivers define their own keywords, which are unknown to this table.
+pub static ODBC: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "database",
+            &[],
+            "Name of the default database, for the drivers of SQL Server.",
+        ),
+        Keyword::new(
+            "driver",
+            &[],
+            "Description of the driver, as returned by SQLDrivers.",
+        ),
+        Keyword::new("dsn", &[], "Name of the data source.").default_value("Default"),
+        Keyword::new(
+            "filedsn",
+            &[],
+            "Name of a .dsn file containing the attributes of the connection.",
+        )
+        .kind(ValueKind::Path),
+        Keyword::new("pwd", &[], "Password of the user.").kind(ValueKind::Secret),
+        Keyword::new(
+            "savefile",
+            &[],
+            "Name of a .dsn file where the attributes of the connection are saved.",
+        )
+        .kind(ValueKind::Path),
+        Keyword::new(
+            "server",
+            &[],
+            "Name of the server, for the drivers of SQL Server.",
+        ),
+        Keyword::new(
+            "trusted_connection",
+            &[],
+            "Uses the Windows account of the user instead of UID and PWD.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("No"),
+        Keyword::new("uid", &[], "User ID used to connect to the data source.").credential(),
+    ],
+    lookup: &[
+        ("database", "database"),
+        ("driver", "driver"),
+        ("dsn", "dsn"),
+        ("filedsn", "filedsn"),
+        ("pwd", "pwd"),
+        ("savefile", "savefile"),
+        ("server", "server"),
+        ("trusted_connection", "trusted_connection"),
+        ("uid", "uid"),
+    ],
+};
+
+/// Keywords of the libpq connection strings of PostgreSQL.
+///
+/// The keywords of libpq have no synonyms.
+pub static POSTGRES: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "application_name",
+            &[],
+            "Name of the application, reported in pg_stat_activity.",
+        ),
+        Keyword::new("channel_binding", &[], "Use of the SCRAM channel binding.")
+            .kind(ValueKind::Enum(&["disable", "prefer", "require"]))
+            .default_value("prefer"),
+        Keyword::new(
+            "client_encoding",
+            &[],
+            "Character set of the client, `auto` detecting it from the locale.",
+        ),
+        Keyword::new(
+            "connect_timeout",
+            &[],
+            "Maximum wait for a connection, per host, 0 waiting indefinitely.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("0"),
+        Keyword::new(
+            "dbname",
+            &[],
+            "Name of the database, the user name by default.",
+        ),
+        Keyword::new(
+            "fallback_application_name",
+            &[],
+            "Name of the application used when `application_name` is not set.",
+        ),
+        Keyword::new("gssencmode", &[], "Use of a GSSAPI encrypted connection.")
+            .kind(ValueKind::Enum(&["disable", "prefer", "require"]))
+            .default_value("prefer"),
+        Keyword::new(
+            "host",
+            &[],
+            "Host names or Unix socket directories, separated by commas.",
+        )
+        .kind(ValueKind::List { separator: ',' }),
+        Keyword::new(
+            "hostaddr",
+            &[],
+            "IP addresses of the hosts, skipping the name resolution.",
+        )
+        .kind(ValueKind::List { separator: ',' }),
+        Keyword::new(
+            "keepalives",
+            &[],
+            "Whether the TCP keepalives are used, 1 or 0.",
+        )
+        .kind(ValueKind::Int { min: 0, max: 1 })
+        .default_value("1"),
+        Keyword::new(
+            "keepalives_count",
+            &[],
+            "Number of unanswered keepalives before the connection is considered dead.",
+        )
+        .kind(POSITIVE_INT),
+        Keyword::new(
+            "keepalives_idle",
+            &[],
+            "Inactivity before a keepalive is sent.",
+        )
+        .kind(POSITIVE_SECONDS),
+        Keyword::new(
+            "keepalives_interval",
+            &[],
+            "Wait for the answer of a keepalive before it is sent again.",
+        )
+        .kind(POSITIVE_SECONDS),
+        Keyword::new(
+            "load_balance_hosts",
+            &[],
+            "Order in which the hosts are tried.",
+        )
+        .kind(ValueKind::Enum(&["disable", "random"]))
+        .default_value("disable"),
+        Keyword::new(
+            "options",
+            &[],
+            "Command line options sent to the server at connection start.",
+        ),
+        Keyword::new("passfile", &[], "Path of the password file.").kind(ValueKind::Path),
+        Keyword::new("password", &[], "Password of the user.").kind(ValueKind::Secret),
+        Keyword::new(
+            "port",
+            &[],
+            "Ports of the hosts, separated by commas, or a single port for every host.",
+        )
+        .kind(ValueKind::List { separator: ',' })
+        .default_value("5432"),
+        Keyword::new(
+            "require_auth",
+            &[],
+            "Authentication methods accepted from the server.",
+        )
+        .kind(ValueKind::List { separator: ',' }),
+        Keyword::new(
+            "service",
+            &[],
+            "Name of a service of pg_service.conf holding additional parameters.",
+        ),
+        Keyword::new("sslcert", &[], "Path of the client certificate.").kind(ValueKind::Path),
+        Keyword::new("sslcrl", &[], "Path of the certificate revocation list.")
+            .kind(ValueKind::Path),
+        Keyword::new("sslkey", &[], "Path of the key of the client certificate.")
+            .kind(ValueKind::Path),
+        Keyword::new(
+            "sslmode",
+            &[],
+            "Use of a SSL connection and its verification.",
+        )
+        .kind(ValueKind::Enum(&[
+            "disable",
+            "allow",
+            "prefer",
+            "require",
+            "verify-ca",
+            "verify-full",
+        ]))
+        .default_value("prefer"),
+        Keyword::new(
+            "sslpassword",
+            &[],
+            "Password of the key of the client certificate.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new(
+            "sslrootcert",
+            &[],
+            "Path of the certificate authorities verifying the server.",
+        )
+        .kind(ValueKind::Path),
+        Keyword::new(
+            "target_session_attrs",
+            &[],
+            "Kind of session accepted among the hosts.",
+        )
+        .kind(ValueKind::Enum(&[
+            "any",
+            "read-write",
+            "read-only",
+            "primary",
+            "standby",
+            "prefer-standby",
+        ]))
+        .default_value("any"),
+        Keyword::new(
+            "user",
+            &[],
+            "Name of the user, the operating system user by default.",
+        )
+        .credential(),
+    ],
+    lookup: &[
+        ("application_name", "application_name"),
+        ("channel_binding", "channel_binding"),
+        ("client_encoding", "client_encoding"),
+        ("connect_timeout", "connect_timeout"),
+        ("dbname", "dbname"),
+        ("fallback_application_name", "fallback_application_name"),
+        ("gssencmode", "gssencmode"),
+        ("host", "host"),
+        ("hostaddr", "hostaddr"),
+        ("keepalives", "keepalives"),
+        ("keepalives_count", "keepalives_count"),
+        ("keepalives_idle", "keepalives_idle"),
+        ("keepalives_interval", "keepalives_interval"),
+        ("load_balance_hosts", "load_balance_hosts"),
+        ("options", "options"),
+        ("passfile", "passfile"),
+        ("password", "password"),
+        ("port", "port"),
+        ("require_auth", "require_auth"),
+        ("service", "service"),
+        ("sslcert", "sslcert"),
+        ("sslcrl", "sslcrl"),
+        ("sslkey", "sslkey"),
+        ("sslmode", "sslmode"),
+        ("sslpassword", "sslpassword"),
+        ("sslrootcert", "sslrootcert"),
+        ("target_session_attrs", "target_session_attrs"),
+        ("user", "user"),
+    ],
+};
+
+/// Keywords of the MySQL Connector/NET connection strings.
+pub static MYSQL: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "allow batch",
+            &["allowbatch"],
+            "Allows several statements in a command.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("true"),
+        Keyword::new(
+            "allow public key retrieval",
+            &["allowpublickeyretrieval"],
+            "Allows the RSA public key to be requested from the server.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "allow user variables",
+            &["allowuservariables"],
+            "Allows the @variables in the queries.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "allow zero datetime",
+            &["allowzerodatetime"],
+            "Returns the zero dates as MySqlDateTime instead of failing.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "auto enlist",
+            &["autoenlist"],
+            "Enlists the connections in the ambient transaction.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("true"),
+        Keyword::new(
+            "character set",
+            &["charset", "characterset"],
+            "Character set of the queries sent to the server.",
+        ),
+        Keyword::new(
+            "connection protocol",
+            &["protocol", "connectionprotocol"],
+            "Protocol used to reach the server.",
+        )
+        .kind(ValueKind::Enum(&[
+            "socket", "tcp", "pipe", "unix", "memory",
+        ]))
+        .default_value("socket"),
+        Keyword::new(
+            "connection timeout",
+            &["connect timeout", "connectiontimeout"],
+            "Seconds to wait for a connection before failing.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("15"),
+        Keyword::new(
+            "convert zero datetime",
+            &["convertzerodatetime"],
+            "Returns the zero dates as DateTime.MinValue.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "database",
+            &["initial catalog"],
+            "Name of the default database.",
+        ),
+        Keyword::new(
+            "default command timeout",
+            &["command timeout", "defaultcommandtimeout"],
+            "Seconds to wait for a command before failing.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("30"),
+        Keyword::new(
+            "keep alive",
+            &["keepalive"],
+            "Seconds between the TCP keepalives, 0 disabling them.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("0"),
+        Keyword::new(
+            "maximum pool size",
+            &["max pool size", "maxpoolsize"],
+            "Maximum number of connections in the pool.",
+        )
+        .kind(ValueKind::Int {
+            min: 1,
+            max: i32::MAX as i64,
+        })
+        .default_value("100"),
+        Keyword::new(
+            "minimum pool size",
+            &["min pool size", "minpoolsize"],
+            "Minimum number of connections in the pool.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("0"),
+        Keyword::new("password", &["pwd"], "Password of the MySQL user.").kind(ValueKind::Secret),
+        Keyword::new(
+            "persist security info",
+            &["persistsecurityinfo"],
+            "Keeps the password in the connection string once opened.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "pipe name",
+            &["pipe", "pipename"],
+            "Name of the named pipe, with the `pipe` protocol.",
+        )
+        .default_value("mysql"),
+        Keyword::new("pooling", &[], "Whether the connections are pooled.")
+            .kind(ValueKind::Bool)
+            .default_value("true"),
+        Keyword::new("port", &[], "TCP port of the server.")
+            .kind(ValueKind::Int { min: 1, max: 65535 })
+            .default_value("3306"),
+        Keyword::new(
+            "server",
+            &[
+                "host",
+                "data source",
+                "datasource",
+                "address",
+                "addr",
+                "network address",
+            ],
+            "Host names of the MySQL servers, separated by commas.",
+        )
+        .default_value("localhost"),
+        Keyword::new(
+            "ssl ca",
+            &["ssl-ca", "sslca"],
+            "Path of the certificate authorities verifying the server.",
+        )
+        .kind(ValueKind::Path),
+        Keyword::new(
+            "ssl cert",
+            &["ssl-cert", "sslcert"],
+            "Path of the client certificate.",
+        )
+        .kind(ValueKind::Path),
+        Keyword::new(
+            "ssl key",
+            &["ssl-key", "sslkey"],
+            "Path of the key of the client certificate.",
+        )
+        .kind(ValueKind::Path),
+        Keyword::new(
+            "ssl mode",
+            &["sslmode", "ssl-mode"],
+            "Use of a SSL connection and its verification.",
+        )
+        .kind(ValueKind::Enum(&[
+            "none",
+            "disabled",
+            "preferred",
+            "required",
+            "verifyca",
+            "verify_ca",
+            "verifyfull",
+            "verify_identity",
+        ]))
+        .default_value("Preferred"),
+        Keyword::new(
+            "treat tiny as boolean",
+            &["treattinyasboolean"],
+            "Returns the TINYINT(1) columns as booleans.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("true"),
+        Keyword::new(
+            "use compression",
+            &["compress", "usecompression"],
+            "Compresses the packets exchanged with the server.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "user id",
+            &["uid", "username", "user name", "user"],
+            "Name of the MySQL user.",
+        )
+        .credential(),
+    ],
+    lookup: &[
+        ("addr", "server"),
+        ("address", "server"),
+        ("allow batch", "allow batch"),
+        ("allow public key retrieval", "allow public key retrieval"),
+        ("allow user variables", "allow user variables"),
+        ("allow zero datetime", "allow zero datetime"),
+        ("allowbatch", "allow batch"),
+        ("allowpublickeyretrieval", "allow public key retrieval"),
+        ("allowuservariables", "allow user variables"),
+        ("allowzerodatetime", "allow zero datetime"),
+        ("auto enlist", "auto enlist"),
+        ("autoenlist", "auto enlist"),
+        ("character set", "character set"),
+        ("characterset", "character set"),
+        ("charset", "character set"),
+        ("command timeout", "default command timeout"),
+        ("compress", "use compression"),
+        ("connect timeout", "connection timeout"),
+        ("connection protocol", "connection protocol"),
+        ("connection timeout", "connection timeout"),
+        ("connectionprotocol", "connection protocol"),
+        ("connectiontimeout", "connection timeout"),
+        ("convert zero datetime", "convert zero datetime"),
+        ("convertzerodatetime", "convert zero datetime"),
+        ("data source", "server"),
+        ("database", "database"),
+        ("datasource", "server"),
+        ("default command timeout", "default command timeout"),
+        ("defaultcommandtimeout", "default command timeout"),
+        ("host", "server"),
+        ("initial catalog", "database"),
+        ("keep alive", "keep alive"),
+        ("keepalive", "keep alive"),
+        ("max pool size", "maximum pool size"),
+        ("maximum pool size", "maximum pool size"),
+        ("maxpoolsize", "maximum pool size"),
+        ("min pool size", "minimum pool size"),
+        ("minimum pool size", "minimum pool size"),
+        ("minpoolsize", "minimum pool size"),
+        ("network address", "server"),
+        ("password", "password"),
+        ("persist security info", "persist security info"),
+        ("persistsecurityinfo", "persist security info"),
+        ("pipe", "pipe name"),
+        ("pipe name", "pipe name"),
+        ("pipename", "pipe name"),
+        ("pooling", "pooling"),
+        ("port", "port"),
+        ("protocol", "connection protocol"),
+        ("pwd", "password"),
+        ("server", "server"),
+        ("ssl ca", "ssl ca"),
+        ("ssl cert", "ssl cert"),
+        ("ssl key", "ssl key"),
+        ("ssl mode", "ssl mode"),
+        ("ssl-ca", "ssl ca"),
+        ("ssl-cert", "ssl cert"),
+        ("ssl-key", "ssl key"),
+        ("ssl-mode", "ssl mode"),
+        ("sslca", "ssl ca"),
+        ("sslcert", "ssl cert"),
+        ("sslkey", "ssl key"),
+        ("sslmode", "ssl mode"),
+        ("treat tiny as boolean", "treat tiny as boolean"),
+        ("treattinyasboolean", "treat tiny as boolean"),
+        ("uid", "user id"),
+        ("use compression", "use compression"),
+        ("usecompression", "use compression"),
+        ("user", "user id"),
+        ("user id", "user id"),
+        ("user name", "user id"),
+        ("username", "user id"),
+    ],
+};
+
+/// Keywords of the Microsoft.Data.Sqlite connection strings.
+pub static SQLITE: Keywords = Keywords {
+    keywords: &[
+        Keyword::new("cache", &[], "Caching mode of the connection.")
+            .kind(ValueKind::Enum(&["default", "private", "shared"]))
+            .default_value("Default"),
+        Keyword::new(
+            "data source",
+            &["datasource", "filename"],
+            "Path of the database file, `:memory:` for an in-memory database.",
+        ),
+        Keyword::new(
+            "default timeout",
+            &["command timeout"],
+            "Seconds to wait for a command before failing.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("30"),
+        Keyword::new(
+            "foreign keys",
+            &[],
+            "Enables or disables the foreign key constraints, left as is when empty.",
+        )
+        .kind(ValueKind::Bool),
+        Keyword::new("mode", &[], "Mode in which the database is opened.")
+            .kind(ValueKind::Enum(&[
+                "readwritecreate",
+                "readwrite",
+                "readonly",
+                "memory",
+            ]))
+            .default_value("ReadWriteCreate"),
+        Keyword::new(
+            "password",
+            &[],
+            "Encryption key of the database, with SQLCipher.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new("pooling", &[], "Whether the connections are pooled.")
+            .kind(ValueKind::Bool)
+            .default_value("true"),
+        Keyword::new("recursive triggers", &[], "Enables the recursive triggers.")
+            .kind(ValueKind::Bool)
+            .default_value("false"),
+    ],
+    lookup: &[
+        ("cache", "cache"),
+        ("command timeout", "default timeout"),
+        ("data source", "data source"),
+        ("datasource", "data source"),
+        ("default timeout", "default timeout"),
+        ("filename", "data source"),
+        ("foreign keys", "foreign keys"),
+        ("mode", "mode"),
+        ("password", "password"),
+        ("pooling", "pooling"),
+        ("recursive triggers", "recursive triggers"),
+    ],
+};
+
+/// Keywords of the OLE DB connection strings, with the common properties of the
+/// providers.
+///
+/// The other properties are passed through to the provider.
+pub static OLE_DB: Keywords = Keywords {
+    keywords: &[
+        Keyword::new("connect timeout", &[], "Seconds to wait for a connection before failing.")
+            .kind(POSITIVE_SECONDS),
+        Keyword::new("data source", &[], "Server or file of the data, depending on the provider."),
+        Keyword::new("extended properties", &[], "Properties specific to the provider, as a nested connection string."),
+        Keyword::new("file name", &[], "Path of a .udl file holding the connection string.")
+            .kind(ValueKind::Path),
+        Keyword::new("initial catalog", &[], "Name of the default database."),
+        Keyword::new("integrated security", &[], "`SSPI` to use the Windows account of the user."),
+        Keyword::new("jet oledb:database password", &[], "Password of an Access database.")
+            .kind(ValueKind::Secret),
+        Keyword::new("jet oledb:engine type", &[], "Version of the database format, 5 for Access 2000 and later.")
+            .kind(POSITIVE_INT),
+        Keyword::new("jet oledb:system database", &[], "Path of the workgroup information file, .mdw, holding the users.")
+            .kind(ValueKind::Path),
+        Keyword::new("mode", &[], "Access permissions of the connection, such as `Share Deny None`."),
+        Keyword::new("ole db services", &[], "Services enabled by the OLE DB core components, such as the pooling, as a bitmask.")
+            .kind(ValueKind::Int {
+                min: i32::MIN as i64,
+                max: i32::MAX as i64,
+            })
+            .default_value("-1"),
+        Keyword::new("password", &[], "Password of the user.")
+            .kind(ValueKind::Secret),
+        Keyword::new("persist security info", &[], "Keeps the password in the connection string once opened.")
+            .kind(ValueKind::Bool)
+            .default_value("false"),
+        Keyword::new("provider", &[], "Name or ProgID of the OLE DB provider, such as `SQLOLEDB` or `Microsoft.ACE.OLEDB.12.0`."),
+        Keyword::new("user id", &[], "User name of the connection.")
+            .credential(),
+    ],
+    lookup: &[
+        ("connect timeout", "connect timeout"),
+        ("data source", "data source"),
+        ("extended properties", "extended properties"),
+        ("file name", "file name"),
+        ("initial catalog", "initial catalog"),
+        ("integrated security", "integrated security"),
+        ("jet oledb:database password", "jet oledb:database password"),
+        ("jet oledb:engine type", "jet oledb:engine type"),
+        ("jet oledb:system database", "jet oledb:system database"),
+        ("mode", "mode"),
+        ("ole db services", "ole db services"),
+        ("password", "password"),
+        ("persist security info", "persist security info"),
+        ("provider", "provider"),
+        ("user id", "user id"),
+    ],
+};
+
+/// Keywords of the Oracle Data Provider for .NET connection strings
+/// (Oracle.ManagedDataAccess).
+///
+/// The keywords of ODP.NET have no synonyms.
+pub static ORACLE: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "connection lifetime",
+            &[],
+            "Seconds a pooled connection lives before being closed.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("0"),
+        Keyword::new(
+            "connection timeout",
+            &[],
+            "Seconds to wait for a connection from the pool.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("15"),
+        Keyword::new(
+            "data source",
+            &[],
+            "Net service name, connect descriptor or EZConnect of the database.",
+        ),
+        Keyword::new(
+            "dba privilege",
+            &[],
+            "Administrative privilege of the connection.",
+        )
+        .kind(ValueKind::Enum(&[
+            "SYSDBA",
+            "SYSOPER",
+            "SYSASM",
+            "SYSBACKUP",
+            "SYSDG",
+            "SYSKM",
+        ])),
+        Keyword::new(
+            "decr pool size",
+            &[],
+            "Number of connections closed when the pool shrinks.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("1"),
+        Keyword::new(
+            "enlist",
+            &[],
+            "Enlists the connections in the ambient transaction.",
+        )
+        .kind(ValueKind::Enum(&["true", "false", "dynamic"]))
+        .default_value("true"),
+        Keyword::new(
+            "ha events",
+            &[],
+            "Removes the connections to failed RAC instances from the pool.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("true"),
+        Keyword::new(
+            "incr pool size",
+            &[],
+            "Number of connections opened when the pool grows.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("5"),
+        Keyword::new(
+            "load balancing",
+            &[],
+            "Balances the connections between the RAC instances.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("true"),
+        Keyword::new(
+            "max pool size",
+            &[],
+            "Maximum number of connections in the pool.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("100"),
+        Keyword::new(
+            "metadata pooling",
+            &[],
+            "Caches the metadata of the queries.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("true"),
+        Keyword::new(
+            "min pool size",
+            &[],
+            "Minimum number of connections in the pool.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("1"),
+        Keyword::new("password", &[], "Password of the user.").kind(ValueKind::Secret),
+        Keyword::new(
+            "persist security info",
+            &[],
+            "Keeps the password in the connection string once connected.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new("pooling", &[], "Pools the connections.")
+            .kind(ValueKind::Bool)
+            .default_value("true"),
+        Keyword::new(
+            "promotable transaction",
+            &[],
+            "Promotes the local transactions to distributed ones when needed.",
+        )
+        .kind(ValueKind::Enum(&["promotable", "local"]))
+        .default_value("promotable"),
+        Keyword::new("proxy password", &[], "Password of the proxy user.").kind(ValueKind::Secret),
+        Keyword::new(
+            "proxy user id",
+            &[],
+            "Proxy user connecting on behalf of the user.",
+        )
+        .credential(),
+        Keyword::new(
+            "self tuning",
+            &[],
+            "Tunes the statement cache size automatically.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("true"),
+        Keyword::new(
+            "statement cache purge",
+            &[],
+            "Purges the statement cache when the connection returns to the pool.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "statement cache size",
+            &[],
+            "Number of statements cached per connection.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("0"),
+        Keyword::new(
+            "tns_admin",
+            &[],
+            "Directory of the tnsnames.ora and sqlnet.ora files.",
+        )
+        .kind(ValueKind::Path),
+        Keyword::new("user id", &[], "User connecting to the database.").credential(),
+        Keyword::new(
+            "validate connection",
+            &[],
+            "Validates the pooled connections before using them.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new("wallet_location", &[], "Directory of the Oracle wallet.")
+            .kind(ValueKind::Path),
+    ],
+    lookup: &[
+        ("connection lifetime", "connection lifetime"),
+        ("connection timeout", "connection timeout"),
+        ("data source", "data source"),
+        ("dba privilege", "dba privilege"),
+        ("decr pool size", "decr pool size"),
+        ("enlist", "enlist"),
+        ("ha events", "ha events"),
+        ("incr pool size", "incr pool size"),
+        ("load balancing", "load balancing"),
+        ("max pool size", "max pool size"),
+        ("metadata pooling", "metadata pooling"),
+        ("min pool size", "min pool size"),
+        ("password", "password"),
+        ("persist security info", "persist security info"),
+        ("pooling", "pooling"),
+        ("promotable transaction", "promotable transaction"),
+        ("proxy password", "proxy password"),
+        ("proxy user id", "proxy user id"),
+        ("self tuning", "self tuning"),
+        ("statement cache purge", "statement cache purge"),
+        ("statement cache size", "statement cache size"),
+        ("tns_admin", "tns_admin"),
+        ("user id", "user id"),
+        ("validate connection", "validate connection"),
+        ("wallet_location", "wallet_location"),
+    ],
+};
+
+/// Options of the MongoDB connection URIs, in lower case as they are compared
+/// ignoring case, with the parts of the URI stored under `username`, `password`,
+/// `host` and `database`.
+pub static MONGO: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "appname",
+            &[],
+            "Name of the application reported to the server.",
+        ),
+        Keyword::new("authmechanism", &[], "Mechanism authenticating the user.").kind(
+            ValueKind::Enum(&[
+                "SCRAM-SHA-1",
+                "SCRAM-SHA-256",
+                "MONGODB-X509",
+                "MONGODB-AWS",
+                "MONGODB-OIDC",
+                "GSSAPI",
+                "PLAIN",
+            ]),
+        ),
+        Keyword::new(
+            "authmechanismproperties",
+            &[],
+            "Properties of the authentication mechanism, as `key:value` pairs.",
+        )
+        .kind(ValueKind::List { separator: ',' }),
+        Keyword::new(
+            "authsource",
+            &[],
+            "Database holding the credentials of the user.",
+        ),
+        Keyword::new(
+            "compressors",
+            &[],
+            "Compressors of the messages, by order of preference.",
+        )
+        .kind(ValueKind::List { separator: ',' }),
+        Keyword::new(
+            "connecttimeoutms",
+            &[],
+            "Milliseconds to wait for a connection to open.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("10000"),
+        Keyword::new(
+            "database",
+            &[],
+            "Database authenticating the user when there is no `authSource`.",
+        ),
+        Keyword::new(
+            "directconnection",
+            &[],
+            "Connects to the host directly instead of discovering the replica set.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "host",
+            &[],
+            "Hosts of the replica set or the sharded cluster, with their port.",
+        )
+        .kind(ValueKind::List { separator: ',' }),
+        Keyword::new(
+            "journal",
+            &[],
+            "Acknowledges the writes once written to the journal.",
+        )
+        .kind(ValueKind::Bool),
+        Keyword::new("loadbalanced", &[], "Connects through a load balancer.")
+            .kind(ValueKind::Bool)
+            .default_value("false"),
+        Keyword::new(
+            "maxidletimems",
+            &[],
+            "Milliseconds a pooled connection stays idle before being closed.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("0"),
+        Keyword::new(
+            "maxpoolsize",
+            &[],
+            "Maximum number of connections in the pool.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("100"),
+        Keyword::new(
+            "minpoolsize",
+            &[],
+            "Minimum number of connections in the pool.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("0"),
+        Keyword::new("password", &[], "Password of the user.").kind(ValueKind::Secret),
+        Keyword::new("readconcernlevel", &[], "Isolation level of the reads.").kind(
+            ValueKind::Enum(&["local", "majority", "linearizable", "available", "snapshot"]),
+        ),
+        Keyword::new(
+            "readpreference",
+            &[],
+            "Members of the replica set read from.",
+        )
+        .kind(ValueKind::Enum(&[
+            "primary",
+            "primaryPreferred",
+            "secondary",
+            "secondaryPreferred",
+            "nearest",
+        ]))
+        .default_value("primary"),
+        Keyword::new(
+            "readpreferencetags",
+            &[],
+            "Tags of the members read from, as `key:value` pairs.",
+        )
+        .kind(ValueKind::List { separator: ',' }),
+        Keyword::new("replicaset", &[], "Name of the replica set."),
+        Keyword::new(
+            "retryreads",
+            &[],
+            "Retries the reads once after a network error.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("true"),
+        Keyword::new(
+            "retrywrites",
+            &[],
+            "Retries the writes once after a network error.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("true"),
+        Keyword::new(
+            "serverselectiontimeoutms",
+            &[],
+            "Milliseconds to wait for a server matching the read preference.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("30000"),
+        Keyword::new(
+            "sockettimeoutms",
+            &[],
+            "Milliseconds to wait for a response, 0 waiting forever.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("0"),
+        Keyword::new(
+            "srvmaxhosts",
+            &[],
+            "Maximum number of hosts taken from the SRV records.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("0"),
+        Keyword::new("srvservicename", &[], "Service name of the SRV records.")
+            .default_value("mongodb"),
+        Keyword::new("tls", &["ssl"], "Encrypts the connections with TLS.").kind(ValueKind::Bool),
+        Keyword::new(
+            "tlsallowinvalidcertificates",
+            &[],
+            "Accepts the certificates of the servers not signed by a trusted authority.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "tlsallowinvalidhostnames",
+            &[],
+            "Accepts the certificates of the servers not matching their host name.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "tlscafile",
+            &[],
+            "File of the certificate authorities trusted.",
+        )
+        .kind(ValueKind::Path),
+        Keyword::new(
+            "tlscertificatekeyfile",
+            &[],
+            "File of the certificate and the private key of the client.",
+        )
+        .kind(ValueKind::Path),
+        Keyword::new(
+            "tlscertificatekeyfilepassword",
+            &[],
+            "Password of the private key of the client.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new(
+            "tlsinsecure",
+            &[],
+            "Disables every validation of the certificates of the servers.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new("username", &[], "User connecting to the database.").credential(),
+        Keyword::new(
+            "w",
+            &[],
+            "Write concern, a number of members or `majority`.",
+        ),
+        Keyword::new(
+            "wtimeoutms",
+            &[],
+            "Milliseconds to wait for the write concern.",
+        )
+        .kind(POSITIVE_INT),
+        Keyword::new(
+            "zlibcompressionlevel",
+            &[],
+            "Compression level of zlib, -1 using the default one.",
+        )
+        .kind(ValueKind::Int { min: -1, max: 9 })
+        .default_value("-1"),
+    ],
+    lookup: &[
+        ("appname", "appname"),
+        ("authmechanism", "authmechanism"),
+        ("authmechanismproperties", "authmechanismproperties"),
+        ("authsource", "authsource"),
+        ("compressors", "compressors"),
+        ("connecttimeoutms", "connecttimeoutms"),
+        ("database", "database"),
+        ("directconnection", "directconnection"),
+        ("host", "host"),
+        ("journal", "journal"),
+        ("loadbalanced", "loadbalanced"),
+        ("maxidletimems", "maxidletimems"),
+        ("maxpoolsize", "maxpoolsize"),
+        ("minpoolsize", "minpoolsize"),
+        ("password", "password"),
+        ("readconcernlevel", "readconcernlevel"),
+        ("readpreference", "readpreference"),
+        ("readpreferencetags", "readpreferencetags"),
+        ("replicaset", "replicaset"),
+        ("retryreads", "retryreads"),
+        ("retrywrites", "retrywrites"),
+        ("serverselectiontimeoutms", "serverselectiontimeoutms"),
+        ("sockettimeoutms", "sockettimeoutms"),
+        ("srvmaxhosts", "srvmaxhosts"),
+        ("srvservicename", "srvservicename"),
+        ("ssl", "tls"),
+        ("tls", "tls"),
+        ("tlsallowinvalidcertificates", "tlsallowinvalidcertificates"),
+        ("tlsallowinvalidhostnames", "tlsallowinvalidhostnames"),
+        ("tlscafile", "tlscafile"),
+        ("tlscertificatekeyfile", "tlscertificatekeyfile"),
+        (
+            "tlscertificatekeyfilepassword",
+            "tlscertificatekeyfilepassword",
+        ),
+        ("tlsinsecure", "tlsinsecure"),
+        ("username", "username"),
+        ("w", "w"),
+        ("wtimeoutms", "wtimeoutms"),
+        ("zlibcompressionlevel", "zlibcompressionlevel"),
+    ],
+};
+
+/// Options of the StackExchange.Redis configuration strings, in lower case as they
+/// are compared ignoring case.
+pub static REDIS: Keywords = Keywords {
+    keywords: &[
+        Keyword::new("abortconnect", &[], "Fails when no server is available on connecting instead of retrying in the background.")
+            .kind(ValueKind::Bool)
+            .default_value("true"),
+        Keyword::new("allowadmin", &[], "Allows the administrative commands.")
+            .kind(ValueKind::Bool)
+            .default_value("false"),
+        Keyword::new("asynctimeout", &[], "Milliseconds to wait for an asynchronous operation.")
+            .kind(POSITIVE_INT)
+            .default_value("5000"),
+        Keyword::new("channelprefix", &[], "Prefix of the pub/sub channels."),
+        Keyword::new("checkcertificaterevocation", &[], "Checks the revocation list of the certificate of the server.")
+            .kind(ValueKind::Bool)
+            .default_value("true"),
+        Keyword::new("configchannel", &[], "Channel broadcasting the configuration changes.")
+            .default_value("__Booksleeve_MasterChanged"),
+        Keyword::new("configcheckseconds", &[], "Seconds between the checks of the configuration.")
+            .kind(POSITIVE_SECONDS)
+            .default_value("60"),
+        Keyword::new("connectretry", &[], "Number of connection attempts on connecting.")
+            .kind(POSITIVE_INT)
+            .default_value("3"),
+        Keyword::new("connecttimeout", &[], "Milliseconds to wait for a connection to open.")
+            .kind(POSITIVE_INT)
+            .default_value("5000"),
+        Keyword::new("defaultdatabase", &[], "Database used when none is given.")
+            .kind(POSITIVE_INT),
+        Keyword::new("keepalive", &[], "Seconds between the messages keeping the connections alive, -1 for the default.")
+            .kind(ValueKind::Duration { min: -1, max: i32::MAX as i64 })
+            .default_value("-1"),
+        Keyword::new("name", &[], "Name of the client reported to the server."),
+        Keyword::new("password", &[], "Password of the user.")
+            .kind(ValueKind::Secret),
+        Keyword::new("protocol", &[], "Protocol of the connections.")
+            .kind(ValueKind::Enum(&["resp2", "resp3"])),
+        Keyword::new("proxy", &[], "Proxy in front of the servers, restricting the commands.")
+            .kind(ValueKind::Enum(&["None", "Twemproxy", "Envoyproxy"]))
+            .default_value("None"),
+        Keyword::new("resolvedns", &[], "Resolves the host names on connecting instead of on each connection.")
+            .kind(ValueKind::Bool)
+            .default_value("false"),
+        Keyword::new("servicename", &[], "Name of the Sentinel service monitoring the servers."),
+        Keyword::new("setlib", &[], "Reports the library name and version to the server.")
+            .kind(ValueKind::Bool)
+            .default_value("true"),
+        Keyword::new("ssl", &[], "Encrypts the connections with TLS.")
+            .kind(ValueKind::Bool)
+            .default_value("false"),
+        Keyword::new("sslhost", &[], "Host name expected in the certificate of the server."),
+        Keyword::new("sslprotocols", &[], "TLS versions allowed."),
+        Keyword::new("synctimeout", &[], "Milliseconds to wait for a synchronous operation.")
+            .kind(POSITIVE_INT)
+            .default_value("5000"),
+        Keyword::new("tiebreaker", &[], "Key electing the primary between several servers.")
+            .default_value("__Booksleeve_TieBreak"),
+        Keyword::new("user", &[], "User of the ACL connecting to the server.")
+            .credential(),
+        Keyword::new("version", &[], "Version of the server when it cannot be detected.")
+            .default_value("4.0"),
+    ],
+    lookup: &[
+        ("abortconnect", "abortconnect"),
+        ("allowadmin", "allowadmin"),
+        ("asynctimeout", "asynctimeout"),
+        ("channelprefix", "channelprefix"),
+        ("checkcertificaterevocation", "checkcertificaterevocation"),
+        ("configchannel", "configchannel"),
+        ("configcheckseconds", "configcheckseconds"),
+        ("connectretry", "connectretry"),
+        ("connecttimeout", "connecttimeout"),
+        ("defaultdatabase", "defaultdatabase"),
+        ("keepalive", "keepalive"),
+        ("name", "name"),
+        ("password", "password"),
+        ("protocol", "protocol"),
+        ("proxy", "proxy"),
+        ("resolvedns", "resolvedns"),
+        ("servicename", "servicename"),
+        ("setlib", "setlib"),
+        ("ssl", "ssl"),
+        ("sslhost", "sslhost"),
+        ("sslprotocols", "sslprotocols"),
+        ("synctimeout", "synctimeout"),
+        ("tiebreaker", "tiebreaker"),
+        ("user", "user"),
+        ("version", "version"),
+    ],
+};
+
+/// Keywords of the Npgsql connection strings, the ADO.NET provider of PostgreSQL.
+pub static NPGSQL: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "application name",
+            &["applicationname"],
+            "Name of the application, reported in pg_stat_activity.",
+        ),
+        Keyword::new(
+            "check certificate revocation",
+            &["checkcertificaterevocation"],
+            "Checks the revocation list of the certificate of the server.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "client encoding",
+            &["clientencoding"],
+            "Character set of the client.",
+        ),
+        Keyword::new(
+            "command timeout",
+            &["commandtimeout"],
+            "Seconds to wait for a command to complete, 0 waiting indefinitely.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("30"),
+        Keyword::new(
+            "connection idle lifetime",
+            &["connectionidlelifetime"],
+            "Seconds a pooled connection stays idle before being closed.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("300"),
+        Keyword::new(
+            "connection lifetime",
+            &["connectionlifetime", "load balance timeout"],
+            "Seconds a pooled connection lives before being closed, 0 living indefinitely.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("0")
+        .deprecated_synonyms(&["load balance timeout"]),
+        Keyword::new(
+            "connection pruning interval",
+            &["connectionpruninginterval"],
+            "Seconds between the prunings of the idle connections.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("10"),
+        Keyword::new(
+            "database",
+            &["db"],
+            "Name of the database, the user name by default.",
+        ),
+        Keyword::new(
+            "enlist",
+            &[],
+            "Enlists the connections in the ambient transaction.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("true"),
+        Keyword::new(
+            "host",
+            &["server"],
+            "Hosts of the servers, separated by commas, with their optional port.",
+        )
+        .kind(ValueKind::List { separator: ',' }),
+        Keyword::new(
+            "include error detail",
+            &["includeerrordetail"],
+            "Includes the details of the errors, which can hold sensitive data.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "include realm",
+            &["includerealm"],
+            "Keeps the Kerberos realm in the user name.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "keepalive",
+            &[],
+            "Seconds of inactivity before sending a keepalive query, 0 disabling it.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("0"),
+        Keyword::new(
+            "kerberos service name",
+            &["kerberosservicename", "krbsrvname"],
+            "Kerberos service name of the server.",
+        )
+        .default_value("postgres"),
+        Keyword::new(
+            "load balance hosts",
+            &["loadbalancehosts"],
+            "Balances the connections between the hosts.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "maximum pool size",
+            &["max pool size", "maximumpoolsize", "maxpoolsize"],
+            "Maximum number of connections in the pool.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("100"),
+        Keyword::new(
+            "minimum pool size",
+            &["min pool size", "minimumpoolsize", "minpoolsize"],
+            "Minimum number of connections in the pool.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("0"),
+        Keyword::new(
+            "multiplexing",
+            &[],
+            "Shares the connections between the commands.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "no reset on close",
+            &["noresetonclose"],
+            "Keeps the state of the connections returned to the pool.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new("options", &[], "Command-line options sent to the server."),
+        Keyword::new(
+            "passfile",
+            &[],
+            "File of the passwords, like the .pgpass file of libpq.",
+        )
+        .kind(ValueKind::Path),
+        Keyword::new("password", &["psw", "pwd"], "Password of the user.").kind(ValueKind::Secret),
+        Keyword::new(
+            "persist security info",
+            &["persistsecurityinfo"],
+            "Keeps the password in the connection string once connected.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new("pooling", &[], "Pools the connections.")
+            .kind(ValueKind::Bool)
+            .default_value("true"),
+        Keyword::new("port", &[], "TCP port of the servers.")
+            .kind(ValueKind::Int { min: 1, max: 65535 })
+            .default_value("5432"),
+        Keyword::new(
+            "root certificate",
+            &["rootcertificate"],
+            "File of the certificate authority trusted.",
+        )
+        .kind(ValueKind::Path),
+        Keyword::new(
+            "search path",
+            &["searchpath"],
+            "Schemas searched for the unqualified names.",
+        ),
+        Keyword::new(
+            "server compatibility mode",
+            &["servercompatibilitymode"],
+            "Compatibility with the servers based on PostgreSQL.",
+        )
+        .kind(ValueKind::Enum(&["None", "Redshift", "NoTypeLoading"]))
+        .default_value("None"),
+        Keyword::new(
+            "ssl certificate",
+            &["sslcertificate"],
+            "File of the certificate of the client.",
+        )
+        .kind(ValueKind::Path),
+        Keyword::new(
+            "ssl key",
+            &["sslkey"],
+            "File of the private key of the client.",
+        )
+        .kind(ValueKind::Path),
+        Keyword::new("ssl mode", &["sslmode"], "Use of SSL.")
+            .kind(ValueKind::Enum(&[
+                "Disable",
+                "Allow",
+                "Prefer",
+                "Require",
+                "VerifyCA",
+                "VerifyFull",
+            ]))
+            .default_value("Prefer"),
+        Keyword::new(
+            "ssl password",
+            &["sslpassword"],
+            "Password of the private key of the client.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new(
+            "target session attributes",
+            &["targetsessionattributes"],
+            "Kind of server the connections are opened to.",
+        )
+        .kind(ValueKind::Enum(&[
+            "any",
+            "primary",
+            "standby",
+            "prefer-primary",
+            "prefer-standby",
+            "read-write",
+            "read-only",
+        ]))
+        .default_value("any"),
+        Keyword::new(
+            "tcp keepalive",
+            &["tcpkeepalive"],
+            "Enables the keepalives of TCP.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "timeout",
+            &[],
+            "Seconds to wait for a connection to open, 0 waiting indefinitely.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("15"),
+        Keyword::new("timezone", &[], "Time zone of the session."),
+        Keyword::new(
+            "trust server certificate",
+            &["trustservercertificate"],
+            "Accepts the certificate of the server without validating it.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "username",
+            &["user", "user id", "user name", "userid", "uid"],
+            "User connecting to the database.",
+        )
+        .credential(),
+    ],
+    lookup: &[
+        ("application name", "application name"),
+        ("applicationname", "application name"),
+        (
+            "check certificate revocation",
+            "check certificate revocation",
+        ),
+        ("checkcertificaterevocation", "check certificate revocation"),
+        ("client encoding", "client encoding"),
+        ("clientencoding", "client encoding"),
+        ("command timeout", "command timeout"),
+        ("commandtimeout", "command timeout"),
+        ("connection idle lifetime", "connection idle lifetime"),
+        ("connection lifetime", "connection lifetime"),
+        ("connection pruning interval", "connection pruning interval"),
+        ("connectionidlelifetime", "connection idle lifetime"),
+        ("connectionlifetime", "connection lifetime"),
+        ("connectionpruninginterval", "connection pruning interval"),
+        ("database", "database"),
+        ("db", "database"),
+        ("enlist", "enlist"),
+        ("host", "host"),
+        ("include error detail", "include error detail"),
+        ("include realm", "include realm"),
+        ("includeerrordetail", "include error detail"),
+        ("includerealm", "include realm"),
+        ("keepalive", "keepalive"),
+        ("kerberos service name", "kerberos service name"),
+        ("kerberosservicename", "kerberos service name"),
+        ("krbsrvname", "kerberos service name"),
+        ("load balance hosts", "load balance hosts"),
+        ("load balance timeout", "connection lifetime"),
+        ("loadbalancehosts", "load balance hosts"),
+        ("max pool size", "maximum pool size"),
+        ("maximum pool size", "maximum pool size"),
+        ("maximumpoolsize", "maximum pool size"),
+        ("maxpoolsize", "maximum pool size"),
+        ("min pool size", "minimum pool size"),
+        ("minimum pool size", "minimum pool size"),
+        ("minimumpoolsize", "minimum pool size"),
+        ("minpoolsize", "minimum pool size"),
+        ("multiplexing", "multiplexing"),
+        ("no reset on close", "no reset on close"),
+        ("noresetonclose", "no reset on close"),
+        ("options", "options"),
+        ("passfile", "passfile"),
+        ("password", "password"),
+        ("persist security info", "persist security info"),
+        ("persistsecurityinfo", "persist security info"),
+        ("pooling", "pooling"),
+        ("port", "port"),
+        ("psw", "password"),
+        ("pwd", "password"),
+        ("root certificate", "root certificate"),
+        ("rootcertificate", "root certificate"),
+        ("search path", "search path"),
+        ("searchpath", "search path"),
+        ("server", "host"),
+        ("server compatibility mode", "server compatibility mode"),
+        ("servercompatibilitymode", "server compatibility mode"),
+        ("ssl certificate", "ssl certificate"),
+        ("ssl key", "ssl key"),
+        ("ssl mode", "ssl mode"),
+        ("ssl password", "ssl password"),
+        ("sslcertificate", "ssl certificate"),
+        ("sslkey", "ssl key"),
+        ("sslmode", "ssl mode"),
+        ("sslpassword", "ssl password"),
+        ("target session attributes", "target session attributes"),
+        ("targetsessionattributes", "target session attributes"),
+        ("tcp keepalive", "tcp keepalive"),
+        ("tcpkeepalive", "tcp keepalive"),
+        ("timeout", "timeout"),
+        ("timezone", "timezone"),
+        ("trust server certificate", "trust server certificate"),
+        ("trustservercertificate", "trust server certificate"),
+        ("uid", "username"),
+        ("user", "username"),
+        ("user id", "username"),
+        ("user name", "username"),
+        ("userid", "username"),
+        ("username", "username"),
+    ],
+};
+
+/// Keywords of the FirebirdSql.Data.FirebirdClient connection strings.
+pub static FIREBIRD: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "application name",
+            &["applicationname", "app"],
+            "Name of the application reported to the server.",
+        ),
+        Keyword::new(
+            "charset",
+            &["character set"],
+            "Character set of the connection.",
+        )
+        .default_value("UTF8"),
+        Keyword::new(
+            "client library",
+            &["clientlibrary"],
+            "Client library of the embedded server.",
+        )
+        .kind(ValueKind::Path)
+        .default_value("fbembed"),
+        Keyword::new(
+            "command timeout",
+            &["commandtimeout"],
+            "Seconds to wait for a command to complete, 0 waiting indefinitely.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("0"),
+        Keyword::new(
+            "compression",
+            &["wire compression"],
+            "Compresses the data sent over the network.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "connection lifetime",
+            &["connectionlifetime"],
+            "Seconds a pooled connection lives before being closed, 0 living indefinitely.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("0"),
+        Keyword::new(
+            "connection timeout",
+            &["connect timeout", "connectiontimeout", "timeout"],
+            "Seconds to wait for a connection to open.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("15"),
+        Keyword::new(
+            "crypt key",
+            &["cryptkey"],
+            "Key of the encrypted database, in base 64.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new(
+            "data source",
+            &["datasource", "host", "server"],
+            "Host of the server when the database does not name it.",
+        ),
+        Keyword::new(
+            "database",
+            &["initial catalog"],
+            "Database file or alias, optionally prefixed by `host[/port]:`.",
+        ),
+        Keyword::new(
+            "db cache pages",
+            &["cache pages", "dbcachepages", "page buffers"],
+            "Number of pages of the database cache.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("0"),
+        Keyword::new("dialect", &[], "SQL dialect of the database.")
+            .kind(ValueKind::Enum(&["1", "3"]))
+            .default_value("3"),
+        Keyword::new(
+            "isolation level",
+            &["isolationlevel"],
+            "Isolation level of the transactions.",
+        )
+        .default_value("ReadCommitted"),
+        Keyword::new(
+            "max pool size",
+            &["maxpoolsize"],
+            "Maximum number of connections in the pool.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("100"),
+        Keyword::new(
+            "min pool size",
+            &["minpoolsize"],
+            "Minimum number of connections in the pool.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("0"),
+        Keyword::new(
+            "no database triggers",
+            &["nodatabasetriggers", "no db triggers"],
+            "Disables the database triggers of the connection.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "no garbage collect",
+            &["nogarbagecollect", "no gc"],
+            "Disables the garbage collection of the connection.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "packet size",
+            &["packetsize"],
+            "Size of the network packets, in bytes.",
+        )
+        .kind(ValueKind::Int {
+            min: 512,
+            max: 32767,
+        })
+        .default_value("8192"),
+        Keyword::new(
+            "parallel workers",
+            &["parallelworkers", "parallel"],
+            "Number of workers of the parallel operations.",
+        )
+        .kind(POSITIVE_INT),
+        Keyword::new(
+            "password",
+            &["pwd", "user password"],
+            "Password of the user.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new("pooling", &[], "Pools the connections.")
+            .kind(ValueKind::Bool)
+            .default_value("true"),
+        Keyword::new("port", &["port number"], "TCP port of the server.")
+            .kind(ValueKind::Int { min: 1, max: 65535 })
+            .default_value("3050"),
+        Keyword::new("role", &["role name"], "SQL role of the user."),
+        Keyword::new(
+            "server type",
+            &["servertype"],
+            "Type of server, `Embedded` loading the client library in the process.",
+        )
+        .kind(ValueKind::Enum(&["Default", "Embedded", "0", "1"]))
+        .default_value("Default"),
+        Keyword::new(
+            "user id",
+            &["uid", "user", "user name", "userid", "username"],
+            "User connecting to the database.",
+        )
+        .credential(),
+        Keyword::new(
+            "wire crypt",
+            &["wirecrypt"],
+            "Encryption of the data sent over the network.",
+        )
+        .kind(ValueKind::Enum(&["Disabled", "Enabled", "Required"]))
+        .default_value("Enabled"),
+    ],
+    lookup: &[
+        ("app", "application name"),
+        ("application name", "application name"),
+        ("applicationname", "application name"),
+        ("cache pages", "db cache pages"),
+        ("character set", "charset"),
+        ("charset", "charset"),
+        ("client library", "client library"),
+        ("clientlibrary", "client library"),
+        ("command timeout", "command timeout"),
+        ("commandtimeout", "command timeout"),
+        ("compression", "compression"),
+        ("connect timeout", "connection timeout"),
+        ("connection lifetime", "connection lifetime"),
+        ("connection timeout", "connection timeout"),
+        ("connectionlifetime", "connection lifetime"),
+        ("connectiontimeout", "connection timeout"),
+        ("crypt key", "crypt key"),
+        ("cryptkey", "crypt key"),
+        ("data source", "data source"),
+        ("database", "database"),
+        ("datasource", "data source"),
+        ("db cache pages", "db cache pages"),
+        ("dbcachepages", "db cache pages"),
+        ("dialect", "dialect"),
+        ("host", "data source"),
+        ("initial catalog", "database"),
+        ("isolation level", "isolation level"),
+        ("isolationlevel", "isolation level"),
+        ("max pool size", "max pool size"),
+        ("maxpoolsize", "max pool size"),
+        ("min pool size", "min pool size"),
+        ("minpoolsize", "min pool size"),
+        ("no database triggers", "no database triggers"),
+        ("no db triggers", "no database triggers"),
+        ("no garbage collect", "no garbage collect"),
+        ("no gc", "no garbage collect"),
+        ("nodatabasetriggers", "no database triggers"),
+        ("nogarbagecollect", "no garbage collect"),
+        ("packet size", "packet size"),
+        ("packetsize", "packet size"),
+        ("page buffers", "db cache pages"),
+        ("parallel", "parallel workers"),
+        ("parallel workers", "parallel workers"),
+        ("parallelworkers", "parallel workers"),
+        ("password", "password"),
+        ("pooling", "pooling"),
+        ("port", "port"),
+        ("port number", "port"),
+        ("pwd", "password"),
+        ("role", "role"),
+        ("role name", "role"),
+        ("server", "data source"),
+        ("server type", "server type"),
+        ("servertype", "server type"),
+        ("timeout", "connection timeout"),
+        ("uid", "user id"),
+        ("user", "user id"),
+        ("user id", "user id"),
+        ("user name", "user id"),
+        ("user password", "password"),
+        ("userid", "user id"),
+        ("username", "user id"),
+        ("wire compression", "compression"),
+        ("wire crypt", "wire crypt"),
+        ("wirecrypt", "wire crypt"),
+    ],
+};
+
+/// Keywords of the SAP ASE ADO.NET connection strings (Sybase AseClient).
+pub static ASE: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "ansinull",
+            &[],
+            "Follows the SQL standard for the comparisons with NULL.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "application name",
+            &["applicationname", "app"],
+            "Name of the application reported to the server.",
+        ),
+        Keyword::new(
+            "charset",
+            &["character set"],
+            "Character set of the client, such as `iso_1` or `utf8`.",
+        ),
+        Keyword::new(
+            "client host name",
+            &["clienthostname"],
+            "Name of the client host reported to the server.",
+        ),
+        Keyword::new(
+            "connection idle timeout",
+            &["connectionidletimeout"],
+            "Seconds a pooled connection stays idle before being closed, 0 for no limit.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("0"),
+        Keyword::new(
+            "connection lifetime",
+            &["connectionlifetime"],
+            "Seconds a pooled connection lives before being closed, 0 living indefinitely.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("0"),
+        Keyword::new(
+            "connection timeout",
+            &["connect timeout", "connectiontimeout", "timeout"],
+            "Seconds to wait for a connection to open.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("15"),
+        Keyword::new(
+            "data source",
+            &[
+                "address",
+                "addr",
+                "datasource",
+                "network address",
+                "server",
+                "server name",
+            ],
+            "Host of the server, without its port.",
+        ),
+        Keyword::new(
+            "database",
+            &["db", "initial catalog"],
+            "Name of the database.",
+        ),
+        Keyword::new(
+            "encrypt password",
+            &["encryptpassword"],
+            "Encryption of the password sent to the server.",
+        )
+        .kind(ValueKind::Enum(&["0", "1", "2"]))
+        .default_value("0"),
+        Keyword::new("language", &[], "Language of the messages of the server."),
+        Keyword::new(
+            "max pool size",
+            &["maxpoolsize"],
+            "Maximum number of connections in the pool.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("100"),
+        Keyword::new(
+            "min pool size",
+            &["minpoolsize"],
+            "Minimum number of connections in the pool.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("20"),
+        Keyword::new(
+            "named parameters",
+            &["namedparameters"],
+            "Binds the parameters by name instead of by position.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("true"),
+        Keyword::new(
+            "packet size",
+            &["packetsize"],
+            "Size of the network packets, in bytes.",
+        )
+        .kind(ValueKind::Int {
+            min: 512,
+            max: 65535,
+        })
+        .default_value("512"),
+        Keyword::new("pooling", &[], "Pools the connections.")
+            .kind(ValueKind::Bool)
+            .default_value("true"),
+        Keyword::new(
+            "port",
+            &["server port"],
+            "TCP port of the server, kept apart from the data source.",
+        )
+        .kind(ValueKind::Int { min: 1, max: 65535 })
+        .default_value("5000"),
+        Keyword::new("pwd", &["password"], "Password of the user.").kind(ValueKind::Secret),
+        Keyword::new(
+            "text size",
+            &["textsize"],
+            "Maximum size of the text and image values returned, in bytes.",
+        )
+        .kind(POSITIVE_INT),
+        Keyword::new(
+            "trusted file",
+            &["trustedfile"],
+            "File of the certificates trusted for the SSL connections.",
+        )
+        .kind(ValueKind::Path),
+        Keyword::new(
+            "uid",
+            &["user", "user id", "userid"],
+            "Login connecting to the server.",
+        )
+        .credential(),
+    ],
+    lookup: &[
+        ("addr", "data source"),
+        ("address", "data source"),
+        ("ansinull", "ansinull"),
+        ("app", "application name"),
+        ("application name", "application name"),
+        ("applicationname", "application name"),
+        ("character set", "charset"),
+        ("charset", "charset"),
+        ("client host name", "client host name"),
+        ("clienthostname", "client host name"),
+        ("connect timeout", "connection timeout"),
+        ("connection idle timeout", "connection idle timeout"),
+        ("connection lifetime", "connection lifetime"),
+        ("connection timeout", "connection timeout"),
+        ("connectionidletimeout", "connection idle timeout"),
+        ("connectionlifetime", "connection lifetime"),
+        ("connectiontimeout", "connection timeout"),
+        ("data source", "data source"),
+        ("database", "database"),
+        ("datasource", "data source"),
+        ("db", "database"),
+        ("encrypt password", "encrypt password"),
+        ("encryptpassword", "encrypt password"),
+        ("initial catalog", "database"),
+        ("language", "language"),
+        ("max pool size", "max pool size"),
+        ("maxpoolsize", "max pool size"),
+        ("min pool size", "min pool size"),
+        ("minpoolsize", "min pool size"),
+        ("named parameters", "named parameters"),
+        ("namedparameters", "named parameters"),
+        ("network address", "data source"),
+        ("packet size", "packet size"),
+        ("packetsize", "packet size"),
+        ("password", "pwd"),
+        ("pooling", "pooling"),
+        ("port", "port"),
+        ("pwd", "pwd"),
+        ("server", "data source"),
+        ("server name", "data source"),
+        ("server port", "port"),
+        ("text size", "text size"),
+        ("textsize", "text size"),
+        ("timeout", "connection timeout"),
+        ("trusted file", "trusted file"),
+        ("trustedfile", "trusted file"),
+        ("uid", "uid"),
+        ("user", "uid"),
+        ("user id", "uid"),
+        ("userid", "uid"),
+    ],
+};
+
+/// Keywords of the IBM Informix .NET provider connection strings.
+pub static INFORMIX: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "client_locale",
+            &["client locale"],
+            "Locale of the client, such as `en_US.utf8`.",
+        ),
+        Keyword::new(
+            "connection lifetime",
+            &["connectionlifetime"],
+            "Seconds a pooled connection lives before being closed, 0 living indefinitely.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("0"),
+        Keyword::new(
+            "connection timeout",
+            &["connect timeout", "connectiontimeout"],
+            "Seconds to wait for a connection to open.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("15"),
+        Keyword::new("database", &["db"], "Name of the database."),
+        Keyword::new(
+            "db_locale",
+            &["database locale", "dblocale"],
+            "Locale of the database.",
+        ),
+        Keyword::new(
+            "delimident",
+            &[],
+            "Reads the identifiers between double quotes as delimited identifiers.",
+        )
+        .kind(ValueKind::Bool),
+        Keyword::new(
+            "enlist",
+            &[],
+            "Enlists the connections in the ambient transaction.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("true"),
+        Keyword::new(
+            "exclusive",
+            &["xcl"],
+            "Opens the database in exclusive mode.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "fetch buffer size",
+            &["fbs", "fetchbuffersize"],
+            "Size of the buffer of the fetched rows, in bytes.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("32767"),
+        Keyword::new("host", &[], "Host of the server."),
+        Keyword::new(
+            "max pool size",
+            &["maxpoolsize"],
+            "Maximum number of connections in the pool.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("100"),
+        Keyword::new(
+            "min pool size",
+            &["minpoolsize"],
+            "Minimum number of connections in the pool.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("0"),
+        Keyword::new("password", &["pwd"], "Password of the user.").kind(ValueKind::Secret),
+        Keyword::new(
+            "persist security info",
+            &["persistsecurityinfo"],
+            "Keeps the password in the connection string once connected.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new("pooling", &[], "Pools the connections.")
+            .kind(ValueKind::Bool)
+            .default_value("true"),
+        Keyword::new("protocol", &["pro"], "Protocol of the connections.").kind(ValueKind::Enum(
+            &[
+                "onsoctcp", "ontlitcp", "onsocssl", "onipcshm", "onipcstr", "onipcnmp", "drsoctcp",
+                "drtlitcp", "drsocssl",
+            ],
+        )),
+        Keyword::new(
+            "server",
+            &["srv"],
+            "Name of the database server instance, the INFORMIXSERVER.",
+        ),
+        Keyword::new(
+            "service",
+            &["serv"],
+            "Port number or service name of the server.",
+        ),
+        Keyword::new("user id", &["uid"], "User connecting to the database.").credential(),
+    ],
+    lookup: &[
+        ("client locale", "client_locale"),
+        ("client_locale", "client_locale"),
+        ("connect timeout", "connection timeout"),
+        ("connection lifetime", "connection lifetime"),
+        ("connection timeout", "connection timeout"),
+        ("connectionlifetime", "connection lifetime"),
+        ("connectiontimeout", "connection timeout"),
+        ("database", "database"),
+        ("database locale", "db_locale"),
+        ("db", "database"),
+        ("db_locale", "db_locale"),
+        ("dblocale", "db_locale"),
+        ("delimident", "delimident"),
+        ("enlist", "enlist"),
+        ("exclusive", "exclusive"),
+        ("fbs", "fetch buffer size"),
+        ("fetch buffer size", "fetch buffer size"),
+        ("fetchbuffersize", "fetch buffer size"),
+        ("host", "host"),
+        ("max pool size", "max pool size"),
+        ("maxpoolsize", "max pool size"),
+        ("min pool size", "min pool size"),
+        ("minpoolsize", "min pool size"),
+        ("password", "password"),
+        ("persist security info", "persist security info"),
+        ("persistsecurityinfo", "persist security info"),
+        ("pooling", "pooling"),
+        ("pro", "protocol"),
+        ("protocol", "protocol"),
+        ("pwd", "password"),
+        ("serv", "service"),
+        ("server", "server"),
+        ("service", "service"),
+        ("srv", "server"),
+        ("uid", "user id"),
+        ("user id", "user id"),
+        ("xcl", "exclusive"),
+    ],
+};
+
+/// Keywords of the Teradata .NET Data Provider connection strings.
+pub static TERADATA: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "account string",
+            &["account"],
+            "Account string of the user, for the accounting of the sessions.",
+        ),
+        Keyword::new(
+            "authentication mechanism",
+            &["auth mechanism", "logon mechanism"],
+            "Logon mechanism, such as `TD2`, `LDAP` or `KRB5`.",
+        )
+        .kind(ValueKind::Enum(&[
+            "TD2", "LDAP", "KRB5", "TDNEGO", "JWT", "BROWSER",
+        ]))
+        .default_value("TD2"),
+        Keyword::new(
+            "authentication string",
+            &["auth string"],
+            "Data of the logon mechanism, such as a token.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new(
+            "command timeout",
+            &[],
+            "Seconds to wait for a command to complete.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("30"),
+        Keyword::new(
+            "connection pooling",
+            &["pooling"],
+            "Enables the connection pooling.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "connection timeout",
+            &["connect timeout"],
+            "Seconds to wait for a connection to open.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("20"),
+        Keyword::new(
+            "data source",
+            &["server", "host"],
+            "Host name or IP address of the database.",
+        ),
+        Keyword::new(
+            "data source dns entries",
+            &[],
+            "Number of COP entries of the host, `dbccop1` and so on.",
+        )
+        .kind(POSITIVE_INT),
+        Keyword::new(
+            "database",
+            &["default database"],
+            "Default database of the session.",
+        ),
+        Keyword::new(
+            "max pool size",
+            &["maximum pool size"],
+            "Maximum number of connections in the pool.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("100"),
+        Keyword::new(
+            "min pool size",
+            &["minimum pool size"],
+            "Minimum number of connections in the pool.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("0"),
+        Keyword::new("password", &["pwd"], "Password of the user.").kind(ValueKind::Secret),
+        Keyword::new("port number", &["port"], "Port of the database.")
+            .kind(ValueKind::Int { min: 1, max: 65535 })
+            .default_value("1025"),
+        Keyword::new(
+            "query band",
+            &[],
+            "Query band of the session, `name=value;` pairs.",
+        ),
+        Keyword::new(
+            "session character set",
+            &["charset"],
+            "Character set of the session, such as `UTF8` or `UTF16`.",
+        ),
+        Keyword::new(
+            "session mode",
+            &[],
+            "Transaction semantics of the session, `ANSI` or `TERA`.",
+        )
+        .kind(ValueKind::Enum(&["ANSI", "TERA"])),
+        Keyword::new("user id", &["uid", "user"], "Name of the user.").credential(),
+    ],
+    lookup: &[
+        ("account", "account string"),
+        ("account string", "account string"),
+        ("auth mechanism", "authentication mechanism"),
+        ("auth string", "authentication string"),
+        ("authentication mechanism", "authentication mechanism"),
+        ("authentication string", "authentication string"),
+        ("charset", "session character set"),
+        ("command timeout", "command timeout"),
+        ("connect timeout", "connection timeout"),
+        ("connection pooling", "connection pooling"),
+        ("connection timeout", "connection timeout"),
+        ("data source", "data source"),
+        ("data source dns entries", "data source dns entries"),
+        ("database", "database"),
+        ("default database", "database"),
+        ("host", "data source"),
+        ("logon mechanism", "authentication mechanism"),
+        ("max pool size", "max pool size"),
+        ("maximum pool size", "max pool size"),
+        ("min pool size", "min pool size"),
+        ("minimum pool size", "min pool size"),
+        ("password", "password"),
+        ("pooling", "connection pooling"),
+        ("port", "port number"),
+        ("port number", "port number"),
+        ("pwd", "password"),
+        ("query band", "query band"),
+        ("server", "data source"),
+        ("session character set", "session character set"),
+        ("session mode", "session mode"),
+        ("uid", "user id"),
+        ("user", "user id"),
+        ("user id", "user id"),
+    ],
+};
+
+/// Keywords of the Snowflake .NET driver connection strings.
+pub static SNOWFLAKE: Keywords = Keywords {
+    keywords: &[
+        Keyword::new("account", &[], "Identifier of the account, such as `xy12345` or `xy12345.us-east-1`."),
+        Keyword::new("application", &[], "Name of the application, reported to Snowflake."),
+        Keyword::new("authenticator", &[], "Authenticator, such as `snowflake`, `externalbrowser`, `snowflake_jwt`, `oauth` or the url of an Okta endpoint.")
+            .default_value("snowflake"),
+        Keyword::new("connection_timeout", &[], "Seconds to wait for a connection to open, 0 waiting indefinitely.")
+            .kind(POSITIVE_SECONDS)
+            .default_value("300"),
+        Keyword::new("db", &["database"], "Default database of the session."),
+        Keyword::new("host", &[], "Host of the account, `<account>.snowflakecomputing.com` when missing."),
+        Keyword::new("insecuremode", &[], "Disables the check of the revocation of the certificates.")
+            .kind(ValueKind::Bool)
+            .default_value("false"),
+        Keyword::new("maxpoolsize", &[], "Maximum number of connections in the pool.")
+            .kind(POSITIVE_INT)
+            .default_value("10"),
+        Keyword::new("minpoolsize", &[], "Minimum number of connections in the pool.")
+            .kind(POSITIVE_INT)
+            .default_value("2"),
+        Keyword::new("passcode", &[], "Passcode of the multi-factor authentication.")
+            .kind(ValueKind::Secret),
+        Keyword::new("password", &[], "Password of the user.")
+            .kind(ValueKind::Secret),
+        Keyword::new("poolingenabled", &[], "Enables the connection pooling.")
+            .kind(ValueKind::Bool)
+            .default_value("true"),
+        Keyword::new("port", &[], "Port of the host.")
+            .kind(ValueKind::Int { min: 1, max: 65535 })
+            .default_value("443"),
+        Keyword::new("private_key", &[], "Private key of the key pair authentication.")
+            .kind(ValueKind::Secret),
+        Keyword::new("private_key_file", &[], "Path of the private key of the key pair authentication.")
+            .kind(ValueKind::Path),
+        Keyword::new("private_key_pwd", &[], "Password of the encrypted private key.")
+            .kind(ValueKind::Secret),
+        Keyword::new("region", &[], "Region of the account, deprecated in favor of the account identifier."),
+        Keyword::new("role", &[], "Default role of the session."),
+        Keyword::new("schema", &[], "Default schema of the session."),
+        Keyword::new("token", &[], "OAuth token of the `oauth` authenticator.")
+            .kind(ValueKind::Secret),
+        Keyword::new("user", &[], "Login name of the user.")
+            .credential(),
+        Keyword::new("warehouse", &[], "Default virtual warehouse of the session."),
+    ],
+    lookup: &[
+        ("account", "account"),
+        ("application", "application"),
+        ("authenticator", "authenticator"),
+        ("connection_timeout", "connection_timeout"),
+        ("database", "db"),
+        ("db", "db"),
+        ("host", "host"),
+        ("insecuremode", "insecuremode"),
+        ("maxpoolsize", "maxpoolsize"),
+        ("minpoolsize", "minpoolsize"),
+        ("passcode", "passcode"),
+        ("password", "password"),
+        ("poolingenabled", "poolingenabled"),
+        ("port", "port"),
+        ("private_key", "private_key"),
+        ("private_key_file", "private_key_file"),
+        ("private_key_pwd", "private_key_pwd"),
+        ("region", "region"),
+        ("role", "role"),
+        ("schema", "schema"),
+        ("token", "token"),
+        ("user", "user"),
+        ("warehouse", "warehouse"),
+    ],
+};
+
+/// Keywords of the ClickHouse connection strings, the keys of the ADO.NET clients and
+/// the options of the `clickhouse://` urls.
+pub static CLICKHOUSE: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "compress",
+            &["compression"],
+            "Compresses the data sent and received.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("true"),
+        Keyword::new("database", &["db"], "Default database of the session.")
+            .default_value("default"),
+        Keyword::new("host", &["server"], "Host of the server.").default_value("localhost"),
+        Keyword::new("password", &["pwd"], "Password of the user.").kind(ValueKind::Secret),
+        Keyword::new(
+            "port",
+            &[],
+            "Port of the server, 9000 for the native protocol and 8123 for http.",
+        )
+        .kind(ValueKind::Int { min: 1, max: 65535 }),
+        Keyword::new(
+            "protocol",
+            &[],
+            "Protocol of the http clients, `http` or `https`.",
+        )
+        .kind(ValueKind::Enum(&["http", "https"])),
+        Keyword::new(
+            "sessionid",
+            &["session id"],
+            "Identifier of the session, when the session is used.",
+        ),
+        Keyword::new("timeout", &[], "Seconds to wait for a command to complete.")
+            .kind(POSITIVE_SECONDS),
+        Keyword::new("username", &["user", "user id", "uid"], "Name of the user.")
+            .credential()
+            .default_value("default"),
+        Keyword::new(
+            "usesession",
+            &["use session"],
+            "Keeps the temporary tables and the settings between the commands.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+    ],
+    lookup: &[
+        ("compress", "compress"),
+        ("compression", "compress"),
+        ("database", "database"),
+        ("db", "database"),
+        ("host", "host"),
+        ("password", "password"),
+        ("port", "port"),
+        ("protocol", "protocol"),
+        ("pwd", "password"),
+        ("server", "host"),
+        ("session id", "sessionid"),
+        ("sessionid", "sessionid"),
+        ("timeout", "timeout"),
+        ("uid", "username"),
+        ("use session", "usesession"),
+        ("user", "username"),
+        ("user id", "username"),
+        ("username", "username"),
+        ("usesession", "usesession"),
+    ],
+};
+
+/// Keywords of the DataStax C# driver connection strings of Cassandra.
+pub static CASSANDRA: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "cluster name",
+            &[],
+            "Name of the cluster, reported in the logs of the driver.",
+        ),
+        Keyword::new(
+            "contact points",
+            &["contactpoints", "host", "hosts"],
+            "Nodes contacted first to discover the cluster, separated by commas.",
+        )
+        .kind(ValueKind::List { separator: ',' }),
+        Keyword::new(
+            "default keyspace",
+            &["keyspace"],
+            "Keyspace of the queries without one.",
+        ),
+        Keyword::new("password", &["pwd"], "Password of the user.").kind(ValueKind::Secret),
+        Keyword::new("port", &[], "Port of the contact points without one.")
+            .kind(ValueKind::Int { min: 1, max: 65535 })
+            .default_value("9042"),
+        Keyword::new("username", &["user", "user id", "uid"], "Name of the user.").credential(),
+    ],
+    lookup: &[
+        ("cluster name", "cluster name"),
+        ("contact points", "contact points"),
+        ("contactpoints", "contact points"),
+        ("default keyspace", "default keyspace"),
+        ("host", "contact points"),
+        ("hosts", "contact points"),
+        ("keyspace", "default keyspace"),
+        ("password", "password"),
+        ("port", "port"),
+        ("pwd", "password"),
+        ("uid", "username"),
+        ("user", "username"),
+        ("user id", "username"),
+        ("username", "username"),
+    ],
+};
+
+/// Keywords of the Amazon Redshift ODBC and .NET connection strings.
+pub static REDSHIFT: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "accesskeyid",
+            &["access key id"],
+            "Access key of the IAM user or role.",
+        ),
+        Keyword::new(
+            "autocreate",
+            &["auto create"],
+            "Creates the `DbUser` when it does not exist.",
+        )
+        .kind(FLAG)
+        .default_value("false"),
+        Keyword::new(
+            "clusterid",
+            &["cluster id", "cluster identifier"],
+            "Identifier of the cluster, read from the server when missing.",
+        ),
+        Keyword::new("database", &["db"], "Name of the database."),
+        Keyword::new(
+            "dbgroups",
+            &["db groups"],
+            "Groups joined by the `DbUser`, separated by commas.",
+        )
+        .kind(ValueKind::List { separator: ',' }),
+        Keyword::new(
+            "dbuser",
+            &["db user"],
+            "Database user of the IAM credentials.",
+        )
+        .credential(),
+        Keyword::new("driver", &[], "Name of the ODBC driver."),
+        Keyword::new(
+            "iam",
+            &[],
+            "Authenticates with the temporary credentials of IAM.",
+        )
+        .kind(FLAG)
+        .default_value("false"),
+        Keyword::new(
+            "plugin_name",
+            &["plugin name"],
+            "Credentials provider of a federated identity.",
+        ),
+        Keyword::new("port", &[], "Port of the cluster.")
+            .kind(ValueKind::Int { min: 1, max: 65535 })
+            .default_value("5439"),
+        Keyword::new("profile", &[], "Profile of the AWS credentials file."),
+        Keyword::new("pwd", &["password"], "Password of the user.").kind(ValueKind::Secret),
+        Keyword::new(
+            "region",
+            &[],
+            "AWS region of the cluster, read from the server when missing.",
+        ),
+        Keyword::new(
+            "secretaccesskey",
+            &["secret access key"],
+            "Secret access key of the IAM user or role.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new(
+            "server",
+            &["host", "data source"],
+            "Endpoint of the cluster.",
+        ),
+        Keyword::new(
+            "sessiontoken",
+            &["session token"],
+            "Session token of temporary credentials.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new(
+            "sslmode",
+            &["ssl mode"],
+            "Use of a SSL connection and its verification.",
+        )
+        .kind(ValueKind::Enum(&[
+            "disable",
+            "allow",
+            "prefer",
+            "require",
+            "verify-ca",
+            "verify-full",
+        ]))
+        .default_value("verify-ca"),
+        Keyword::new("uid", &["user id", "user", "username"], "Name of the user.").credential(),
+    ],
+    lookup: &[
+        ("access key id", "accesskeyid"),
+        ("accesskeyid", "accesskeyid"),
+        ("auto create", "autocreate"),
+        ("autocreate", "autocreate"),
+        ("cluster id", "clusterid"),
+        ("cluster identifier", "clusterid"),
+        ("clusterid", "clusterid"),
+        ("data source", "server"),
+        ("database", "database"),
+        ("db", "database"),
+        ("db groups", "dbgroups"),
+        ("db user", "dbuser"),
+        ("dbgroups", "dbgroups"),
+        ("dbuser", "dbuser"),
+        ("driver", "driver"),
+        ("host", "server"),
+        ("iam", "iam"),
+        ("password", "pwd"),
+        ("plugin name", "plugin_name"),
+        ("plugin_name", "plugin_name"),
+        ("port", "port"),
+        ("profile", "profile"),
+        ("pwd", "pwd"),
+        ("region", "region"),
+        ("secret access key", "secretaccesskey"),
+        ("secretaccesskey", "secretaccesskey"),
+        ("server", "server"),
+        ("session token", "sessiontoken"),
+        ("sessiontoken", "sessiontoken"),
+        ("ssl mode", "sslmode"),
+        ("sslmode", "sslmode"),
+        ("uid", "uid"),
+        ("user", "uid"),
+        ("user id", "uid"),
+        ("username", "uid"),
+    ],
+};
+
+/// Keywords of the Vertica ADO.NET provider connection strings.
+pub static VERTICA: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "backupservernode",
+            &["backup server node"],
+            "Hosts tried when the `Host` is down, separated by commas.",
+        )
+        .kind(ValueKind::List { separator: ',' }),
+        Keyword::new(
+            "connectionloadbalance",
+            &["connection load balance"],
+            "Redirects the connection to a node chosen by the load balancing of the cluster.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+        Keyword::new(
+            "connectiontimeout",
+            &["connection timeout", "connect timeout"],
+            "Seconds to wait for a connection to open, 0 waiting indefinitely.",
+        )
+        .kind(POSITIVE_SECONDS)
+        .default_value("0"),
+        Keyword::new("database", &["db"], "Name of the database."),
+        Keyword::new(
+            "host",
+            &["server", "data source"],
+            "Host of the primary node.",
+        ),
+        Keyword::new("label", &[], "Label of the session."),
+        Keyword::new(
+            "maxpoolsize",
+            &["max pool size"],
+            "Maximum number of connections in the pool.",
+        )
+        .kind(POSITIVE_INT)
+        .default_value("20"),
+        Keyword::new("password", &["pwd"], "Password of the user.").kind(ValueKind::Secret),
+        Keyword::new("pooling", &[], "Enables the connection pooling.")
+            .kind(ValueKind::Bool)
+            .default_value("false"),
+        Keyword::new("port", &[], "Port of the nodes without one.")
+            .kind(ValueKind::Int { min: 1, max: 65535 })
+            .default_value("5433"),
+        Keyword::new(
+            "sslmode",
+            &["ssl mode", "tlsmode", "tls mode"],
+            "Use of a TLS connection and its verification.",
+        )
+        .kind(ValueKind::Enum(&[
+            "disable",
+            "prefer",
+            "require",
+            "verify-ca",
+            "verify-full",
+        ]))
+        .default_value("prefer"),
+        Keyword::new("user", &["user id", "uid", "username"], "Name of the user.").credential(),
+    ],
+    lookup: &[
+        ("backup server node", "backupservernode"),
+        ("backupservernode", "backupservernode"),
+        ("connect timeout", "connectiontimeout"),
+        ("connection load balance", "connectionloadbalance"),
+        ("connection timeout", "connectiontimeout"),
+        ("connectionloadbalance", "connectionloadbalance"),
+        ("connectiontimeout", "connectiontimeout"),
+        ("data source", "host"),
+        ("database", "database"),
+        ("db", "database"),
+        ("host", "host"),
+        ("label", "label"),
+        ("max pool size", "maxpoolsize"),
+        ("maxpoolsize", "maxpoolsize"),
+        ("password", "password"),
+        ("pooling", "pooling"),
+        ("port", "port"),
+        ("pwd", "password"),
+        ("server", "host"),
+        ("ssl mode", "sslmode"),
+        ("sslmode", "sslmode"),
+        ("tls mode", "sslmode"),
+        ("tlsmode", "sslmode"),
+        ("uid", "user"),
+        ("user", "user"),
+        ("user id", "user"),
+        ("username", "user"),
+    ],
+};
+
+/// Keywords of the Simba Spark ODBC and JDBC connection strings of Databricks.
+pub static DATABRICKS: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "auth_accesstoken",
+            &[],
+            "OAuth access token, with `AuthMech=11`.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new(
+            "auth_flow",
+            &[],
+            "OAuth flow, 0 for a token, 1 for the client credentials and 2 for the browser.",
+        )
+        .kind(ValueKind::Enum(&["0", "1", "2"])),
+        Keyword::new(
+            "authmech",
+            &[],
+            "Authentication mechanism, 3 for a personal access token and 11 for OAuth.",
+        )
+        .kind(ValueKind::Enum(&["0", "1", "2", "3", "6", "7", "11"])),
+        Keyword::new(
+            "catalog",
+            &["conncatalog"],
+            "Default catalog of the queries.",
+        ),
+        Keyword::new("driver", &[], "Name of the ODBC driver."),
+        Keyword::new("host", &[], "Server hostname of the workspace."),
+        Keyword::new(
+            "httppath",
+            &[],
+            "HTTP path of the SQL warehouse or of the cluster.",
+        ),
+        Keyword::new("port", &[], "Port of the workspace.")
+            .kind(ValueKind::Int { min: 1, max: 65535 })
+            .default_value("443"),
+        Keyword::new(
+            "pwd",
+            &["password"],
+            "Password of the user, the personal access token of the `token` user.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new("schema", &["connschema"], "Default schema of the queries.")
+            .default_value("default"),
+        Keyword::new("ssl", &[], "Uses TLS, 1 being required by Databricks."),
+        Keyword::new(
+            "thrifttransport",
+            &[],
+            "Transport of the ODBC driver, 2 for HTTP.",
+        )
+        .kind(ValueKind::Enum(&["0", "1", "2"])),
+        Keyword::new(
+            "transportmode",
+            &[],
+            "Transport of the JDBC driver, `http` for Databricks.",
+        ),
+        Keyword::new(
+            "uid",
+            &["user"],
+            "Name of the user, `token` with a personal access token.",
+        )
+        .credential(),
+    ],
+    lookup: &[
+        ("auth_accesstoken", "auth_accesstoken"),
+        ("auth_flow", "auth_flow"),
+        ("authmech", "authmech"),
+        ("catalog", "catalog"),
+        ("conncatalog", "catalog"),
+        ("connschema", "schema"),
+        ("driver", "driver"),
+        ("host", "host"),
+        ("httppath", "httppath"),
+        ("password", "pwd"),
+        ("port", "port"),
+        ("pwd", "pwd"),
+        ("schema", "schema"),
+        ("ssl", "ssl"),
+        ("thrifttransport", "thrifttransport"),
+        ("transportmode", "transportmode"),
+        ("uid", "uid"),
+        ("user", "uid"),
+    ],
+};
+
+/// Keywords of the Cloudera ODBC connection strings of Apache Hive and Impala.
+pub static HIVE: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "auth_accesstoken",
+            &[],
+            "OAuth access token, with `AuthMech=11`.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new(
+            "auth_client_id",
+            &[],
+            "OAuth client id of the client credentials flow.",
+        ),
+        Keyword::new(
+            "auth_client_secret",
+            &[],
+            "OAuth client secret of the client credentials flow.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new(
+            "authmech",
+            &[],
+            "Authentication mechanism, 1 for Kerberos, 3 for a password and 11 for OAuth.",
+        )
+        .kind(ValueKind::Enum(&["0", "1", "2", "3", "6", "7", "11"])),
+        Keyword::new(
+            "delegationuid",
+            &[],
+            "User on behalf of whom the queries are run.",
+        )
+        .credential(),
+        Keyword::new("driver", &[], "Name of the ODBC driver."),
+        Keyword::new(
+            "host",
+            &[],
+            "Host of the server, or of the ZooKeeper ensemble.",
+        ),
+        Keyword::new(
+            "krbhostfqdn",
+            &[],
+            "Fully qualified name of the Kerberos host.",
+        ),
+        Keyword::new("krbrealm", &[], "Kerberos realm of the server."),
+        Keyword::new("krbservicename", &[], "Kerberos principal of the server."),
+        Keyword::new(
+            "port",
+            &[],
+            "Port of the server, 10000 for Hive and 21050 for Impala.",
+        )
+        .kind(ValueKind::Int { min: 1, max: 65535 }),
+        Keyword::new("pwd", &["password"], "Password of the user.").kind(ValueKind::Secret),
+        Keyword::new("schema", &[], "Default database of the queries.").default_value("default"),
+        Keyword::new("ssl", &[], "Uses TLS, `1` or `0`."),
+        Keyword::new(
+            "sslkeystore",
+            &[],
+            "Path of the key store of the client certificate.",
+        )
+        .kind(ValueKind::Path),
+        Keyword::new("sslkeystorepwd", &[], "Password of the key store.").kind(ValueKind::Secret),
+        Keyword::new(
+            "trustedcerts",
+            &[],
+            "Path of the certificates of the trusted authorities.",
+        )
+        .kind(ValueKind::Path),
+        Keyword::new("truststorepwd", &[], "Password of the trust store.").kind(ValueKind::Secret),
+        Keyword::new("uid", &["user"], "Name of the user.").credential(),
+    ],
+    lookup: &[
+        ("auth_accesstoken", "auth_accesstoken"),
+        ("auth_client_id", "auth_client_id"),
+        ("auth_client_secret", "auth_client_secret"),
+        ("authmech", "authmech"),
+        ("delegationuid", "delegationuid"),
+        ("driver", "driver"),
+        ("host", "host"),
+        ("krbhostfqdn", "krbhostfqdn"),
+        ("krbrealm", "krbrealm"),
+        ("krbservicename", "krbservicename"),
+        ("password", "pwd"),
+        ("port", "port"),
+        ("pwd", "pwd"),
+        ("schema", "schema"),
+        ("ssl", "ssl"),
+        ("sslkeystore", "sslkeystore"),
+        ("sslkeystorepwd", "sslkeystorepwd"),
+        ("trustedcerts", "trustedcerts"),
+        ("truststorepwd", "truststorepwd"),
+        ("uid", "uid"),
+        ("user", "uid"),
+    ],
+};
+
+/// Keywords of the Simba ODBC driver connection strings of Google BigQuery.
+pub static BIGQUERY: Keywords = Keywords {
+    keywords: &[
+        Keyword::new("additionalprojects", &[], "Projects queried besides the `Catalog`, separated by commas."),
+        Keyword::new("allowlargeresults", &[], "Allow results larger than the response limit, with the legacy SQL.")
+            .kind(ValueKind::Enum(&["0", "1"]))
+            .default_value("0"),
+        Keyword::new("catalog", &[], "Project billed for the queries."),
+        Keyword::new("clientid", &[], "OAuth client of the user authentication."),
+        Keyword::new("clientsecret", &[], "Secret of the OAuth client.")
+            .kind(ValueKind::Secret),
+        Keyword::new("defaultdataset", &[], "Dataset of the unqualified table names."),
+        Keyword::new("driver", &[], "Name of the ODBC driver."),
+        Keyword::new("dsn", &[], "Name of the data source."),
+        Keyword::new("email", &[], "Email of the service account."),
+        Keyword::new("keyfilepath", &[], "Path of the JSON or P12 key file of the service account.")
+            .kind(ValueKind::Path),
+        Keyword::new("largeresultsdatasetid", &[], "Dataset of the temporary tables of the large results."),
+        Keyword::new("oauthmechanism", &[], "Authentication, 0 for a service account, 1 for a user, 2 for a pre-generated token, 3 for the application default credentials and 4 for an external account.")
+            .kind(ValueKind::Enum(&["0", "1", "2", "3", "4"])),
+        Keyword::new("refreshtoken", &[], "Refresh token of the user authentication.")
+            .kind(ValueKind::Secret),
+        Keyword::new("sqldialect", &[], "Dialect of the queries, 0 for the legacy SQL and 1 for the standard SQL.")
+            .kind(ValueKind::Enum(&["0", "1"]))
+            .default_value("1"),
+        Keyword::new("timeout", &[], "Seconds waited for a query.").kind(POSITIVE_SECONDS),
+        Keyword::new("trustedcerts", &[], "Path of the certificates trusted for TLS.")
+            .kind(ValueKind::Path),
+    ],
+    lookup: &[
+        ("additionalprojects", "additionalprojects"),
+        ("allowlargeresults", "allowlargeresults"),
+        ("catalog", "catalog"),
+        ("clientid", "clientid"),
+        ("clientsecret", "clientsecret"),
+        ("defaultdataset", "defaultdataset"),
+        ("driver", "driver"),
+        ("dsn", "dsn"),
+        ("email", "email"),
+        ("keyfilepath", "keyfilepath"),
+        ("largeresultsdatasetid", "largeresultsdatasetid"),
+        ("oauthmechanism", "oauthmechanism"),
+        ("refreshtoken", "refreshtoken"),
+        ("sqldialect", "sqldialect"),
+        ("timeout", "timeout"),
+        ("trustedcerts", "trustedcerts"),
+    ],
+};
+
+/// Keywords of the Azure Storage connection strings.
+pub static AZURE_STORAGE: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "accountkey",
+            &[],
+            "Shared key of the storage account, encoded in base64.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new("accountname", &[], "Name of the storage account."),
+        Keyword::new(
+            "blobendpoint",
+            &[],
+            "Url of the blob service, replacing the one of the account.",
+        ),
+        Keyword::new(
+            "defaultendpointsprotocol",
+            &[],
+            "Scheme of the endpoints of the account.",
+        )
+        .kind(ValueKind::Enum(&["http", "https"]))
+        .default_value("https"),
+        Keyword::new(
+            "developmentstorageproxyuri",
+            &[],
+            "Url of a proxy of the storage emulator.",
+        ),
+        Keyword::new(
+            "endpointsuffix",
+            &[],
+            "Domain of the endpoints of the account.",
+        )
+        .default_value("core.windows.net"),
+        Keyword::new(
+            "fileendpoint",
+            &[],
+            "Url of the file service, replacing the one of the account.",
+        ),
+        Keyword::new(
+            "queueendpoint",
+            &[],
+            "Url of the queue service, replacing the one of the account.",
+        ),
+        Keyword::new(
+            "sharedaccesssignature",
+            &[],
+            "Shared access signature used instead of the account key.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new(
+            "tableendpoint",
+            &[],
+            "Url of the table service, replacing the one of the account.",
+        ),
+        Keyword::new(
+            "usedevelopmentstorage",
+            &[],
+            "Connect to the local storage emulator with its well-known account.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+    ],
+    lookup: &[
+        ("accountkey", "accountkey"),
+        ("accountname", "accountname"),
+        ("blobendpoint", "blobendpoint"),
+        ("defaultendpointsprotocol", "defaultendpointsprotocol"),
+        ("developmentstorageproxyuri", "developmentstorageproxyuri"),
+        ("endpointsuffix", "endpointsuffix"),
+        ("fileendpoint", "fileendpoint"),
+        ("queueendpoint", "queueendpoint"),
+        ("sharedaccesssignature", "sharedaccesssignature"),
+        ("tableendpoint", "tableendpoint"),
+        ("usedevelopmentstorage", "usedevelopmentstorage"),
+    ],
+};
+
+/// Keywords of the Azure Service Bus connection strings.
+pub static SERVICE_BUS: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "endpoint",
+            &[],
+            "Url of the namespace, `sb://<namespace>.servicebus.windows.net/`.",
+        ),
+        Keyword::new("entitypath", &[], "Name of the queue or of the topic."),
+        Keyword::new("sharedaccesskey", &[], "Key of the shared access policy.")
+            .kind(ValueKind::Secret),
+        Keyword::new(
+            "sharedaccesskeyname",
+            &[],
+            "Name of the shared access policy.",
+        )
+        .credential(),
+        Keyword::new(
+            "sharedaccesssignature",
+            &[],
+            "Shared access signature used instead of the policy key.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new(
+            "usedevelopmentemulator",
+            &[],
+            "Connect to the local Service Bus emulator without TLS.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+    ],
+    lookup: &[
+        ("endpoint", "endpoint"),
+        ("entitypath", "entitypath"),
+        ("sharedaccesskey", "sharedaccesskey"),
+        ("sharedaccesskeyname", "sharedaccesskeyname"),
+        ("sharedaccesssignature", "sharedaccesssignature"),
+        ("usedevelopmentemulator", "usedevelopmentemulator"),
+    ],
+};
+
+/// Keywords of the Azure Event Hubs connection strings.
+pub static EVENT_HUBS: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "endpoint",
+            &[],
+            "Url of the namespace, `sb://<namespace>.servicebus.windows.net/`.",
+        ),
+        Keyword::new("entitypath", &[], "Name of the event hub."),
+        Keyword::new(
+            "operationtimeout",
+            &[],
+            "Time waited for an operation, written `[d.]hh:mm:ss[.fffffff]`.",
+        )
+        .default_value("00:01:00"),
+        Keyword::new("sharedaccesskey", &[], "Key of the shared access policy.")
+            .kind(ValueKind::Secret),
+        Keyword::new(
+            "sharedaccesskeyname",
+            &[],
+            "Name of the shared access policy.",
+        )
+        .credential(),
+        Keyword::new(
+            "sharedaccesssignature",
+            &[],
+            "Shared access signature used instead of the policy key.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new(
+            "transporttype",
+            &[],
+            "Protocol of the connection, AMQP over TCP or over web sockets.",
+        )
+        .kind(ValueKind::Enum(&["Amqp", "AmqpWebSockets"]))
+        .default_value("Amqp"),
+        Keyword::new(
+            "usedevelopmentemulator",
+            &[],
+            "Connect to the local Event Hubs emulator without TLS.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+    ],
+    lookup: &[
+        ("endpoint", "endpoint"),
+        ("entitypath", "entitypath"),
+        ("operationtimeout", "operationtimeout"),
+        ("sharedaccesskey", "sharedaccesskey"),
+        ("sharedaccesskeyname", "sharedaccesskeyname"),
+        ("sharedaccesssignature", "sharedaccesssignature"),
+        ("transporttype", "transporttype"),
+        ("usedevelopmentemulator", "usedevelopmentemulator"),
+    ],
+};
+
+/// Keywords of the Azure IoT Hub connection strings of the devices, modules and
+/// services.
+pub static IOT_HUB: Keywords = Keywords {
+    keywords: &[
+        Keyword::new("deviceid", &[], "Identifier of the device."),
+        Keyword::new(
+            "gatewayhostname",
+            &[],
+            "Host of the IoT Edge gateway relaying the device.",
+        ),
+        Keyword::new(
+            "hostname",
+            &[],
+            "Host of the hub, `<hub>.azure-devices.net`.",
+        ),
+        Keyword::new("moduleid", &[], "Identifier of the module of the device."),
+        Keyword::new(
+            "sharedaccesskey",
+            &[],
+            "Symmetric key of the device, of the module or of the policy.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new(
+            "sharedaccesskeyname",
+            &[],
+            "Name of the shared access policy of a service.",
+        )
+        .credential(),
+        Keyword::new(
+            "sharedaccesssignature",
+            &[],
+            "Shared access signature used instead of the key.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new(
+            "x509",
+            &[],
+            "Authenticate the device with its X.509 certificate.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+    ],
+    lookup: &[
+        ("deviceid", "deviceid"),
+        ("gatewayhostname", "gatewayhostname"),
+        ("hostname", "hostname"),
+        ("moduleid", "moduleid"),
+        ("sharedaccesskey", "sharedaccesskey"),
+        ("sharedaccesskeyname", "sharedaccesskeyname"),
+        ("sharedaccesssignature", "sharedaccesssignature"),
+        ("x509", "x509"),
+    ],
+};
+
+/// Keywords of the Azure Cosmos DB connection strings of the API for NoSQL.
+pub static COSMOS: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "accountendpoint",
+            &[],
+            "Url of the account, `https://<account>.documents.azure.com:443/`.",
+        ),
+        Keyword::new(
+            "accountkey",
+            &[],
+            "Primary or secondary key of the account.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new("database", &[], "Database of the account."),
+        Keyword::new(
+            "disableservercertificatevalidation",
+            &[],
+            "Trust any certificate, such as the one of the emulator.",
+        )
+        .kind(ValueKind::Bool)
+        .default_value("false"),
+    ],
+    lookup: &[
+        ("accountendpoint", "accountendpoint"),
+        ("accountkey", "accountkey"),
+        ("database", "database"),
+        (
+            "disableservercertificatevalidation",
+            "disableservercertificatevalidation",
+        ),
+    ],
+};
+
+/// Keywords of the Kafka client configurations, the properties of librdkafka and of
+/// the Java client.
+pub static KAFKA: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "acks",
+            &[],
+            "Acknowledgements awaited by the producer, 0, 1 or all.",
+        )
+        .kind(ValueKind::Enum(&["0", "1", "-1", "all"])),
+        Keyword::new(
+            "bootstrap.servers",
+            &["metadata.broker.list"],
+            "Brokers contacted first, `host:port` separated by commas.",
+        ),
+        Keyword::new(
+            "client.id",
+            &[],
+            "Name of the client in the logs of the brokers.",
+        ),
+        Keyword::new("group.id", &[], "Consumer group of the consumer."),
+        Keyword::new(
+            "sasl.jaas.config",
+            &[],
+            "JAAS login module of the Java client, holding the credentials.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new(
+            "sasl.mechanisms",
+            &["sasl.mechanism"],
+            "SASL mechanism of the authentication.",
+        )
+        .kind(ValueKind::Enum(&[
+            "GSSAPI",
+            "PLAIN",
+            "SCRAM-SHA-256",
+            "SCRAM-SHA-512",
+            "OAUTHBEARER",
+        ]))
+        .default_value("GSSAPI"),
+        Keyword::new(
+            "sasl.password",
+            &[],
+            "Password of the PLAIN and SCRAM mechanisms.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new(
+            "sasl.username",
+            &[],
+            "User of the PLAIN and SCRAM mechanisms.",
+        )
+        .credential(),
+        Keyword::new(
+            "security.protocol",
+            &[],
+            "Protocol of the connections to the brokers.",
+        )
+        .kind(ValueKind::Enum(&[
+            "PLAINTEXT",
+            "SSL",
+            "SASL_PLAINTEXT",
+            "SASL_SSL",
+        ]))
+        .default_value("PLAINTEXT"),
+        Keyword::new(
+            "ssl.ca.location",
+            &[],
+            "Path of the certificates of the authorities trusted.",
+        )
+        .kind(ValueKind::Path),
+        Keyword::new(
+            "ssl.certificate.location",
+            &[],
+            "Path of the certificate of the client.",
+        )
+        .kind(ValueKind::Path),
+        Keyword::new(
+            "ssl.key.location",
+            &[],
+            "Path of the private key of the client.",
+        )
+        .kind(ValueKind::Path),
+        Keyword::new(
+            "ssl.key.password",
+            &[],
+            "Password of the private key of the client.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new(
+            "ssl.keystore.password",
+            &[],
+            "Password of the keystore of the Java client.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new(
+            "ssl.truststore.password",
+            &[],
+            "Password of the truststore of the Java client.",
+        )
+        .kind(ValueKind::Secret),
+    ],
+    lookup: &[
+        ("acks", "acks"),
+        ("bootstrap.servers", "bootstrap.servers"),
+        ("client.id", "client.id"),
+        ("group.id", "group.id"),
+        ("metadata.broker.list", "bootstrap.servers"),
+        ("sasl.jaas.config", "sasl.jaas.config"),
+        ("sasl.mechanism", "sasl.mechanisms"),
+        ("sasl.mechanisms", "sasl.mechanisms"),
+        ("sasl.password", "sasl.password"),
+        ("sasl.username", "sasl.username"),
+        ("security.protocol", "security.protocol"),
+        ("ssl.ca.location", "ssl.ca.location"),
+        ("ssl.certificate.location", "ssl.certificate.location"),
+        ("ssl.key.location", "ssl.key.location"),
+        ("ssl.key.password", "ssl.key.password"),
+        ("ssl.keystore.password", "ssl.keystore.password"),
+        ("ssl.truststore.password", "ssl.truststore.password"),
+    ],
+};
+
+/// Keywords of the Elasticsearch connection settings of the Elastic .NET clients.
+pub static ELASTICSEARCH: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "apikey",
+            &[],
+            "API key of the authentication, encoded in base64.",
+        )
+        .kind(ValueKind::Secret),
+        Keyword::new(
+            "certificatefingerprint",
+            &[],
+            "SHA-256 fingerprint of the certificate expected from the nodes.",
+        ),
+        Keyword::new(
+            "cloudid",
+            &[],
+            "Identifier of an Elastic Cloud deployment, `<name>:<base64>`.",
+        ),
+        Keyword::new(
+            "nodes",
+            &["node"],
+            "Urls of the nodes, separated by commas.",
+        ),
+        Keyword::new("password", &[], "Password of the basic authentication.")
+            .kind(ValueKind::Secret),
+        Keyword::new("username", &[], "User of the basic authentication.").credential(),
+    ],
+    lookup: &[
+        ("apikey", "apikey"),
+        ("certificatefingerprint", "certificatefingerprint"),
+        ("cloudid", "cloudid"),
+        ("node", "nodes"),
+        ("nodes", "nodes"),
+        ("password", "password"),
+        ("username", "username"),
+    ],
+};
+
+/// Keywords of the InfluxDB connection strings, of the 2.x API and of the 1.x API.
+pub static INFLUX: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "bucket",
+            &[],
+            "Bucket written and queried, with the 2.x API.",
+        ),
+        Keyword::new(
+            "database",
+            &["db"],
+            "Database written and queried, with the 1.x API.",
+        ),
+        Keyword::new(
+            "org",
+            &["organization"],
+            "Organization of the bucket, with the 2.x API.",
+        ),
+        Keyword::new("password", &[], "Password of the user, with the 1.x API.")
+            .kind(ValueKind::Secret),
+        Keyword::new(
+            "retentionpolicy",
+            &["rp"],
+            "Retention policy of the database, with the 1.x API.",
+        ),
+        Keyword::new("token", &[], "API token, with the 2.x API.").kind(ValueKind::Secret),
+        Keyword::new(
+            "url",
+            &[],
+            "Url of the server, `http://localhost:8086` by default.",
+        )
+        .default_value("http://localhost:8086"),
+        Keyword::new("username", &["user"], "User, with the 1.x API.").credential(),
+    ],
+    lookup: &[
+        ("bucket", "bucket"),
+        ("database", "database"),
+        ("db", "database"),
+        ("org", "org"),
+        ("organization", "org"),
+        ("password", "password"),
+        ("retentionpolicy", "retentionpolicy"),
+        ("rp", "retentionpolicy"),
+        ("token", "token"),
+        ("url", "url"),
+        ("user", "username"),
+        ("username", "username"),
+    ],
+};
+
+/// Keywords of the LDAP and Active Directory connection strings.
+pub static LDAP: Keywords = Keywords {
+    keywords: &[
+        Keyword::new(
+            "basedn",
+            &["base dn", "searchbase", "search base"],
+            "Distinguished name of the entry the searches start from.",
+        ),
+        Keyword::new(
+            "binddn",
+            &["bind dn", "user", "username", "user id", "uid"],
+            "Distinguished name, user principal name or account name of the user bound.",
+        )
+        .credential(),
+        Keyword::new("password", &["pwd"], "Password of the user bound.").kind(ValueKind::Secret),
+        Keyword::new("port", &[], "TCP port of the server, 636 with SSL.")
+            .kind(ValueKind::Int { min: 1, max: 65535 })
+            .default_value("389"),
+        Keyword::new(
+            "server",
+            &["host", "hostname", "server name"],
+            "Host name of the domain controller or of the directory server.",
+        ),
+        Keyword::new("usessl", &["ssl", "secure"], "Connect with LDAP over SSL.")
+            .kind(ValueKind::Bool)
+            .default_value("false"),
+    ],
+    lookup: &[
+        ("base dn", "basedn"),
+        ("basedn", "basedn"),
+        ("bind dn", "binddn"),
+        ("binddn", "binddn"),
+        ("host", "server"),
+        ("hostname", "server"),
+        ("password", "password"),
+        ("port", "port"),
+        ("pwd", "password"),
+        ("search base", "basedn"),
+        ("searchbase", "basedn"),
+        ("secure", "usessl"),
+        ("server", "server"),
+        ("server name", "server"),
+        ("ssl", "usessl"),
+        ("uid", "binddn"),
+        ("user", "binddn"),
+        ("user id", "binddn"),
+        ("username", "binddn"),
+        ("usessl", "usessl"),
+    ],
+};
+
+#[cfg(test)]
+fn assert_consistent(keywords: &Keywords) {
+    for w in keywords.keywords.windows(2) {
+        assert!(
+            w[0].name < w[1].name,
+            "keyword `{}` is not sorted",
+            w[1].name
+        );
+    }
+
+    for w in keywords.lookup.windows(2) {
+        assert!(w[0].0 < w[1].0, "lookup `{}` is not sorted", w[1].0);
+    }
+
+    let count: usize = keywords.iter().map(|k| k.names().count()).sum();
+    assert_eq!(count, keywords.lookup.len());
+
+    for keyword in keywords.iter() {
+        for name in keyword.names() {
+            assert_eq!(keyword.name, keywords.find(name).unwrap().name);
+        }
+
+        for synonym in keyword.deprecated_synonyms {
+            assert!(
+                keyword.synonyms.contains(synonym),
+                "`{}` is not a synonym",
+                synonym
+            );
+        }
+    }
+}
+
+#[test]
+fn keyword_tables_are_consistent() {
+    assert_consistent(&EF);
+    assert_consistent(&MS_SQL);
+    assert_consistent(&ODBC);
+    assert_consistent(&POSTGRES);
+    assert_consistent(&MYSQL);
+    assert_consistent(&SQLITE);
+    assert_consistent(&OLE_DB);
+    assert_consistent(&ORACLE);
+    assert_consistent(&MONGO);
+    assert_consistent(&REDIS);
+    assert_consistent(&NPGSQL);
+    assert_consistent(&FIREBIRD);
+    assert_consistent(&ASE);
+    assert_consistent(&INFORMIX);
+    assert_consistent(&TERADATA);
+    assert_consistent(&SNOWFLAKE);
+    assert_consistent(&CLICKHOUSE);
+    assert_consistent(&CASSANDRA);
+    assert_consistent(&REDSHIFT);
+    assert_consistent(&VERTICA);
+    assert_consistent(&DATABRICKS);
+    assert_consistent(&HIVE);
+    assert_consistent(&BIGQUERY);
+    assert_consistent(&AZURE_STORAGE);
+    assert_consistent(&SERVICE_BUS);
+    assert_consistent(&EVENT_HUBS);
+    assert_consistent(&IOT_HUB);
+    assert_consistent(&COSMOS);
+    assert_consistent(&KAFKA);
+    assert_consistent(&ELASTICSEARCH);
+    assert_consistent(&INFLUX);
+    assert_consistent(&LDAP);
+}
+
+#[test]
+fn renamed_synonyms_are_deprecated() {
+    let renamed = |keywords: &Keywords, key| keywords.deprecation(key).map(|d| d.replacement);
+
+    assert_eq!(
+        Some(Some("trust server certificate")),
+        renamed(&MS_SQL, "trustservercertificate")
+    );
+    assert_eq!(None, renamed(&MS_SQL, "trust server certificate"));
+    assert_eq!(None, renamed(&MS_SQL, "server"));
+
+    assert_eq!(
+        Some(Some("connection lifetime")),
+        renamed(&NPGSQL, "load balance timeout")
+    );
+    assert_eq!(None, renamed(&NPGSQL, "connectionlifetime"));
+}
@@ -0,0 +1,436 @@
+//! LDAP and Active Directory connection strings, as ADSI paths such as
+//! `LDAP://dc01.contoso.com/DC=contoso,DC=com` or as `key=value` bind settings.
+
+use crate::host::Host;
+use crate::keywords;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{decode_bytes, get, parse, parse_bool, parse_pairs, parse_port, to_connection_string};
+use crate::{Error, ParseOptions};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// An attribute of a distinguished name, such as `DC=contoso`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DnComponent {
+    /// The type of the attribute as written, such as `CN`, `OU` or `DC`.
+    pub attribute: String,
+
+    /// The value of the attribute, unescaped.
+    pub value: String,
+}
+
+/// Splits a distinguished name into its attributes, from the most specific to the
+/// root, following RFC 4514.
+///
+/// The attributes of a multi-valued RDN, joined by `+`, are listed one after the
+/// other. The escapes `\,` and `\2C` are decoded, and the unescaped spaces around the
+/// values are trimmed.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::ldap::split_dn;
+///
+/// let dn = split_dn(r"CN=Smith\, John,OU=Sales,DC=contoso,DC=com").unwrap();
+///
+/// assert_eq!("CN", dn[0].attribute);
+/// assert_eq!("Smith, John", dn[0].value);
+/// assert_eq!(vec!["Sales", "contoso", "com"], dn[1..].iter().map(|c| c.value.as_str()).collect::<Vec<_>>());
+/// ```
+pub fn split_dn(dn: &str) -> Result<Vec<DnComponent>, Error> {
+    let bytes = dn.as_bytes();
+    let mut components = Vec::new();
+    let mut i = 0;
+
+    if dn.trim().is_empty() {
+        return Ok(components);
+    }
+
+    loop {
+        let start = i;
+
+        while i < bytes.len() && bytes[i] != b'=' {
+            if matches!(bytes[i], b',' | b';' | b'+' | b'\\') {
+                return Err(Error::SyntaxError(i));
+            }
+
+            i += 1;
+        }
+
+        let attribute = dn[start..i].trim();
+
+        if i == bytes.len() || attribute.is_empty() {
+            return Err(Error::SyntaxError(i));
+        }
+
+        i += 1;
+
+        while bytes.get(i) == Some(&b' ') {
+            i += 1;
+        }
+
+        let value_start = i;
+        let mut value = Vec::new();
+        let mut kept = 0;
+
+        while i < bytes.len() && !matches!(bytes[i], b',' | b';' | b'+') {
+            match bytes[i] {
+                b'\\' => {
+                    let hex = dn
+                        .get(i + 1..i + 3)
+                        .filter(|h| h.bytes().all(|b| b.is_ascii_hexdigit()));
+
+                    match (hex, bytes.get(i + 1)) {
+                        (Some(hex), _) => {
+                            value.push(u8::from_str_radix(hex, 16).unwrap());
+                            i += 3;
+                        }
+                        (None, Some(&b)) => {
+                            value.push(b);
+                            i += 2;
+                        }
+                        (None, None) => return Err(Error::SyntaxError(i)),
+                    }
+
+                    kept = value.len();
+                }
+                b => {
+                    value.push(b);
+                    i += 1;
+
+                    if b != b' ' {
+                        kept = value.len();
+                    }
+                }
+            }
+        }
+
+        value.truncate(kept);
+
+        components.push(DnComponent {
+            attribute: attribute.to_owned(),
+            value: String::from_utf8(value).map_err(|_| Error::InvalidEncoding(value_start))?,
+        });
+
+        if i == bytes.len() {
+            return Ok(components);
+        }
+
+        i += 1;
+    }
+}
+
+/// An LDAP or Active Directory connection string.
+///
+/// An ADSI path, `LDAP://[server[:port]/]base dn` or `LDAPS://...`, is stored under
+/// the same keys as the `key=value` form, such as
+/// `Server=dc01.contoso.com;BaseDN=DC=contoso,DC=com;BindDN=...;Password=...`. A path
+/// without server, such as `LDAP://DC=contoso,DC=com`, binds to a domain controller of
+/// the domain of the computer.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::ldap::LdapConnStr;
+/// use std::str::FromStr;
+///
+/// let conn = LdapConnStr::from_str("LDAP://dc01.contoso.com:389/OU=Users,DC=contoso,DC=com").unwrap();
+///
+/// assert!(conn.is_adsi_path());
+/// assert_eq!(Some("dc01.contoso.com"), conn.server());
+/// assert_eq!(389, conn.port().unwrap());
+/// assert_eq!(Some("OU=Users,DC=contoso,DC=com"), conn.base_dn());
+/// assert_eq!(Some("contoso.com".to_owned()), conn.domain().unwrap());
+///
+/// let conn = LdapConnStr::from_str("Server=ldap.example.com;UseSSL=true;BaseDN=dc=example,dc=com;BindDN=cn=admin,dc=example,dc=com;Password=secret").unwrap();
+///
+/// assert_eq!(636, conn.port().unwrap());
+/// assert_eq!(Some("cn=admin,dc=example,dc=com"), conn.bind_dn());
+/// assert_eq!(
+///     "basedn=\"dc=example,dc=com\";binddn=\"cn=admin,dc=example,dc=com\";password=***;server=ldap.example.com;usessl=true",
+///     conn.to_string(),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct LdapConnStr {
+    adsi: bool,
+    map: Arc<HashMap<String, String>>,
+}
+
+impl FromStr for LdapConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl LdapConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    /// Parses an `LDAP://` or `LDAPS://` ADSI path, or a `key=value` connection
+    /// string.
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        let (adsi, map) = match adsi_prefix(conn_str) {
+            Some(prefix) => (
+                true,
+                parse_pairs(
+                    conn_str,
+                    adsi_pairs(conn_str, prefix)?,
+                    &keywords::LDAP,
+                    options,
+                )?,
+            ),
+            None => (false, parse(conn_str, false, &keywords::LDAP, options)?),
+        };
+
+        let conn = LdapConnStr {
+            adsi,
+            map: Arc::new(map),
+        };
+
+        // the port is checked once parsed, so that it is never dropped from the path
+        if let Some(port) = conn.get("port") {
+            parse_port("port", port)?;
+        }
+
+        Ok(conn)
+    }
+
+    /// Gets the distinguished name of the entry the searches start from.
+    pub fn base_dn(&self) -> Option<&str> {
+        self.get("basedn")
+    }
+
+    /// Splits the base distinguished name into its attributes, empty when missing.
+    pub fn base_dn_components(&self) -> Result<Vec<DnComponent>, Error> {
+        split_dn(self.base_dn().unwrap_or_default())
+    }
+
+    /// Gets the user bound, a distinguished name, a user principal name such as
+    /// `john@contoso.com` or an account name such as `CONTOSO\john`.
+    pub fn bind_dn(&self) -> Option<&str> {
+        self.get("binddn")
+    }
+
+    /// Gets the DNS name of the domain, the `DC` attributes of the base distinguished
+    /// name joined by dots, `None` when it has none.
+    pub fn domain(&self) -> Result<Option<String>, Error> {
+        let labels: Vec<String> = self
+            .base_dn_components()?
+            .into_iter()
+            .filter(|c| c.attribute.eq_ignore_ascii_case("dc"))
+            .map(|c| c.value)
+            .collect();
+
+        match labels.is_empty() {
+            true => Ok(None),
+            false => Ok(Some(labels.join("."))),
+        }
+    }
+
+    /// Indicates if the connection string is an ADSI path.
+    pub fn is_adsi_path(&self) -> bool {
+        self.adsi
+    }
+
+    #[cfg(not(feature = "secret"))]
+    pub fn password(&self) -> Option<&str> {
+        self.get("password")
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("password").map(crate::secret::Secret::new)
+    }
+
+    /// Gets the port, 389 when missing, or 636 with SSL.
+    pub fn port(&self) -> Result<u16, Error> {
+        match self.get("port") {
+            Some(port) => parse_port("port", port),
+            None if self.use_ssl()? => Ok(636),
+            None => Ok(389),
+        }
+    }
+
+    /// Gets the host name of the server, `None` to bind to a domain controller of the
+    /// domain of the computer.
+    pub fn server(&self) -> Option<&str> {
+        self.get("server").filter(|s| !s.trim().is_empty())
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        self.to_string_with(false)
+    }
+
+    /// Indicates if the connection uses LDAP over SSL, as with an `LDAPS://` path.
+    pub fn use_ssl(&self) -> Result<bool, Error> {
+        match self.get("usessl") {
+            Some(v) => parse_bool(v.trim()),
+            None => Ok(false),
+        }
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        get(&self.map, &keywords::LDAP, name)
+    }
+
+    fn to_string_with(&self, redact: bool) -> String {
+        if !self.adsi {
+            return to_connection_string(&self.map, &keywords::LDAP, false, redact);
+        }
+
+        let mut out = match self.use_ssl() {
+            Ok(true) => "LDAPS://".to_owned(),
+            _ => "LDAP://".to_owned(),
+        };
+
+        if let Some(server) = self.server() {
+            let port = self.get("port").and_then(|p| parse_port("port", p).ok());
+
+            out.push_str(&Host::new(server.trim(), port).to_string());
+        }
+
+        if let Some(base_dn) = self.base_dn() {
+            if self.server().is_some() {
+                out.push('/');
+            }
+
+            out.push_str(base_dn);
+        }
+
+        out
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.map, &keywords::LDAP, validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted, an ADSI path being
+/// formatted as a path.
+impl fmt::Display for LdapConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_string_with(true))
+    }
+}
+
+/// Gets the length of the `LDAP://` or `LDAPS://` prefix of an ADSI path, `None` for
+/// the other connection strings.
+fn adsi_prefix(conn_str: &str) -> Option<usize> {
+    let lower = conn_str.trim_start().to_lowercase();
+
+    ["ldap://", "ldaps://"]
+        .iter()
+        .find(|p| lower.starts_with(*p))
+        .map(|p| p.len())
+}
+
+/// Splits an `LDAP://[server[:port]/]base dn` path into the keys of the
+/// [`LDAP`](keywords::LDAP) table, a first segment with a `=` being the base
+/// distinguished name of a path without server.
+fn adsi_pairs(conn_str: &str, prefix: usize) -> Result<Vec<(String, String, usize)>, Error> {
+    let start = conn_str.len() - conn_str.trim_start().len();
+    let trimmed = conn_str.trim();
+    let rest = &trimmed[prefix..];
+    let mut pairs = Vec::new();
+
+    if prefix == "ldaps://".len() {
+        pairs.push(("usessl".to_owned(), "true".to_owned(), start));
+    }
+
+    let (server, base_dn) = match rest.split_once('/') {
+        Some((server, base_dn)) if !server.contains('=') => (server, base_dn),
+        _ if rest.contains('=') => ("", rest),
+        _ => (rest, ""),
+    };
+
+    if !server.is_empty() {
+        let host = server.parse::<Host>()?;
+
+        pairs.push(("server".to_owned(), host.name, start + prefix));
+
+        if let Some(port) = host.port {
+            pairs.push(("port".to_owned(), port.to_string(), start + prefix));
+        }
+    }
+
+    if !base_dn.is_empty() {
+        pairs.push((
+            "basedn".to_owned(),
+            base_dn.to_owned(),
+            start + trimmed.len() - base_dn.len(),
+        ));
+    }
+
+    Ok(pairs)
+}
+
+#[test]
+fn dn_escapes_are_decoded() {
+    let dn = split_dn(r" CN = Doe\2C Jane\ +UID=jd ; O=\#1\\ ,DC=caf\C3\A9").unwrap();
+
+    assert_eq!(
+        vec![
+            ("CN", "Doe, Jane "),
+            ("UID", "jd"),
+            ("O", "#1\\"),
+            ("DC", "café"),
+        ],
+        dn.iter()
+            .map(|c| (c.attribute.as_str(), c.value.as_str()))
+            .collect::<Vec<_>>()
+    );
+
+    assert!(split_dn("").unwrap().is_empty());
+    assert!(split_dn("DC=a,").is_err());
+    assert!(split_dn("DC").is_err());
+    assert!(split_dn(r"DC=a\").is_err());
+    assert!(split_dn(r"DC=\FF").is_err());
+
+    let conn = LdapConnStr::from_str("ldaps://DC=contoso,DC=com").unwrap();
+    assert_eq!(None, conn.server());
+    assert_eq!(636, conn.port().unwrap());
+    assert_eq!("LDAPS://DC=contoso,DC=com", conn.to_string());
+
+    let conn = LdapConnStr::from_str("LDAP://[::1]:3268").unwrap();
+    assert_eq!(3268, conn.port().unwrap());
+    assert_eq!(None, conn.domain().unwrap());
+    assert_eq!("LDAP://[::1]:3268", conn.to_string());
+
+    assert!(LdapConnStr::from_str("LDAP://dc1:90000").is_err());
+    assert!(LdapConnStr::from_str("Server=dc1;Port=0").is_err());
+}
+
+#[test]
+fn hardened_parsing_checks_the_bind_dn() {
+    let options = ParseOptions {
+        hardened: true,
+        ..Default::default()
+    };
+
+    let conn = "server=dc01;user=\"cn=x;password=y\"";
+    match LdapConnStr::parse_with(conn, &options) {
+        Err(Error::SuspiciousValue { key, index }) => {
+            assert_eq!("user", key);
+            assert_eq!(";", &conn[index..index + 1]);
+        }
+        _ => panic!("the nested key should be rejected"),
+    }
+
+    assert!(LdapConnStr::parse_with("server=dc01;basedn=\"o=a;dc=b\"", &options).is_ok());
+}
@@ -5,8 +5,47 @@
 //!
 //! # Supported formats
 //!
+//! - Access (the OLE DB connection strings of the Jet and ACE providers)
+//! - AMQP URIs (the `amqp://` and `amqps://` URIs of RabbitMQ)
+//! - ASE (from the SAP ASE ADO.NET provider, AseClient)
+//! - Azure Storage (from the Azure Storage client libraries)
+//! - BigQuery (from the Simba ODBC driver for Google BigQuery)
+//! - Cassandra (from the DataStax C# driver)
+//! - ClickHouse (the ADO.NET connection strings and the `clickhouse://` urls)
+//! - CockroachDB (the PostgreSQL formats with the options of CockroachDB)
+//! - Cosmos DB (the connection strings of the APIs for NoSQL, MongoDB and Table)
+//! - Databricks (from the Simba Spark ODBC driver and the `jdbc:databricks://` urls)
+//! - Elasticsearch (the connection settings of the Elastic .NET clients)
 //! - Entity Framework (from the .net framework)
+//! - Event Hubs (from the Azure Event Hubs client libraries)
+//! - Excel (the OLE DB connection strings of the Jet and ACE providers and the ODBC
+//!   connection strings of the Excel driver)
+//! - Firebird (from FirebirdSql.Data.FirebirdClient)
+//! - Hive and Impala (from the Cloudera ODBC drivers)
+//! - InfluxDB (the settings of the 2.x and 1.x APIs)
+//! - Informix (from the IBM Informix .NET provider)
+//! - IoT Hub (from the Azure IoT device and service SDKs)
+//! - JDBC urls (of the SQL Server, PostgreSQL, MySQL and Oracle thin drivers)
+//! - Kafka (the client properties of librdkafka and of the Java client)
+//! - LDAP and Active Directory (the ADSI paths and the `key=value` bind settings)
+//! - MongoDB (the `mongodb://` and `mongodb+srv://` URIs)
 //! - MS SQL (from the .net framework System.Data.SqlClient)
+//! - MySQL (from MySQL Connector/NET)
+//! - Neo4j (the `neo4j://` and `bolt://` URIs of the official drivers)
+//! - Npgsql (the ADO.NET provider of PostgreSQL)
+//! - ODBC (as passed to `SQLDriverConnect`, with the data sources of the .dsn files
+//!   and of the unixODBC odbc.ini)
+//! - OLE DB (from the .net framework System.Data.OleDb)
+//! - Oracle (from the Oracle Data Provider for .NET)
+//! - PostgreSQL (the keyword/value form of libpq and the `postgresql://` URIs)
+//! - Redis (from StackExchange.Redis)
+//! - Redshift (from the Amazon Redshift ODBC driver and .NET providers)
+//! - Service Bus (from the Azure Service Bus client libraries)
+//! - Snowflake (from the Snowflake .NET driver)
+//! - SQLite (from Microsoft.Data.Sqlite)
+//! - Teradata (from the Teradata .NET Data Provider)
+//! - Trino and Presto JDBC urls
+//! - Vertica (from the Vertica ADO.NET provider)
 //!
 //! # Example
 //!
@@ -33,13 +72,214 @@
 //!     assert_eq!(&new_conn, r#"data source=.\SQL2017;initial catalog=Db1;user id=john;password="Pass1=3""#);
 //! }
 //! ```
+use keywords::{Keywords, Lookup, ValueKind};
+use std::borrow::Cow;
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::error;
 use std::fmt;
+use std::ops::Range;
 use std::str::{CharIndices, FromStr};
+use std::sync::Arc;
+use validate::{ValidationIssue, Validator};
+
+pub mod access;
+pub mod amqp;
+pub mod any;
+pub mod arena;
+pub mod ase;
+pub mod audit;
+pub mod azure_storage;
+pub mod batch;
+pub mod bigquery;
+mod builder;
+pub mod cassandra;
+pub mod clickhouse;
+pub mod cockroach;
+pub mod convert;
+pub mod cosmos;
+pub mod databricks;
+pub mod elasticsearch;
+pub mod encode;
+pub mod event_hubs;
+pub mod excel;
+pub mod firebird;
+pub mod hive;
+pub mod host;
+pub mod influx;
+pub mod informix;
+pub mod iot_hub;
+pub mod jdbc;
+pub mod kafka;
+pub mod keywords;
+pub mod ldap;
+pub mod metadata;
+pub mod mongo;
+pub mod mysql;
+pub mod neo4j;
+pub mod npgsql;
+pub mod odbc;
+pub mod odbc_ini;
+pub mod oledb;
+pub mod oracle;
+pub mod ordered;
+pub mod percent;
+pub mod pg;
+pub mod redis;
+pub mod redshift;
+pub mod reference;
+#[cfg(all(windows, feature = "registry"))]
+pub mod registry;
+pub mod schema;
+#[cfg(feature = "secret")]
+pub mod secret;
+pub mod server;
+pub mod service_bus;
+pub mod set;
+pub mod snowflake;
+pub mod sqlite;
+pub mod teradata;
+pub mod tns;
+pub mod trino;
+mod url;
+pub mod validate;
+pub mod vertica;
+#[cfg(feature = "xml")]
+pub mod web_config;
+
+pub use any::{AnyConnStr, Provider};
+pub use builder::MsSqlConnStrBuilder;
+pub use encode::ToConnStr;
+pub use odbc::OdbcConnStr;
+
+/// Options controlling the parsing of a connection string.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::{MsSqlConnStr, ParseOptions};
+///
+/// let options = ParseOptions { strict: true, ..Default::default() };
+///
+/// assert!(MsSqlConnStr::parse_with("server=.;database=Db1", &options).is_ok());
+/// assert!(MsSqlConnStr::parse_with("server=.;databse=Db1", &options).is_err());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ParseOptions {
+    /// Fails on any key unknown to the format being parsed.
+    pub strict: bool,
+
+    /// Fails when the connection string or one of its keys is too long.
+    pub limits: Option<Limits>,
+
+    /// Rejects the values containing control characters and the credentials, quoted
+    /// or escaped by the grammar of the format, containing a nested `;key=` pattern,
+    /// which may be injected when a connection string is assembled from user input.
+    pub hardened: bool,
+
+    /// Fails when a key mandatory to the format is missing, such as the `metadata` of
+    /// an Entity Framework connection string.
+    pub required: bool,
+
+    /// Strips the comment lines and the trailing comments, starting with `#` or `//`,
+    /// from the documents and files holding connection strings.
+    pub comments: bool,
+
+    /// Decrypts the values of the credential keys, such as a password protected with
+    /// DPAPI, once the syntax of the connection string is checked.
+    pub decryptor: Option<Decryptor>,
+}
+
+/// A user supplied decryption of the protected values of a connection string.
+///
+/// The function receives the canonical name of a credential key and its value, and
+/// returns the decrypted value, `None` keeping the value as is. The decryption
+/// happens after the hardened checks, which apply to the protected value, and before
+/// the strict checks, which apply to the decrypted one.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::{Decryptor, MsSqlConnStr, ParseOptions};
+///
+/// let options = ParseOptions {
+///     decryptor: Some(Decryptor::new(|_key, value| {
+///         Ok(value.strip_prefix("rot:").map(|v| v.chars().rev().collect()))
+///     })),
+///     ..Default::default()
+/// };
+///
+/// let conn = MsSqlConnStr::parse_with("server=.;password=rot:321ssaP", &options).unwrap();
+///
+/// assert_eq!("password=Pass123;server=.", conn.to_connection_string_with_secrets());
+/// ```
+#[derive(Clone)]
+pub struct Decryptor(Arc<DecryptFn>);
+
+type DecryptFn = dyn Fn(&str, &str) -> Result<Option<String>, String> + Send + Sync;
+
+impl Decryptor {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&str, &str) -> Result<Option<String>, String> + Send + Sync + 'static,
+    {
+        Decryptor(Arc::new(f))
+    }
+
+    fn decrypt(&self, key: &str, value: String) -> Result<String, Error> {
+        match (self.0)(key, &value) {
+            Ok(Some(value)) => Ok(value),
+            Ok(None) => Ok(value),
+            Err(message) => Err(Error::DecryptionFailed {
+                key: key.to_owned(),
+                message,
+            }),
+        }
+    }
+}
+
+impl fmt::Debug for Decryptor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Decryptor")
+    }
+}
+
+/// Length limits of a connection string, in characters.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::{Error, Limits, MsSqlConnStr, ParseOptions};
+///
+/// let options = ParseOptions { limits: Some(Limits::SQL_CLIENT), ..Default::default() };
+/// let conn = format!("server=.;{}=1", "k".repeat(129));
+///
+/// match MsSqlConnStr::parse_with(&conn, &options) {
+///     Err(Error::LimitExceeded { key: Some(_), limit: 128 }) => {}
+///     _ => panic!("the key should exceed the limit"),
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Limits {
+    /// The maximum length of the whole connection string.
+    pub max_len: usize,
+
+    /// The maximum length of a key.
+    pub max_key_len: usize,
+}
+
+impl Limits {
+    /// The limits enforced by SqlClient.
+    pub const SQL_CLIENT: Limits = Limits {
+        max_len: 65_535,
+        max_key_len: 128,
+    };
+}
 
 /// Represent an Entity Framework Connection String
 ///
+/// Like [`MsSqlConnStr`], cloning only increments a reference count.
+///
 /// # Example
 ///
 /// ```
@@ -53,36 +293,264 @@ use std::str::{CharIndices, FromStr};
 /// assert_eq!("System.Data.SqlClient", b.provider().unwrap());
 /// assert_eq!("server=.\\Sql2017;database=Db1", b.provider_connection_string().unwrap());
 /// ```
-pub struct EFConnStr(HashMap<String, String>);
+#[derive(Clone)]
+pub struct EFConnStr(Arc<HashMap<String, String>>);
 
 impl FromStr for EFConnStr {
     type Err = Error;
 
     fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
-        Ok(EFConnStr(parse(conn_str, false, None)?))
+        Self::parse_with(conn_str, &ParseOptions::default())
     }
 }
 
 impl EFConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    /// Parses the connection string, failing on any key unknown to Entity Framework.
+    pub fn parse_strict(conn_str: &str) -> Result<Self, Error> {
+        Self::parse_with(
+            conn_str,
+            &ParseOptions {
+                strict: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Parses the connection string with the given options.
+    ///
+    /// A strict parsing only accepts the `metadata`, `name`, `provider` and
+    /// `provider connection string` keys, like the `EntityConnectionStringBuilder`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::{EFConnStr, Error, ParseOptions};
+    ///
+    /// let options = ParseOptions { strict: true, required: true, ..Default::default() };
+    ///
+    /// assert!(EFConnStr::parse_with("name=Db1", &options).is_ok());
+    ///
+    /// match EFConnStr::parse_with("provider=System.Data.SqlClient;metadata=res://*/", &options) {
+    ///     Err(Error::MissingKey(key)) => assert_eq!("provider connection string", key),
+    ///     _ => panic!("the provider connection string should be missing"),
+    /// }
+    /// ```
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        let map = parse(conn_str, false, &keywords::EF, options)?;
+
+        if options.required {
+            Self::check_required(&map)?;
+        }
+
+        Ok(EFConnStr(Arc::new(map)))
+    }
+
+    /// Parses the connection string, also returning the recoverable anomalies such as
+    /// the duplicate keys, which are otherwise ignored.
+    pub fn parse_with_warnings(
+        conn_str: &str,
+        options: &ParseOptions,
+    ) -> Result<(Self, Vec<Warning>), Error> {
+        let mut warnings = Vec::new();
+        let map = parse_warn(conn_str, false, &keywords::EF, options, &mut warnings)?;
+
+        if options.required {
+            Self::check_required(&map)?;
+        }
+
+        Ok((EFConnStr(Arc::new(map)), warnings))
+    }
+
+    /// Checks the keys needed to open a connection, unless it is a named connection.
+    fn check_required(map: &HashMap<String, String>) -> Result<(), Error> {
+        if get(map, &keywords::EF, "name").is_some() {
+            return Ok(());
+        }
+
+        match Self::missing(map).next() {
+            Some(key) => Err(Error::MissingKey(key.to_owned())),
+            None => Ok(()),
+        }
+    }
+
+    /// Gets the keys needed to open a connection but missing.
+    pub(crate) fn missing_keys(&self) -> Vec<&'static str> {
+        Self::missing(&self.0).collect()
+    }
+
+    fn missing(map: &HashMap<String, String>) -> impl Iterator<Item = &'static str> + '_ {
+        ["metadata", "provider", "provider connection string"]
+            .iter()
+            .cloned()
+            .filter(move |name| get(map, &keywords::EF, name).is_none())
+    }
+
     pub fn metadata(&self) -> Option<&str> {
-        self.0.get("metadata").map(|s| s.as_str())
+        get(&self.0, &keywords::EF, "metadata")
+    }
+
+    /// Gets the artifacts of the metadata, `None` when the keyword is missing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::metadata::Artifact;
+    /// use conn_str::EFConnStr;
+    /// use std::str::FromStr;
+    ///
+    /// let conn = EFConnStr::from_str("metadata=res://*/Model.csdl|~/Model.ssdl").unwrap();
+    /// let metadata = conn.metadata_artifacts().unwrap().unwrap();
+    ///
+    /// assert_eq!(Artifact::Path("~/Model.ssdl".to_owned()), metadata.artifacts[1]);
+    /// ```
+    pub fn metadata_artifacts(&self) -> Result<Option<metadata::EfMetadata>, Error> {
+        self.metadata().map(str::parse).transpose()
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.0, &keywords::EF, false, false)
     }
 
     pub fn name(&self) -> Option<&str> {
-        self.0.get("name").map(|s| s.as_str())
+        get(&self.0, &keywords::EF, "name")
     }
 
     pub fn provider(&self) -> Option<&str> {
-        self.0.get("provider").map(|s| s.as_str())
+        get(&self.0, &keywords::EF, "provider")
     }
 
     pub fn provider_connection_string(&self) -> Option<&str> {
-        self.0.get("provider connection string").map(|s| s.as_str())
+        get(&self.0, &keywords::EF, "provider connection string")
+    }
+
+    /// Sets the nested provider connection string, with its secrets.
+    ///
+    /// The nested string is quoted when the connection string is formatted, so that it
+    /// is parsed back unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::{EFConnStr, MsSqlConnStr};
+    /// use std::str::FromStr;
+    ///
+    /// let sql = MsSqlConnStr::from_str(r#"server=.;password='Pa"ss;1'"#).unwrap();
+    /// let conn = EFConnStr::from_str("metadata=res://*/").unwrap().with_provider_connection_string(&sql);
+    ///
+    /// let parsed = EFConnStr::from_str(&conn.to_connection_string_with_secrets()).unwrap();
+    /// let nested = MsSqlConnStr::from_str(parsed.provider_connection_string().unwrap()).unwrap();
+    ///
+    /// assert_eq!(
+    ///     sql.to_connection_string_with_secrets(),
+    ///     nested.to_connection_string_with_secrets(),
+    /// );
+    /// ```
+    pub fn set_provider_connection_string(&mut self, conn: &MsSqlConnStr) {
+        Arc::make_mut(&mut self.0).insert(
+            "provider connection string".to_owned(),
+            conn.to_connection_string_with_secrets(),
+        );
+    }
+
+    /// Sets a key of the nested provider connection string, looking up its synonyms.
+    ///
+    /// Only the value of the key changes in the nested string, the other keys keep
+    /// their text and quoting.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::EFConnStr;
+    /// use std::str::FromStr;
+    ///
+    /// let mut conn = EFConnStr::from_str(
+    ///     r#"provider connection string="Server=.; Application Name='My ""App""'; Pwd=old""#,
+    /// )
+    /// .unwrap();
+    ///
+    /// conn.set_provider_connection_string_value("password", "new;1").unwrap();
+    ///
+    /// assert_eq!(
+    ///     Some(r#"Server=.; Application Name='My "App"'; Pwd="new;1""#),
+    ///     conn.provider_connection_string(),
+    /// );
+    /// ```
+    pub fn set_provider_connection_string_value(
+        &mut self,
+        key: &str,
+        value: &str,
+    ) -> Result<(), Error> {
+        let key = key.trim().to_lowercase();
+        let names: Vec<_> = match keywords::MS_SQL.find(&key) {
+            Some(keyword) => keyword.names().collect(),
+            None => vec![key.as_str()],
+        };
+
+        let nested = self.provider_connection_string().unwrap_or_default();
+        let nested = replace_value(nested, &names, value, false)?;
+
+        Arc::make_mut(&mut self.0).insert("provider connection string".to_owned(), nested);
+        Ok(())
+    }
+
+    /// Returns a copy of the connection string with another provider connection string.
+    pub fn with_provider_connection_string(&self, conn: &MsSqlConnStr) -> Self {
+        let mut copy = self.clone();
+        copy.set_provider_connection_string(conn);
+        copy
+    }
+
+    /// Gets the keys unknown to Entity Framework, in lower case and sorted.
+    pub fn unknown_keys(&self) -> Vec<&str> {
+        unknown_keys(&self.0, &keywords::EF)
+    }
+
+    /// Audits the nested provider connection string for insecure configurations.
+    ///
+    /// Nothing is reported when the provider connection string is missing or invalid.
+    pub fn security_audit(&self) -> Vec<ValidationIssue> {
+        self.provider_connection_string()
+            .and_then(|s| MsSqlConnStr::from_str(s).ok())
+            .map(|c| c.security_audit())
+            .unwrap_or_default()
+    }
+
+    /// Validates the connection string, returning the issues found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::validate::NAME_WITH_OTHER_KEYS;
+    /// use conn_str::EFConnStr;
+    /// use std::str::FromStr;
+    ///
+    /// let conn = EFConnStr::from_str("name=Db1;provider=System.Data.SqlClient").unwrap();
+    /// let issues = conn.validate();
+    ///
+    /// assert_eq!(NAME_WITH_OTHER_KEYS, issues[0].rule);
+    /// ```
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::ef(self, validator)
     }
 }
 
 /// Represent a Sql Connection String
 ///
+/// The parsed keys and values are shared, so cloning is only a reference count increment
+/// and the connection string can be cached and sent across threads.
+///
 /// # Example
 ///
 /// ```
@@ -98,50 +566,203 @@ impl EFConnStr {
 /// // gets the initial catalog
 /// assert_eq!("Db1", conn.initial_catalog().unwrap());
 /// ```
-pub struct MsSqlConnStr(HashMap<String, String>);
+#[derive(Clone)]
+pub struct MsSqlConnStr(Arc<HashMap<String, String>>);
 
 impl FromStr for MsSqlConnStr {
     type Err = Error;
 
     fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
-        Ok(MsSqlConnStr(parse(conn_str, false, None)?))
+        Self::parse_with(conn_str, &ParseOptions::default())
     }
 }
 
 impl MsSqlConnStr {
+    /// Parses the connection string, failing on any key unknown to SqlClient or any
+    /// value outside the domain of its key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::MsSqlConnStr;
+    ///
+    /// assert!(MsSqlConnStr::parse_strict("server=.;database=Db1").is_ok());
+    /// assert!(MsSqlConnStr::parse_strict("server=.;databse=Db1").is_err());
+    ///
+    /// let err = MsSqlConnStr::parse_strict("server=.;packet size=100").err().unwrap();
+    /// assert_eq!(
+    ///     "`100` is not a valid value for `packet size`, expected an integer between 512 and 32767",
+    ///     err.to_string(),
+    /// );
+    /// ```
+    pub fn parse_strict(conn_str: &str) -> Result<Self, Error> {
+        Self::parse_with(
+            conn_str,
+            &ParseOptions {
+                strict: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        Ok(MsSqlConnStr(Arc::new(parse(
+            conn_str,
+            false,
+            &keywords::MS_SQL,
+            options,
+        )?)))
+    }
+
+    /// Parses the connection string, also returning the recoverable anomalies such as
+    /// the duplicate keys, which are otherwise ignored.
+    pub fn parse_with_warnings(
+        conn_str: &str,
+        options: &ParseOptions,
+    ) -> Result<(Self, Vec<Warning>), Error> {
+        let mut warnings = Vec::new();
+        let map = parse_warn(conn_str, false, &keywords::MS_SQL, options, &mut warnings)?;
+
+        Ok((MsSqlConnStr(Arc::new(map)), warnings))
+    }
+
+    /// Gets the Azure Active Directory access token, used instead of a user id and a
+    /// password.
+    #[cfg(not(feature = "secret"))]
+    pub fn access_token(&self) -> Option<&str> {
+        self.get("access token")
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn access_token(&self) -> Option<secret::Secret<'_>> {
+        self.get("access token").map(secret::Secret::new)
+    }
+
     pub fn application_name(&self) -> Option<&str> {
-        self.0
-            .get("application name")
-            .or_else(|| self.0.get("app"))
-            .map(|s| s.as_str())
+        self.get("application name")
+    }
+
+    /// Gets the authentication method, `None` when the keyword is missing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::{Authentication, MsSqlConnStr};
+    /// use std::str::FromStr;
+    ///
+    /// let conn = MsSqlConnStr::from_str("server=.;authentication=Active Directory MSI").unwrap();
+    ///
+    /// assert_eq!(
+    ///     Some(Authentication::ActiveDirectoryManagedIdentity),
+    ///     conn.authentication().unwrap(),
+    /// );
+    /// ```
+    pub fn authentication(&self) -> Result<Option<Authentication>, Error> {
+        self.get("authentication").map(str::parse).transpose()
     }
 
     pub fn data_source(&self) -> Option<&str> {
-        self.0
-            .get("data source")
-            .or_else(|| self.0.get("addr"))
-            .or_else(|| self.0.get("address"))
-            .or_else(|| self.0.get("network address"))
-            .or_else(|| self.0.get("server"))
-            .map(|s| s.as_str())
+        self.get("data source")
+    }
+
+    /// Decomposes the data source into its protocol, host, instance and port.
+    pub fn server(&self) -> Result<Option<server::DataSource>, Error> {
+        self.get("data source")
+            .map(|s| server::DataSource::parse("data source", s))
+            .transpose()
     }
 
     pub fn encrypt(&self) -> Result<bool, Error> {
         self.encrypt_or(false)
     }
 
+    /// Indicates if the connection is encrypted, `strict` and `mandatory` being `true`.
     pub fn encrypt_or(&self, default: bool) -> Result<bool, Error> {
-        match self.0.get("encrypt") {
-            Some(v) => parse_bool(v),
+        match self.get("encrypt") {
+            Some(_) => Ok(self.encrypt_mode()? != EncryptMode::Optional),
             None => Ok(default),
         }
     }
 
+    /// Gets the encryption mode, `Optional` when the keyword is missing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::{EncryptMode, MsSqlConnStr};
+    /// use std::str::FromStr;
+    ///
+    /// let conn = MsSqlConnStr::from_str("server=.;encrypt=strict").unwrap();
+    ///
+    /// assert_eq!(EncryptMode::Strict, conn.encrypt_mode().unwrap());
+    /// assert!(conn.encrypt().unwrap());
+    /// ```
+    pub fn encrypt_mode(&self) -> Result<EncryptMode, Error> {
+        match self.get("encrypt") {
+            Some(v) => match v.trim().to_lowercase().as_str() {
+                "strict" => Ok(EncryptMode::Strict),
+                "mandatory" => Ok(EncryptMode::Mandatory),
+                "optional" => Ok(EncryptMode::Optional),
+                _ => Ok(if parse_bool(v)? {
+                    EncryptMode::Mandatory
+                } else {
+                    EncryptMode::Optional
+                }),
+            },
+            None => Ok(EncryptMode::Optional),
+        }
+    }
+
+    /// Gets the servers to try when opening a connection, the data source followed by
+    /// the failover partner.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::MsSqlConnStr;
+    /// use std::str::FromStr;
+    ///
+    /// let conn = MsSqlConnStr::from_str("server=tcp:db1,1433;failover partner=db2\\INST").unwrap();
+    /// let candidates = conn.failover_candidates().unwrap();
+    ///
+    /// assert_eq!("db1", candidates[0].data_source.host);
+    /// assert_eq!(Some(1433), candidates[0].data_source.port);
+    /// assert_eq!("db2", candidates[1].data_source.host);
+    /// assert_eq!(Some("INST"), candidates[1].data_source.instance.as_deref());
+    /// ```
+    pub fn failover_candidates(&self) -> Result<Vec<server::FailoverCandidate>, Error> {
+        let multi_subnet = match self.get("multi subnet failover") {
+            Some(v) => parse_bool(v)?,
+            None => false,
+        };
+
+        let mut candidates = Vec::new();
+
+        for key in ["data source", "failover partner"].iter() {
+            if let Some(value) = self.get(key) {
+                let data_source = server::DataSource::parse(key, value)?;
+                let multi_subnet = multi_subnet
+                    && data_source
+                        .protocol
+                        .is_none_or(|p| p == server::ServerProtocol::Tcp);
+
+                candidates.push(server::FailoverCandidate {
+                    data_source,
+                    multi_subnet,
+                });
+            }
+        }
+
+        Ok(candidates)
+    }
+
     pub fn initial_catalog(&self) -> Option<&str> {
-        self.0
-            .get("initial catalog")
-            .or_else(|| self.0.get("database"))
-            .map(|s| s.as_str())
+        self.get("initial catalog")
     }
 
     pub fn integrated_security(&self) -> Result<bool, Error> {
@@ -149,12 +770,7 @@ impl MsSqlConnStr {
     }
 
     pub fn integrated_security_or(&self, default: bool) -> Result<bool, Error> {
-        match self
-            .0
-            .get("integrated security")
-            .or_else(|| self.0.get("integratedsecurity"))
-            .or_else(|| self.0.get("trusted_connection"))
-        {
+        match self.get("integrated security") {
             Some(s) => match s.to_lowercase().as_str() {
                 "true" | "yes" | "sspi" => Ok(true),
                 "false" | "no" => Ok(false),
@@ -169,17 +785,38 @@ impl MsSqlConnStr {
     }
 
     pub fn multiple_active_result_sets_or(&self, default: bool) -> Result<bool, Error> {
-        match self.0.get("multipleactiveresultsets") {
+        match self.get("multiple active result sets") {
             Some(v) => parse_bool(v),
             None => Ok(default),
         }
     }
 
+    #[cfg(not(feature = "secret"))]
     pub fn password(&self) -> Option<&str> {
-        self.0
-            .get("password")
-            .or_else(|| self.0.get("pwd"))
-            .map(|s| s.as_str())
+        self.get("password")
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn password(&self) -> Option<secret::Secret<'_>> {
+        self.get("password").map(secret::Secret::new)
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::MsSqlConnStr;
+    /// use std::str::FromStr;
+    ///
+    /// let conn = MsSqlConnStr::from_str("Server=.;Password=Pass1=3").unwrap();
+    ///
+    /// assert_eq!("password=***;server=.", conn.to_string());
+    /// assert_eq!(r#"password="Pass1=3";server=."#, conn.to_connection_string_with_secrets());
+    /// ```
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.0, &keywords::MS_SQL, false, false)
     }
 
     pub fn trust_server_certificate(&self) -> Result<bool, Error> {
@@ -187,37 +824,385 @@ impl MsSqlConnStr {
     }
 
     pub fn trust_server_certificate_or(&self, default: bool) -> Result<bool, Error> {
-        match self.0.get("trustservercertificate") {
+        match self.get("trust server certificate") {
             Some(v) => parse_bool(v),
             None => Ok(default),
         }
     }
 
+    /// Gets the keys unknown to SqlClient, in lower case and sorted.
+    ///
+    /// Unlike [`MsSqlConnStr::parse_strict`], the parsing does not fail so the unknown
+    /// keys can be logged as warnings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::MsSqlConnStr;
+    /// use std::str::FromStr;
+    ///
+    /// let conn = MsSqlConnStr::from_str("server=.;Future Option=1;database=Db1").unwrap();
+    ///
+    /// assert_eq!(vec!["future option"], conn.unknown_keys());
+    /// ```
+    pub fn unknown_keys(&self) -> Vec<&str> {
+        unknown_keys(&self.0, &keywords::MS_SQL)
+    }
+
     pub fn user_id(&self) -> Option<&str> {
-        self.0
-            .get("user id")
-            .or_else(|| self.0.get("uid"))
-            .or_else(|| self.0.get("user"))
-            .map(|s| s.as_str())
+        self.get("user id")
+    }
+
+    /// Returns a copy of the connection string with another application name.
+    pub fn with_application_name(&self, name: &str) -> Self {
+        self.with_values(&[("application name", Some(name))])
+    }
+
+    /// Returns a copy of the connection string with another user id and password,
+    /// removing the integrated security and the access token.
+    pub fn with_credentials(&self, user_id: &str, password: &str) -> Self {
+        self.with_values(&[
+            ("integrated security", None),
+            ("access token", None),
+            ("user id", Some(user_id)),
+            ("password", Some(password)),
+        ])
+    }
+
+    /// Returns a copy of the connection string targeting another data source.
+    pub fn with_data_source(&self, data_source: &server::DataSource) -> Self {
+        self.with_values(&[("data source", Some(&data_source.to_string()))])
+    }
+
+    /// Returns a copy of the connection string targeting another database.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::MsSqlConnStr;
+    /// use std::str::FromStr;
+    ///
+    /// let conn = MsSqlConnStr::from_str("server=.;database=Db1;uid=me;pwd=Pass1").unwrap();
+    /// let copy = conn.with_database("Db2").without_credentials();
+    ///
+    /// assert_eq!(Some("Db1"), conn.initial_catalog());
+    /// assert_eq!("initial catalog=Db2;server=.", copy.to_connection_string_with_secrets());
+    /// ```
+    pub fn with_database(&self, database: &str) -> Self {
+        self.with_values(&[("initial catalog", Some(database))])
+    }
+
+    /// Returns a copy of the connection string without its user id, password and
+    /// access token.
+    pub fn without_credentials(&self) -> Self {
+        self.with_values(&[
+            ("user id", None),
+            ("password", None),
+            ("access token", None),
+        ])
+    }
+
+    /// Returns a copy with the values of the keywords replaced, a `None` value
+    /// removing the keyword.
+    fn with_values(&self, values: &[(&str, Option<&str>)]) -> Self {
+        let mut map = (*self.0).clone();
+
+        for (name, value) in values {
+            set_keyword(&mut map, &keywords::MS_SQL, name, *value);
+        }
+
+        MsSqlConnStr(Arc::new(map))
+    }
+
+    /// Audits the connection string for insecure configurations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::audit::{ENCRYPT_DISABLED, TRUST_SERVER_CERTIFICATE};
+    /// use conn_str::MsSqlConnStr;
+    /// use std::str::FromStr;
+    ///
+    /// let conn = MsSqlConnStr::from_str("server=.;encrypt=false;trustservercertificate=true").unwrap();
+    /// let rules: Vec<_> = conn.security_audit().into_iter().map(|i| i.rule).collect();
+    ///
+    /// assert_eq!(vec![ENCRYPT_DISABLED, TRUST_SERVER_CERTIFICATE], rules);
+    /// ```
+    pub fn security_audit(&self) -> Vec<ValidationIssue> {
+        audit::ms_sql(self)
+    }
+
+    /// Validates the connection string, returning the issues found.
+    ///
+    /// Unknown keys, invalid values and conflicting settings are reported, letting the
+    /// caller decide whether to warn or to fail.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::validate::Severity;
+    /// use conn_str::MsSqlConnStr;
+    /// use std::str::FromStr;
+    ///
+    /// let conn = MsSqlConnStr::from_str("server=.;encrypt=maybe;colour=blue").unwrap();
+    /// let issues = conn.validate();
+    ///
+    /// assert_eq!(2, issues.len());
+    /// assert!(issues.iter().any(|i| i.rule == "invalid-value" && i.severity == Severity::Error));
+    /// assert!(issues.iter().any(|i| i.rule == "unknown-key" && i.severity == Severity::Warning));
+    /// ```
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::validate::Validator;
+    /// use conn_str::MsSqlConnStr;
+    /// use std::str::FromStr;
+    ///
+    /// let validator = Validator::new().password_policy(|pwd| {
+    ///     if pwd.len() < 12 {
+    ///         Err("the password must have at least 12 characters".to_owned())
+    ///     } else {
+    ///         Ok(())
+    ///     }
+    /// });
+    ///
+    /// let conn = MsSqlConnStr::from_str("server=.;user id=me;password=secret").unwrap();
+    /// let issues = conn.validate_with(&validator);
+    ///
+    /// assert_eq!("password-policy", issues[0].rule);
+    /// assert_eq!("the password must have at least 12 characters", issues[0].message);
+    /// ```
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::ms_sql(self, validator)
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        get(&self.0, &keywords::MS_SQL, name)
+    }
+
+    pub(crate) fn get_int(&self, name: &str) -> Option<i64> {
+        self.get(name).and_then(|v| v.trim().parse().ok())
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for EFConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(&self.0, &keywords::EF, false, true))
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for MsSqlConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(
+            &self.0,
+            &keywords::MS_SQL,
+            false,
+            true,
+        ))
+    }
+}
+
+/// The authentication method of a Sql Connection String
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Authentication {
+    SqlPassword,
+    ActiveDirectoryPassword,
+    ActiveDirectoryIntegrated,
+    ActiveDirectoryInteractive,
+    ActiveDirectoryServicePrincipal,
+    ActiveDirectoryDeviceCodeFlow,
+    /// Also known as `Active Directory MSI`.
+    ActiveDirectoryManagedIdentity,
+    ActiveDirectoryDefault,
+    ActiveDirectoryWorkloadIdentity,
+}
+
+impl Authentication {
+    /// Indicates if the method authenticates with Azure Active Directory.
+    pub fn is_active_directory(&self) -> bool {
+        *self != Authentication::SqlPassword
     }
 }
 
+impl fmt::Display for Authentication {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Authentication::SqlPassword => "Sql Password",
+            Authentication::ActiveDirectoryPassword => "Active Directory Password",
+            Authentication::ActiveDirectoryIntegrated => "Active Directory Integrated",
+            Authentication::ActiveDirectoryInteractive => "Active Directory Interactive",
+            Authentication::ActiveDirectoryServicePrincipal => "Active Directory Service Principal",
+            Authentication::ActiveDirectoryDeviceCodeFlow => "Active Directory Device Code Flow",
+            Authentication::ActiveDirectoryManagedIdentity => "Active Directory Managed Identity",
+            Authentication::ActiveDirectoryDefault => "Active Directory Default",
+            Authentication::ActiveDirectoryWorkloadIdentity => "Active Directory Workload Identity",
+        })
+    }
+}
+
+impl FromStr for Authentication {
+    type Err = Error;
+
+    /// Parses the method, with or without the spaces between the words.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name: String = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_lowercase();
+
+        Ok(match name.as_str() {
+            "sqlpassword" => Authentication::SqlPassword,
+            "activedirectorypassword" => Authentication::ActiveDirectoryPassword,
+            "activedirectoryintegrated" => Authentication::ActiveDirectoryIntegrated,
+            "activedirectoryinteractive" => Authentication::ActiveDirectoryInteractive,
+            "activedirectoryserviceprincipal" => Authentication::ActiveDirectoryServicePrincipal,
+            "activedirectorydevicecodeflow" => Authentication::ActiveDirectoryDeviceCodeFlow,
+            "activedirectorymanagedidentity" | "activedirectorymsi" => {
+                Authentication::ActiveDirectoryManagedIdentity
+            }
+            "activedirectorydefault" => Authentication::ActiveDirectoryDefault,
+            "activedirectoryworkloadidentity" => Authentication::ActiveDirectoryWorkloadIdentity,
+            _ => {
+                return Err(Error::InvalidValue {
+                    key: "authentication".to_owned(),
+                    value: s.to_owned(),
+                    expected: keywords::MS_SQL
+                        .get("authentication")
+                        .map(|k| k.kind.to_string())
+                        .unwrap_or_default(),
+                })
+            }
+        })
+    }
+}
+
+/// The encryption mode of a Sql Connection String
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum EncryptMode {
+    /// `false`, `no` or `optional`.
+    Optional,
+    /// `true`, `yes` or `mandatory`.
+    Mandatory,
+    /// TDS 8.0 encryption, the certificate of the server is always validated.
+    Strict,
+}
+
 /// A Sql Connection String parsing error
 #[derive(Clone, Debug)]
 pub enum Error {
+    AtLine {
+        line: usize,
+        error: Box<Error>,
+    },
+    DecryptionFailed {
+        key: String,
+        message: String,
+    },
+    /// A name of a key is already used by another key, such as a synonym of a
+    /// [`Schema`](schema::Schema).
+    DuplicateKey {
+        key: String,
+        used_by: String,
+    },
+    /// The bytes are not valid text in the encoding of their byte order mark, at
+    /// the given byte index.
+    InvalidEncoding(usize),
+    InvalidValue {
+        key: String,
+        value: String,
+        expected: String,
+    },
     KeyNotSupported(String),
+    LimitExceeded {
+        key: Option<String>,
+        limit: usize,
+    },
+    MissingKey(String),
     NotAValidBool(String),
+    SuspiciousValue {
+        key: String,
+        index: usize,
+    },
+    SecretUnavailable {
+        key: String,
+        message: String,
+    },
     SyntaxError(usize),
+    Unreadable {
+        path: String,
+        message: String,
+    },
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            Error::AtLine { line, error } => write!(f, "line {}: {}", line, error),
+            Error::DecryptionFailed { key, message } => write!(
+                f,
+                "decryption of connection string key `{}` failed: {}",
+                key, message
+            ),
+            Error::DuplicateKey { key, used_by } => {
+                write!(f, "key `{}` is already used by `{}`", key, used_by)
+            }
+            Error::InvalidEncoding(index) => {
+                write!(
+                    f,
+                    "decoding of connection string failed at byte `{}`",
+                    index
+                )
+            }
+            Error::InvalidValue {
+                key,
+                value,
+                expected,
+            } => write!(
+                f,
+                "`{}` is not a valid value for `{}`, expected {}",
+                value, key, expected
+            ),
             Error::KeyNotSupported(s) => write!(f, "connection string key `{}` not supported", s),
+            Error::LimitExceeded {
+                key: Some(key),
+                limit,
+            } => write!(
+                f,
+                "connection string key `{}` exceeds the limit of {} characters",
+                key, limit
+            ),
+            Error::LimitExceeded { key: None, limit } => write!(
+                f,
+                "connection string exceeds the limit of {} characters",
+                limit
+            ),
+            Error::MissingKey(key) => write!(f, "connection string key `{}` is missing", key),
             Error::NotAValidBool(s) => write!(f, "`{}` is not a valid boolean value", s),
+            Error::SuspiciousValue { key, index } => write!(
+                f,
+                "suspicious value of connection string key `{}` at `{}`",
+                key, index
+            ),
+            Error::SecretUnavailable { key, message } => write!(
+                f,
+                "secret of connection string key `{}` could not be resolved: {}",
+                key, message
+            ),
             Error::SyntaxError(index) => {
                 write!(f, "parsing of connection string failed at `{}`", index)
             }
+            Error::Unreadable { path, message } => {
+                write!(f, "file `{}` could not be read: {}", path, message)
+            }
         }
     }
 }
@@ -225,15 +1210,28 @@ impl fmt::Display for Error {
 impl error::Error for Error {
     fn description(&self) -> &str {
         match self {
+            Error::AtLine { .. } => "parsing of a line failed",
+            Error::DecryptionFailed { .. } => "decryption of connection string key failed",
+            Error::DuplicateKey { .. } => "key is already used by another key",
+            Error::InvalidEncoding(_) => "decoding of connection string failed",
+            Error::InvalidValue { .. } => "not a valid value for the connection string key",
             Error::KeyNotSupported(_) => "connection string key not supported",
+            Error::LimitExceeded { .. } => "connection string exceeds a length limit",
+            Error::MissingKey(_) => "connection string key is missing",
             Error::NotAValidBool(_) => "not a valid boolean value",
+            Error::SuspiciousValue { .. } => "suspicious value of connection string key",
+            Error::SecretUnavailable { .. } => "secret could not be resolved",
             Error::SyntaxError(_) => "parsing of connection string failed",
+            Error::Unreadable { .. } => "file could not be read",
         }
     }
 
-    fn cause(&self) -> Option<&error::Error> {
-        // Generic error, underlying cause isn't tracked.
-        None
+    fn cause(&self) -> Option<&dyn error::Error> {
+        match self {
+            Error::AtLine { error, .. } => Some(error.as_ref()),
+            // Generic error, underlying cause isn't tracked.
+            _ => None,
+        }
     }
 }
 
@@ -266,6 +1264,10 @@ pub fn append_key_value(out: &mut String, key: &str, value: &str, use_odbc_rules
 
     out.push('=');
 
+    push_value(out, value, use_odbc_rules);
+}
+
+fn push_value(out: &mut String, value: &str, use_odbc_rules: bool) {
     if use_odbc_rules {
         // should quote the value
         if !value.is_empty()
@@ -278,9 +1280,98 @@ pub fn append_key_value(out: &mut String, key: &str, value: &str, use_odbc_rules
         } else {
             out.push_str(value);
         }
+    } else {
+        push_quoted(out, value);
+    }
+}
+
+/// Sets the value of a key in a connection string, keeping the text of the other keys
+/// unchanged.
+///
+/// The value of the first occurrence of any of the `names` is replaced, and the first
+/// name is appended when none is found.
+fn replace_value(
+    conn_str: &str,
+    names: &[&str],
+    value: &str,
+    use_odbc_rules: bool,
+) -> Result<String, Error> {
+    let mut chars = conn_str.char_indices();
+    let mut buf = String::new();
+
+    while let Some(pair) = parse_key_value(&mut chars, &mut buf, use_odbc_rules)? {
+        if pair.key.is_empty() {
+            break;
+        }
+
+        if names.contains(&pair.key.as_str()) {
+            let range = pair.value_range;
+            let mut out = conn_str[..range.start].to_owned();
+            let trailing = &conn_str[range.start..range.end];
+            let trailing = &trailing[trailing.trim_end().len()..];
+
+            push_value(&mut out, value, use_odbc_rules);
+            out.push_str(trailing);
+            out.push_str(&conn_str[range.end..]);
+            return Ok(out);
+        }
     }
+
+    let mut out = conn_str.trim_end().to_owned();
+    append_key_value(&mut out, names[0], value, use_odbc_rules);
+    Ok(out)
+}
+
+/// Quotes a value, such as a complete connection string, so that it is parsed back
+/// as a single value.
+///
+/// This is the quoting applied by [`append_key_value`] and reverted by
+/// [`unquote_value`].
+///
+/// # Example
+///
+/// ```
+/// use conn_str::{quote_as_value, unquote_value};
+///
+/// let inner = r#"server=.;password="Pass;1""#;
+/// let quoted = quote_as_value(inner);
+///
+/// assert_eq!(r#"'server=.;password="Pass;1"'"#, quoted);
+/// assert_eq!(inner, unquote_value(&quoted).unwrap());
+/// ```
+pub fn quote_as_value(inner: &str) -> String {
+    let mut out = String::with_capacity(inner.len() + 2);
+    push_quoted(&mut out, inner);
+    out
+}
+
+/// Unquotes a value quoted by [`quote_as_value`], failing when it is not a single value.
+pub fn unquote_value(value: &str) -> Result<String, Error> {
+    let conn_str = format!("k={}", value);
+    let mut chars = conn_str.char_indices();
+    let mut buf = String::new();
+    let syntax_error = |index: usize| Error::SyntaxError(index.saturating_sub(2));
+
+    let pair = parse_key_value(&mut chars, &mut buf, false)
+        .map_err(|e| match e {
+            Error::SyntaxError(index) => syntax_error(index),
+            e => e,
+        })?
+        .ok_or(Error::SyntaxError(0))?;
+
+    // nothing but whitespace may follow the value
+    match conn_str[pair.value_range.end..]
+        .char_indices()
+        .find(|(_, c)| !c.is_whitespace())
+    {
+        Some((index, _)) => Err(syntax_error(pair.value_range.end + index)),
+        None => Ok(pair.value),
+    }
+}
+
+fn push_quoted(out: &mut String, value: &str) {
     // value already quoted!
-    else if quote_value_match(value) {
+    if quote_value_match(value) {
         out.push_str(value)
     }
     // value contains double quote
@@ -297,6 +1388,17 @@ pub fn append_key_value(out: &mut String, key: &str, value: &str, use_odbc_rules
     }
 }
 
+#[test]
+fn unquote_value_rejects_several_values() {
+    assert_eq!("a\"b'c", unquote_value(&quote_as_value("a\"b'c")).unwrap());
+    assert_eq!("plain", unquote_value("plain").unwrap());
+    assert!(matches!(
+        unquote_value("'a';b=c"),
+        Err(Error::SyntaxError(3))
+    ));
+    assert!(unquote_value("a;b").is_err());
+}
+
 #[test]
 fn append_key_value_works() {
     let mut out = String::new();
@@ -304,6 +1406,196 @@ fn append_key_value_works() {
     assert_eq!(&out, "a=\"test=2\"");
 }
 
+/// Decodes bytes holding a connection string, following their byte order mark.
+///
+/// UTF-16LE and UTF-16BE are detected by their mark, like in the UDL files, the bytes
+/// being UTF-8 otherwise. The mark is not part of the decoded text.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::decode_bytes;
+///
+/// assert_eq!("a=1", decode_bytes(b"\xEF\xBB\xBFa=1").unwrap());
+/// assert_eq!("a=1", decode_bytes(b"\xFF\xFEa\x00=\x001\x00").unwrap());
+/// assert_eq!("a=1", decode_bytes(b"\xFE\xFF\x00a\x00=\x001").unwrap());
+/// assert!(decode_bytes(b"a=\xFF").is_err());
+/// ```
+pub fn decode_bytes(bytes: &[u8]) -> Result<Cow<'_, str>, Error> {
+    let (from_units, bytes): (fn([u8; 2]) -> u16, _) = match bytes {
+        [0xEF, 0xBB, 0xBF, rest @ ..] => return decode_utf8(rest, 3),
+        [0xFF, 0xFE, rest @ ..] => (u16::from_le_bytes, rest),
+        [0xFE, 0xFF, rest @ ..] => (u16::from_be_bytes, rest),
+        _ => return decode_utf8(bytes, 0),
+    };
+
+    let units = bytes.chunks(2).map(|c| match c {
+        [a, b] => Ok(from_units([*a, *b])),
+        _ => Err(()),
+    });
+
+    let mut out = String::with_capacity(bytes.len() / 2);
+    let mut index = 2;
+
+    for c in char::decode_utf16(units.map_while(Result::ok)) {
+        match c {
+            Ok(c) => {
+                out.push(c);
+                index += c.len_utf16() * 2;
+            }
+            Err(_) => return Err(Error::InvalidEncoding(index)),
+        }
+    }
+
+    // an odd number of bytes leaves half of a code unit
+    if bytes.len() % 2 != 0 {
+        return Err(Error::InvalidEncoding(bytes.len() + 1));
+    }
+
+    Ok(Cow::Owned(out))
+}
+
+fn decode_utf8(bytes: &[u8], offset: usize) -> Result<Cow<'_, str>, Error> {
+    std::str::from_utf8(bytes)
+        .map(Cow::Borrowed)
+        .map_err(|e| Error::InvalidEncoding(offset + e.valid_up_to()))
+}
+
+#[test]
+fn decode_bytes_reports_the_index_of_invalid_bytes() {
+    assert!(matches!(
+        decode_bytes(b"\xEF\xBB\xBFa=\xFF"),
+        Err(Error::InvalidEncoding(5))
+    ));
+    assert!(matches!(
+        decode_bytes(b"\xFF\xFEa\x00\x00\xDC"),
+        Err(Error::InvalidEncoding(4))
+    ));
+    assert!(matches!(
+        decode_bytes(b"\xFF\xFEa\x00="),
+        Err(Error::InvalidEncoding(4))
+    ));
+}
+
+/// Compares two secrets in a time independent of their content.
+///
+/// Use it instead of `==` when comparing passwords or keys, to avoid leaking
+/// information through the comparison time.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::secret_eq;
+///
+/// assert!(secret_eq("Pass1=3", "Pass1=3"));
+/// assert!(!secret_eq("Pass1=3", "Pass1=4"));
+/// assert!(!secret_eq("Pass1=3", "Pass1"));
+/// ```
+pub fn secret_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut diff = a.len() ^ b.len();
+
+    for i in 0..a.len().max(b.len()) {
+        let x = a.get(i).copied().unwrap_or(0);
+        let y = b.get(i).copied().unwrap_or(0);
+        diff |= usize::from(x ^ y);
+    }
+
+    std::hint::black_box(diff) == 0
+}
+
+/// Sets the value of a key under its canonical name, removing its synonyms, a `None`
+/// value removing the key.
+fn set_keyword(
+    map: &mut HashMap<String, String>,
+    keywords: &Keywords,
+    key: &str,
+    value: Option<&str>,
+) {
+    let key = match keywords.find(key) {
+        Some(keyword) => {
+            for name in keyword.names() {
+                map.remove(name);
+            }
+            keyword.name.to_owned()
+        }
+        None => {
+            let key = key.trim().to_lowercase();
+            map.remove(&key);
+            key
+        }
+    };
+
+    if let Some(value) = value {
+        map.insert(key, value.to_owned());
+    }
+}
+
+/// Gets the value of a keyword, looking up its canonical name and then its synonyms.
+fn get<'a>(map: &'a HashMap<String, String>, keywords: &Keywords, name: &str) -> Option<&'a str> {
+    keywords
+        .get(name)
+        .and_then(|k| k.names().find_map(|n| map.get(n)))
+        .map(|s| s.as_str())
+}
+
+/// Formats the keys and values sorted by key, replacing the secrets by `***` when
+/// they are redacted.
+///
+/// The secrets of a nested provider connection string are also redacted.
+fn to_connection_string(
+    map: &HashMap<String, String>,
+    keywords: &Keywords,
+    use_odbc_rules: bool,
+    redact: bool,
+) -> String {
+    let mut keys: Vec<_> = map.keys().collect();
+    keys.sort();
+
+    let mut out = String::new();
+
+    for key in keys {
+        let value = &map[key];
+
+        match redact {
+            true => append_key_value(
+                &mut out,
+                key,
+                &redact_value(keywords, key, value),
+                use_odbc_rules,
+            ),
+            false => append_key_value(&mut out, key, value, use_odbc_rules),
+        }
+    }
+
+    out
+}
+
+/// Replaces a secret by `***`, and the secrets of a nested provider connection string,
+/// an invalid one being replaced as a whole.
+fn redact_value<'a>(keywords: &Keywords, key: &str, value: &'a str) -> Cow<'a, str> {
+    match keywords.find(key).map(|k| (k.name, k.kind)) {
+        Some((_, ValueKind::Secret)) => Cow::Borrowed("***"),
+        Some(("provider connection string", _)) => Cow::Owned(
+            MsSqlConnStr::from_str(value)
+                .map(|c| c.to_string())
+                .unwrap_or_else(|_| "***".to_owned()),
+        ),
+        _ => Cow::Borrowed(value),
+    }
+}
+
+fn unknown_keys<'a>(map: &'a HashMap<String, String>, keywords: &Keywords) -> Vec<&'a str> {
+    let mut keys: Vec<_> = map
+        .keys()
+        .filter(|k| keywords.find(k).is_none())
+        .map(|k| k.as_str())
+        .collect();
+
+    keys.sort_unstable();
+    keys
+}
+
 fn parse_bool(s: &str) -> Result<bool, Error> {
     match s.to_lowercase().as_str() {
         "true" | "yes" => Ok(true),
@@ -312,6 +1604,18 @@ fn parse_bool(s: &str) -> Result<bool, Error> {
     }
 }
 
+/// Parses a port between 1 and 65535, the error naming its key.
+fn parse_port(key: &str, port: &str) -> Result<u16, Error> {
+    match port.trim().parse::<u16>() {
+        Ok(p) if p > 0 => Ok(p),
+        _ => Err(Error::InvalidValue {
+            key: key.to_owned(),
+            value: port.to_owned(),
+            expected: "a port between 1 and 65535".to_owned(),
+        }),
+    }
+}
+
 fn quote_odbc_value_match(s: &str) -> bool {
     // should be identical to the following regex
     // ^{([^}]|}})*}$
@@ -333,6 +1637,22 @@ fn quote_value_match(s: &str) -> bool {
     })
 }
 
+#[test]
+fn ports_are_between_1_and_65535() {
+    assert_eq!(5432, parse_port("port", " 5432 ").unwrap());
+
+    for port in &["0", "65536", "x"] {
+        match parse_port("port number", port) {
+            Err(Error::InvalidValue { key, value, .. }) => {
+                assert_eq!("port number", key);
+                assert_eq!(*port, value);
+            }
+            _ => panic!("`{}` should not be a port", port),
+        }
+    }
+}
+
+#[cfg(not(feature = "secret"))]
 #[test]
 fn sql_conn_builder_str_from_str_works() {
     let s = r#"Data Source=.;Initial Catalog=MasterDb;Integrated Security=False;User ID=me;Password="special=321";MultipleActiveResultSets=True;Application Name=RustApp"#;
@@ -342,26 +1662,164 @@ fn sql_conn_builder_str_from_str_works() {
     assert_eq!("me", b.user_id().unwrap());
 }
 
+#[cfg(feature = "secret")]
+#[test]
+fn sql_conn_builder_str_from_str_works() {
+    let s = r#"Data Source=.;Initial Catalog=MasterDb;Integrated Security=False;User ID=me;Password="special=321";MultipleActiveResultSets=True;Application Name=RustApp"#;
+    let b = MsSqlConnStr::from_str(s).unwrap();
+
+    assert_eq!("special=321", b.password().unwrap().expose());
+    assert_eq!("me", b.user_id().unwrap());
+}
+
+/// A recoverable anomaly found while parsing a connection string.
+///
+/// The indexes are byte positions in the connection string.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Warning {
+    /// A deprecated key, or a renamed synonym, with the keyword to use instead.
+    DeprecatedKey {
+        key: String,
+        index: usize,
+        replacement: Option<&'static str>,
+    },
+
+    /// A key already set, whose value is ignored.
+    DuplicateKey { key: String, index: usize },
+
+    /// A semicolon without a key, such as in `a=1;;b=2`.
+    EmptyPair { index: usize },
+
+    /// A key containing tabs or consecutive spaces, or a quoted value starting or
+    /// ending with whitespace.
+    SuspiciousWhitespace { key: String, index: usize },
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Warning::DeprecatedKey {
+                key,
+                index,
+                replacement: Some(replacement),
+            } => write!(
+                f,
+                "key `{}` at `{}` is deprecated, use `{}` instead",
+                key, index, replacement
+            ),
+            Warning::DeprecatedKey { key, index, .. } => {
+                write!(f, "key `{}` at `{}` is deprecated", key, index)
+            }
+            Warning::DuplicateKey { key, index } => {
+                write!(f, "key `{}` at `{}` is already set and ignored", key, index)
+            }
+            Warning::EmptyPair { index } => write!(f, "empty key value pair at `{}`", index),
+            Warning::SuspiciousWhitespace { key, index } => {
+                write!(f, "suspicious whitespace in `{}` at `{}`", key, index)
+            }
+        }
+    }
+}
+
+/// Parses a connection string, using the keyword table when the parsing is strict.
 fn parse(
     conn_str: &str,
     use_odbc_rules: bool,
-    synonyms: Option<&HashMap<String, String>>,
+    keywords: &dyn Lookup,
+    options: &ParseOptions,
 ) -> Result<HashMap<String, String>, Error> {
+    parse_warn(conn_str, use_odbc_rules, keywords, options, &mut Vec::new())
+}
+
+/// Parses a connection string like [`parse`], collecting the recoverable anomalies.
+fn parse_warn(
+    conn_str: &str,
+    use_odbc_rules: bool,
+    keywords: &dyn Lookup,
+    options: &ParseOptions,
+    warnings: &mut Vec<Warning>,
+) -> Result<HashMap<String, String>, Error> {
+    if let Some(limits) = &options.limits {
+        if conn_str.chars().count() > limits.max_len {
+            return Err(Error::LimitExceeded {
+                key: None,
+                limit: limits.max_len,
+            });
+        }
+    }
+
     let mut chars = conn_str.char_indices();
     let mut map = HashMap::new();
+    let mut buf = String::new();
+    // the end of the previous value, followed by its separator
+    let mut previous_end = 0;
+    let mut separators = 0;
+
+    while let Some(pair) = parse_key_value(&mut chars, &mut buf, use_odbc_rules)? {
+        let Pair {
+            key,
+            value,
+            key_index,
+            value_range: range,
+        } = pair;
 
-    while let Some((key, value)) = parse_key_value(&mut chars, use_odbc_rules)? {
         if key.is_empty() {
             break;
         }
 
-        let key = match synonyms {
-            Some(synonyms) => match synonyms.get(&key) {
-                Some(key) => key.clone(),
-                None => return Err(Error::KeyNotSupported(key)),
-            },
-            None => key,
-        };
+        empty_pairs(conn_str, previous_end..key_index, separators, warnings);
+        previous_end = range.end;
+        separators = 1;
+
+        if let Some(limits) = &options.limits {
+            if key.chars().count() > limits.max_key_len {
+                return Err(Error::LimitExceeded {
+                    key: Some(key),
+                    limit: limits.max_key_len,
+                });
+            }
+        }
+
+        if options.hardened {
+            let credential = matches!(keywords.lookup(&key), Some((_, _, true)));
+            let raw = &conn_str[range.clone()];
+            // only a quoted value may hold a `;`
+            let quoted = raw.starts_with(['"', '\'', '{']);
+
+            check_hardened(&key, raw, range.start, credential && quoted)?;
+        }
+
+        let raw = &conn_str[range.clone()];
+
+        if key.contains(|c: char| c.is_whitespace() && c != ' ') || key.contains("  ") {
+            warnings.push(Warning::SuspiciousWhitespace {
+                key: key.clone(),
+                index: key_index,
+            });
+        } else if raw.starts_with(['"', '\'', '{']) {
+            let inner = if raw.starts_with('{') {
+                value.get(1..value.len() - 1).unwrap_or_default()
+            } else {
+                &value
+            };
+
+            if inner.trim() != inner {
+                warnings.push(Warning::SuspiciousWhitespace {
+                    key: key.clone(),
+                    index: range.start,
+                });
+            }
+        }
+
+        if let Some(deprecation) = keywords.deprecation(&key) {
+            warnings.push(Warning::DeprecatedKey {
+                key: key.clone(),
+                index: key_index,
+                replacement: deprecation.replacement,
+            });
+        }
+
+        let (key, value) = decrypt_and_check(key, value, keywords, options)?;
 
         if key
             .chars()
@@ -373,23 +1831,198 @@ fn parse(
             return Err(Error::KeyNotSupported(key));
         }
 
-        map.entry(key).or_insert(value);
+        match map.entry(key) {
+            Entry::Occupied(entry) => warnings.push(Warning::DuplicateKey {
+                key: entry.key().clone(),
+                index: key_index,
+            }),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+            }
+        }
+    }
+
+    empty_pairs(conn_str, previous_end..conn_str.len(), separators, warnings);
+
+    Ok(map)
+}
+
+/// Checks the keys and values split by a grammar of its own, with the index of
+/// their value, like [`parse`] does, the last occurrence of a key winning.
+fn parse_pairs(
+    conn_str: &str,
+    pairs: Vec<(String, String, usize)>,
+    keywords: &dyn Lookup,
+    options: &ParseOptions,
+) -> Result<HashMap<String, String>, Error> {
+    if let Some(limits) = &options.limits {
+        if conn_str.chars().count() > limits.max_len {
+            return Err(Error::LimitExceeded {
+                key: None,
+                limit: limits.max_len,
+            });
+        }
+    }
+
+    let mut map = HashMap::new();
+
+    for (key, value, index) in pairs {
+        if let Some(limits) = &options.limits {
+            if key.chars().count() > limits.max_key_len {
+                return Err(Error::LimitExceeded {
+                    key: Some(key),
+                    limit: limits.max_key_len,
+                });
+            }
+        }
+
+        if options.hardened {
+            // the value is already unquoted by the grammar of the format
+            let credential = matches!(keywords.lookup(&key), Some((_, _, true)));
+
+            check_hardened(&key, &value, index, credential)?;
+        }
+
+        let (key, value) = decrypt_and_check(key.to_lowercase(), value, keywords, options)?;
+        map.insert(key, value);
     }
 
     Ok(map)
 }
 
+/// Decrypts the value of a credential key and, when the parsing is strict, checks the
+/// key and its value.
+///
+/// The key is kept as written, so that the synonyms set together are resolved by the
+/// getters, canonical name first, whatever the parsing.
+fn decrypt_and_check(
+    key: String,
+    value: String,
+    keywords: &dyn Lookup,
+    options: &ParseOptions,
+) -> Result<(String, String), Error> {
+    let value = match (&options.decryptor, keywords.lookup(&key)) {
+        (Some(decryptor), Some((name, _, true))) => decryptor.decrypt(name, value)?,
+        _ => value,
+    };
+
+    if !options.strict {
+        return Ok((key, value));
+    }
+
+    match keywords.lookup(&key) {
+        Some((_, kind, _)) if kind.accepts(&value) => Ok((key, value)),
+        Some((_, kind, _)) => Err(Error::InvalidValue {
+            key,
+            value,
+            expected: kind.to_string(),
+        }),
+        None => Err(Error::KeyNotSupported(key)),
+    }
+}
+
+/// Reports the semicolons in `range` beyond the `expected` separators as empty pairs.
+fn empty_pairs(conn_str: &str, range: Range<usize>, expected: usize, warnings: &mut Vec<Warning>) {
+    for (index, _) in conn_str[range.clone()].match_indices(';').skip(expected) {
+        warnings.push(Warning::EmptyPair {
+            index: range.start + index,
+        });
+    }
+}
+
+/// Rejects the suspicious values of a hardened parsing.
+///
+/// `raw` is the value starting at `offset` in the connection string, in which a nested
+/// `;key=` pattern is looked up when `nested` is set.
+fn check_hardened(key: &str, raw: &str, offset: usize, nested: bool) -> Result<(), Error> {
+    let raw = raw.trim_end();
+    let suspicious = |index: usize| Error::SuspiciousValue {
+        key: key.to_owned(),
+        index: offset + index,
+    };
+
+    if let Some((index, _)) = raw.char_indices().find(|(_, c)| c.is_control()) {
+        return Err(suspicious(index));
+    }
+
+    if nested {
+        // looks for a nested `;key=` pattern
+        for (index, _) in raw.match_indices(';') {
+            let rest = &raw[index + 1..];
+
+            if let Some(eq) = rest.find('=') {
+                let nested_key = rest[..eq].trim();
+
+                if !nested_key.is_empty() && !nested_key.contains(';') {
+                    return Err(suspicious(index));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// A key and its value parsed from a connection string.
+struct Pair {
+    key: String,
+    value: String,
+
+    /// The position of the key in the connection string.
+    key_index: usize,
+
+    /// The range of the value, as written in the connection string with its quotes.
+    value_range: Range<usize>,
+}
+
+/// Parses the next key and value, using `buf` as a scratch buffer.
+///
+/// The scratch buffer is reused across the pairs so that only the returned key
+/// and value are allocated.
 fn parse_key_value(
     chars: &mut CharIndices,
+    buf: &mut String,
+    use_odbc_rules: bool,
+) -> Result<Option<Pair>, Error> {
+    Ok(scan_key_value(chars, buf, use_odbc_rules)?.map(|raw| Pair {
+        key: buf[..raw.key_len].to_lowercase(),
+        value: buf[raw.key_len..].to_owned(),
+        key_index: raw.key_index,
+        value_range: raw.value_range,
+    }))
+}
+
+/// A key and its unescaped value scanned into a scratch buffer, the value following
+/// the key.
+struct RawPair {
+    /// The length of the key, as written, at the start of the buffer.
+    key_len: usize,
+
+    /// The position of the key in the connection string.
+    key_index: usize,
+
+    /// The range of the value, as written in the connection string with its quotes.
+    value_range: Range<usize>,
+}
+
+/// Scans the next key and value into `buf`, without allocating anything else.
+///
+/// An empty key marks the end of the connection string.
+fn scan_key_value(
+    chars: &mut CharIndices,
+    buf: &mut String,
     use_odbc_rules: bool,
-) -> Result<Option<(String, String)>, Error> {
+) -> Result<Option<RawPair>, Error> {
     let mut state = State::NothingYet;
-    let mut buf = String::new();
-    let mut key = String::new();
-    let mut value = String::new();
+    let mut key_len = 0;
+    let mut key_index = 0;
+    let mut value_start = None;
+    let mut end = None;
     let mut i = None;
 
-    'next: while let Some((index, c)) = chars.next() {
+    buf.clear();
+
+    'next: for (index, c) in chars.by_ref() {
         i = Some(index);
 
         // this loop is used to simulate a fallback between state
@@ -405,9 +2038,11 @@ fn parse_key_value(
                     } else if c.is_control() {
                         return Err(Error::SyntaxError(index));
                     } else if c == '=' {
+                        key_index = index;
                         state = State::KeyEqual;
                         continue;
                     } else {
+                        key_index = index;
                         state = State::Key;
                         buf.push(c);
                         continue 'next;
@@ -430,12 +2065,12 @@ fn parse_key_value(
                         buf.push(c);
                         continue 'next;
                     } else {
-                        key = buf.trim_end().to_lowercase();
-                        if key.is_empty() {
+                        key_len = buf.trim_end().len();
+                        if key_len == 0 {
                             return Err(Error::SyntaxError(index));
                         }
 
-                        buf.clear();
+                        buf.truncate(key_len);
                         state = State::KeyEnd;
                         continue;
                     }
@@ -444,6 +2079,9 @@ fn parse_key_value(
                     if c.is_whitespace() {
                         continue 'next;
                     }
+
+                    value_start = Some(index);
+
                     if use_odbc_rules {
                         if c == '{' {
                             state = State::BraceQuoteValue;
@@ -494,7 +2132,6 @@ fn parse_key_value(
                         buf.push(c);
                         continue 'next;
                     } else {
-                        value = buf.clone();
                         state = State::QuotedValueEnd;
                         continue;
                     }
@@ -516,7 +2153,6 @@ fn parse_key_value(
                         buf.push(c);
                         continue 'next;
                     } else {
-                        value = buf.clone();
                         state = State::QuotedValueEnd;
                         continue;
                     }
@@ -536,7 +2172,6 @@ fn parse_key_value(
                         buf.push(c);
                         continue 'next;
                     } else {
-                        value = buf.clone();
                         state = State::QuotedValueEnd;
                         continue;
                     }
@@ -562,9 +2197,13 @@ fn parse_key_value(
                 }
             }
         }
+        end = i;
         break;
     }
 
+    let end = end.unwrap_or_else(|| chars.offset());
+    let value_range = value_start.unwrap_or(end)..end;
+
     if let Some(index) = i {
         match state {
             State::Key
@@ -574,27 +2213,33 @@ fn parse_key_value(
                 return Err(Error::SyntaxError(index));
             }
             State::KeyEqual => {
-                key = buf.trim_end().to_lowercase();
-                if buf.is_empty() {
+                key_len = buf.trim_end().len();
+                if key_len == 0 {
                     return Err(Error::SyntaxError(index));
                 }
+                buf.truncate(key_len);
             }
             State::UnquotedValue => {
-                value = buf.trim().to_owned();
-                if !use_odbc_rules && (value.ends_with('\'') || value.ends_with('"')) {
+                // leading whitespaces are skipped in State::KeyEnd, so the key is kept
+                buf.truncate(buf.trim_end().len());
+                if !use_odbc_rules && (buf.ends_with('\'') || buf.ends_with('"')) {
                     return Err(Error::SyntaxError(index));
                 }
             }
-            State::DoubleQuoteValueQuote
+            State::NothingYet
+            | State::KeyEnd
+            | State::DoubleQuoteValueQuote
             | State::SingleQuoteValueQuote
             | State::BraceQuoteValueQuote
-            | State::QuotedValueEnd => {
-                value = buf.clone();
-            }
-            State::NothingYet | State::KeyEnd | State::NullTermination => {}
+            | State::QuotedValueEnd
+            | State::NullTermination => {}
         }
 
-        Ok(Some((key, value)))
+        Ok(Some(RawPair {
+            key_len,
+            key_index,
+            value_range,
+        }))
     } else {
         Ok(None)
     }
@@ -615,3 +2260,220 @@ enum State {
     QuotedValueEnd,
     NullTermination,
 }
+
+#[test]
+fn parse_reuses_scratch_buffer_across_pairs() {
+    let map = parse(
+        r#"a="x;y" ; b='z' ;c = w  ;d={v}"#,
+        false,
+        &keywords::MS_SQL,
+        &ParseOptions::default(),
+    )
+    .unwrap();
+
+    assert_eq!("x;y", map["a"]);
+    assert_eq!("z", map["b"]);
+    assert_eq!("w", map["c"]);
+    assert_eq!("{v}", map["d"]);
+
+    let map = parse(
+        "a={x;y};b=z",
+        true,
+        &keywords::MS_SQL,
+        &ParseOptions::default(),
+    )
+    .unwrap();
+
+    assert_eq!("{x;y}", map["a"]);
+    assert_eq!("z", map["b"]);
+}
+
+#[test]
+fn clone_shares_the_parsed_values() {
+    let a = MsSqlConnStr::from_str("server=.;database=Db1").unwrap();
+    let b = a.clone();
+
+    assert!(Arc::ptr_eq(&a.0, &b.0));
+    assert!(
+        std::thread::spawn(move || b.initial_catalog() == Some("Db1"))
+            .join()
+            .unwrap()
+    );
+}
+
+#[test]
+fn validate_reports_conflicting_settings() {
+    let conn = MsSqlConnStr::from_str(
+        "server=a;failover partner=b;multi subnet failover=true;min pool size=10;max pool size=5",
+    )
+    .unwrap();
+    let mut rules: Vec<_> = conn.validate().into_iter().map(|i| i.rule).collect();
+    rules.sort();
+
+    assert_eq!(
+        vec![
+            validate::FAILOVER_PARTNER_CONFLICT,
+            validate::POOL_SIZE_CONFLICT
+        ],
+        rules
+    );
+}
+
+#[test]
+fn security_audit_flags_persisted_password() {
+    let conn =
+        MsSqlConnStr::from_str("server=.;encrypt=true;user id=me;pwd=x;persist security info=true")
+            .unwrap();
+    let rules: Vec<_> = conn.security_audit().into_iter().map(|i| i.rule).collect();
+
+    assert_eq!(
+        vec![audit::PERSIST_SECURITY_INFO, audit::SQL_AUTHENTICATION],
+        rules
+    );
+
+    let conn = MsSqlConnStr::from_str("server=.;encrypt=yes;integrated security=sspi").unwrap();
+    assert!(conn.security_audit().is_empty());
+}
+
+#[test]
+fn security_audit_flags_explicit_sql_password_authentication() {
+    let conn = MsSqlConnStr::from_str(
+        "server=.;encrypt=true;authentication=SqlPassword;user id=u;password=p",
+    )
+    .unwrap();
+    let rules: Vec<_> = conn.security_audit().into_iter().map(|i| i.rule).collect();
+
+    assert_eq!(vec![audit::SQL_AUTHENTICATION], rules);
+
+    let conn = MsSqlConnStr::from_str(
+        "server=.;encrypt=true;authentication=Active Directory Password;user id=u;password=p",
+    )
+    .unwrap();
+    assert!(conn.security_audit().is_empty());
+}
+
+#[test]
+fn hardened_parsing_rejects_suspicious_values() {
+    let options = ParseOptions {
+        hardened: true,
+        ..Default::default()
+    };
+
+    let conn = "server=.;user id=me;password=\"x;Integrated Security=true\"";
+    match MsSqlConnStr::parse_with(conn, &options) {
+        Err(Error::SuspiciousValue { key, index }) => {
+            assert_eq!("password", key);
+            assert_eq!(";", &conn[index..index + 1]);
+        }
+        _ => panic!("the nested key should be rejected"),
+    }
+
+    let conn = "server=.;app=\"a\tb\"";
+    match MsSqlConnStr::parse_with(conn, &options) {
+        Err(Error::SuspiciousValue { index, .. }) => assert_eq!(15, index),
+        _ => panic!("the control character should be rejected"),
+    }
+
+    assert!(MsSqlConnStr::parse_with("server=.;pwd=\"a;b\" ;app='x;y=z'", &options).is_ok());
+}
+
+#[test]
+fn strict_parsing_resolves_synonyms_like_the_getters() {
+    let conn = "server=a;data source=b";
+    let strict = MsSqlConnStr::parse_strict(conn).unwrap();
+
+    assert_eq!(
+        Some("b"),
+        MsSqlConnStr::from_str(conn).unwrap().data_source()
+    );
+    assert_eq!(Some("b"), strict.data_source());
+    assert!(strict
+        .validate()
+        .iter()
+        .any(|i| i.rule == validate::CONFLICTING_SYNONYMS));
+}
+
+#[test]
+fn decryptor_only_sees_the_credentials() {
+    let options = ParseOptions {
+        decryptor: Some(Decryptor::new(|key, value| match value {
+            "enc:bad" => Err("bad padding".to_owned()),
+            _ => Ok(Some(format!("{}:{}", key, value))),
+        })),
+        ..Default::default()
+    };
+
+    let conn = MsSqlConnStr::parse_with("server=enc:x;uid=enc:y", &options).unwrap();
+    assert_eq!(Some("enc:x"), conn.data_source());
+    assert_eq!(Some("user id:enc:y"), conn.user_id());
+
+    match MsSqlConnStr::parse_with("server=.;pwd=enc:bad", &options) {
+        Err(Error::DecryptionFailed { key, message }) => {
+            assert_eq!("password", key);
+            assert_eq!("bad padding", message);
+        }
+        _ => panic!("the decryption should fail"),
+    }
+}
+
+#[test]
+fn parse_with_warnings_reports_ignored_anomalies() {
+    let conn = "server=.;;database=Db1;Server=x;network library=dbmssocn;user\tid=me;pwd=' p';";
+    let (conn_str, warnings) =
+        MsSqlConnStr::parse_with_warnings(conn, &ParseOptions::default()).unwrap();
+
+    assert_eq!(Some("."), conn_str.data_source());
+    assert_eq!(
+        vec![
+            Warning::EmptyPair { index: 9 },
+            Warning::DuplicateKey {
+                key: "server".to_owned(),
+                index: 23,
+            },
+            Warning::DeprecatedKey {
+                key: "network library".to_owned(),
+                index: 32,
+                replacement: Some("data source"),
+            },
+            Warning::SuspiciousWhitespace {
+                key: "user\tid".to_owned(),
+                index: 57,
+            },
+            Warning::SuspiciousWhitespace {
+                key: "pwd".to_owned(),
+                index: 72,
+            },
+        ],
+        warnings
+    );
+
+    let (_, warnings) =
+        MsSqlConnStr::parse_with_warnings("a=1;", &ParseOptions::default()).unwrap();
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn display_redacts_the_nested_secrets() {
+    let conn = EFConnStr::from_str(
+        r#"metadata=res://*/M.csdl;provider connection string="server=.;pwd=x""#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        r#"metadata=res://*/M.csdl;provider connection string="pwd=***;server=.""#,
+        conn.to_string()
+    );
+    assert!(conn.to_connection_string_with_secrets().contains("pwd=x"));
+}
+
+#[test]
+fn replace_value_keeps_the_other_keys() {
+    assert_eq!(
+        "a = 'x;y' ;b=2 ;c=3",
+        replace_value("a = 'x;y' ;b=1 ;c=3", &["b"], "2", false).unwrap()
+    );
+    assert_eq!(
+        "a=1;b=;c={x;y}",
+        replace_value("a=1;b=;", &["c"], "x;y", true).unwrap()
+    );
+}
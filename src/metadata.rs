@@ -0,0 +1,154 @@
+//! Entity Framework metadata, the list of the model artifacts.
+
+use crate::Error;
+use std::fmt;
+use std::str::FromStr;
+
+const DATA_DIRECTORY: &str = "|datadirectory|";
+const RES: &str = "res://";
+
+/// An artifact of an Entity Framework model (csdl, ssdl or msl).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Artifact {
+    /// A resource embedded in an assembly, `*` standing for every assembly loaded.
+    ///
+    /// An empty resource stands for every resource of the assembly.
+    Resource { assembly: String, resource: String },
+
+    /// A file or a directory, which may start with `|DataDirectory|` or `~`.
+    Path(String),
+}
+
+impl fmt::Display for Artifact {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Artifact::Resource { assembly, resource } => {
+                write!(f, "{}{}/{}", RES, assembly, resource)
+            }
+            Artifact::Path(path) => f.write_str(path),
+        }
+    }
+}
+
+/// The `metadata` value of an Entity Framework connection string.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::metadata::{Artifact, EfMetadata};
+///
+/// let mut metadata: EfMetadata = "res://*/Model.csdl| res://*/Model.ssdl |res://*/Model.msl"
+///     .parse()
+///     .unwrap();
+///
+/// assert_eq!(3, metadata.artifacts.len());
+///
+/// metadata.artifacts.push(Artifact::Path("|DataDirectory|Extra.csdl".to_owned()));
+///
+/// assert_eq!(
+///     "res://*/Model.csdl|res://*/Model.ssdl|res://*/Model.msl||DataDirectory|Extra.csdl",
+///     metadata.to_string(),
+/// );
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EfMetadata {
+    pub artifacts: Vec<Artifact>,
+}
+
+impl FromStr for EfMetadata {
+    type Err = Error;
+
+    /// Parses the pipe separated artifacts, ignoring the blank ones.
+    ///
+    /// The pipes of a `|DataDirectory|` macro are not separators.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut artifacts = Vec::new();
+        let mut start = 0;
+        let mut index = 0;
+
+        while index <= s.len() {
+            let rest = &s[index..];
+
+            if rest.is_empty() || rest.starts_with('|') {
+                if starts_with_ignore_case(rest, DATA_DIRECTORY) {
+                    index += DATA_DIRECTORY.len();
+                    continue;
+                }
+
+                let item = s[start..index].trim();
+
+                if !item.is_empty() {
+                    artifacts.push(artifact(item).ok_or_else(|| Error::InvalidValue {
+                        key: "metadata".to_owned(),
+                        value: s.to_owned(),
+                        expected:
+                            "`res://<assembly>/<resource>` or a path, separated by `|`".to_owned(),
+                    })?);
+                }
+
+                start = index + 1;
+                index += 1;
+            } else {
+                index += rest.chars().next().map_or(1, char::len_utf8);
+            }
+        }
+
+        Ok(EfMetadata { artifacts })
+    }
+}
+
+impl fmt::Display for EfMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, artifact) in self.artifacts.iter().enumerate() {
+            if i > 0 {
+                f.write_str("|")?;
+            }
+            artifact.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn artifact(s: &str) -> Option<Artifact> {
+    if !starts_with_ignore_case(s, RES) {
+        return Some(Artifact::Path(s.to_owned()));
+    }
+
+    let (assembly, resource) = s[RES.len()..].split_once('/')?;
+
+    if assembly.trim().is_empty() {
+        return None;
+    }
+
+    Some(Artifact::Resource {
+        assembly: assembly.trim().to_owned(),
+        resource: resource.trim().to_owned(),
+    })
+}
+
+fn starts_with_ignore_case(s: &str, prefix: &str) -> bool {
+    s.get(..prefix.len())
+        .is_some_and(|p| p.eq_ignore_ascii_case(prefix))
+}
+
+#[test]
+fn metadata_keeps_data_directory_paths_and_rejects_bad_resources() {
+    let metadata: EfMetadata = "|DataDirectory|Model.csdl|res://My.Assembly/|~/bin/Model.msl"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            Artifact::Path("|DataDirectory|Model.csdl".to_owned()),
+            Artifact::Resource {
+                assembly: "My.Assembly".to_owned(),
+                resource: String::new(),
+            },
+            Artifact::Path("~/bin/Model.msl".to_owned()),
+        ],
+        metadata.artifacts
+    );
+
+    assert!("res://Model.csdl".parse::<EfMetadata>().is_err());
+}
@@ -0,0 +1,187 @@
+//! Npgsql connection strings, the ADO.NET provider of PostgreSQL.
+
+use crate::keywords;
+use crate::pg::SslMode;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{
+    decode_bytes, get, parse, parse_bool, parse_port, to_connection_string, Error, ParseOptions,
+};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// An Npgsql connection string.
+///
+/// The grammar is the one of the ADO.NET connection strings, the getters resolving
+/// the synonyms of Npgsql such as `Server`, `User ID` or `Max Pool Size`.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::npgsql::NpgsqlConnStr;
+/// use conn_str::pg::SslMode;
+/// use std::str::FromStr;
+///
+/// let conn = NpgsqlConnStr::from_str("Server=db1;Port=5433;Database=sales;User ID=me;Password=secret;Max Pool Size=20;SSL Mode=VerifyFull;Include Error Detail=true").unwrap();
+///
+/// assert_eq!(Some("db1"), conn.host());
+/// assert_eq!(Some(5433), conn.port().unwrap());
+/// assert_eq!(Some("sales"), conn.database());
+/// assert_eq!(Some("me"), conn.username());
+/// assert_eq!(20, conn.maximum_pool_size().unwrap());
+/// assert_eq!(SslMode::VerifyFull, conn.ssl_mode().unwrap());
+/// assert!(conn.include_error_detail().unwrap());
+/// ```
+#[derive(Clone)]
+pub struct NpgsqlConnStr(Arc<HashMap<String, String>>);
+
+impl FromStr for NpgsqlConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl NpgsqlConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        Ok(NpgsqlConnStr(Arc::new(parse(
+            conn_str,
+            false,
+            &keywords::NPGSQL,
+            options,
+        )?)))
+    }
+
+    pub fn database(&self) -> Option<&str> {
+        self.get("database")
+    }
+
+    /// Gets the hosts, a comma separated list when several hosts are tried, each one
+    /// possibly having its own port.
+    pub fn host(&self) -> Option<&str> {
+        self.get("host")
+    }
+
+    /// Indicates if the details of the errors are included in the exceptions, which
+    /// can hold sensitive data.
+    pub fn include_error_detail(&self) -> Result<bool, Error> {
+        match self.get("include error detail") {
+            Some(v) => parse_bool(v),
+            None => Ok(false),
+        }
+    }
+
+    /// Gets the maximum number of connections in the pool, 100 when missing.
+    pub fn maximum_pool_size(&self) -> Result<u32, Error> {
+        match self.get("maximum pool size") {
+            Some(v) => v.trim().parse().map_err(|_| Error::InvalidValue {
+                key: "maximum pool size".to_owned(),
+                value: v.to_owned(),
+                expected: keywords::NPGSQL
+                    .get("maximum pool size")
+                    .unwrap()
+                    .kind
+                    .to_string(),
+            }),
+            None => Ok(100),
+        }
+    }
+
+    #[cfg(not(feature = "secret"))]
+    pub fn password(&self) -> Option<&str> {
+        self.get("password")
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("password").map(crate::secret::Secret::new)
+    }
+
+    /// Gets the port of the hosts without one, `None` for the default port 5432.
+    pub fn port(&self) -> Result<Option<u16>, Error> {
+        match self.get("port") {
+            Some(port) => parse_port("port", port).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Gets the `SSL Mode`, written `VerifyCA` or `VerifyFull` by Npgsql, `Prefer`
+    /// when missing.
+    pub fn ssl_mode(&self) -> Result<SslMode, Error> {
+        let mode = match self.get("ssl mode") {
+            Some(mode) => mode,
+            None => return Ok(SslMode::Prefer),
+        };
+
+        match mode.trim().to_lowercase().as_str() {
+            "disable" => Ok(SslMode::Disable),
+            "allow" => Ok(SslMode::Allow),
+            "prefer" => Ok(SslMode::Prefer),
+            "require" => Ok(SslMode::Require),
+            "verifyca" => Ok(SslMode::VerifyCa),
+            "verifyfull" => Ok(SslMode::VerifyFull),
+            _ => Err(Error::InvalidValue {
+                key: "ssl mode".to_owned(),
+                value: mode.to_owned(),
+                expected: keywords::NPGSQL.get("ssl mode").unwrap().kind.to_string(),
+            }),
+        }
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.0, &keywords::NPGSQL, false, false)
+    }
+
+    pub fn username(&self) -> Option<&str> {
+        self.get("username")
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        get(&self.0, &keywords::NPGSQL, name)
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.0, &keywords::NPGSQL, validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for NpgsqlConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(
+            &self.0,
+            &keywords::NPGSQL,
+            false,
+            true,
+        ))
+    }
+}
+
+#[test]
+fn synonyms_of_npgsql_are_resolved() {
+    let conn =
+        NpgsqlConnStr::from_str("Host=db1;UserName=me;PWD=x;MaxPoolSize=5;SslMode=verify-full")
+            .unwrap();
+
+    assert_eq!(Some("me"), conn.username());
+    assert_eq!(5, conn.maximum_pool_size().unwrap());
+    assert!(conn.ssl_mode().is_err());
+    assert!(!conn.include_error_detail().unwrap());
+    assert_eq!(None, conn.port().unwrap());
+}
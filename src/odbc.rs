@@ -0,0 +1,577 @@
+//! ODBC connection strings, as passed to `SQLDriverConnect`.
+
+use crate::keywords;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{
+    decode_bytes, get, parse, parse_bool, parse_key_value, to_connection_string, Error, Pair,
+    ParseOptions,
+};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// The keyword designating the driver of an ODBC connection.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum OdbcSource {
+    /// The `DSN` keyword, naming a data source configured on the machine.
+    Dsn,
+
+    /// The `DRIVER` keyword, naming the driver directly.
+    Driver,
+}
+
+/// How an ODBC connection string designates its driver.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ConnectionKind {
+    /// The attributes are read from a .dsn file named by `FILEDSN`.
+    FileDsn,
+
+    /// The attributes are read from a data source configured on the machine, the
+    /// `Default` data source when neither `DSN` nor `DRIVER` is set.
+    Dsn,
+
+    /// The driver is named by `DRIVER`, with every attribute in the connection string.
+    DsnLess,
+}
+
+/// The family of an ODBC driver, for the drivers of SQL Server.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DriverFamily {
+    /// `ODBC Driver <version> for SQL Server`.
+    OdbcDriverForSqlServer,
+
+    /// `SQL Server Native Client <version>`, deprecated.
+    SqlServerNativeClient,
+
+    /// `SQL Server`, the legacy driver shipped with Windows.
+    SqlServer,
+
+    /// Any other driver.
+    Other,
+}
+
+/// The version of an ODBC driver, as written in its name.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DriverVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+/// The name of an ODBC driver, without its braces, with its family and version.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::odbc::{DriverFamily, DriverVersion, OdbcDriver};
+///
+/// let driver = OdbcDriver::new("{ODBC Driver 18 for SQL Server}");
+///
+/// assert_eq!("ODBC Driver 18 for SQL Server", driver.name);
+/// assert_eq!(DriverFamily::OdbcDriverForSqlServer, driver.family);
+/// assert_eq!(Some(DriverVersion { major: 18, minor: 0 }), driver.version);
+/// assert!(driver.supports_strict_encryption());
+///
+/// let driver = OdbcDriver::new(" {SQL Server Native Client 11.0} ");
+///
+/// assert_eq!(DriverFamily::SqlServerNativeClient, driver.family);
+/// assert_eq!(Some(DriverVersion { major: 11, minor: 0 }), driver.version);
+/// assert!(!driver.supports_strict_encryption());
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct OdbcDriver {
+    pub name: String,
+    pub family: DriverFamily,
+
+    /// The first version number found in the name, if any.
+    pub version: Option<DriverVersion>,
+}
+
+impl OdbcDriver {
+    /// Normalizes the value of a `DRIVER` keyword, stripping its braces.
+    pub fn new(value: &str) -> Self {
+        let value = value.trim();
+        let name = match value.strip_prefix('{').and_then(|v| v.strip_suffix('}')) {
+            Some(inner) => inner.replace("}}", "}"),
+            None => value.to_owned(),
+        };
+
+        let name = name.split_whitespace().collect::<Vec<_>>().join(" ");
+        let lower = name.to_lowercase();
+
+        let family = if lower.starts_with("odbc driver ") && lower.ends_with(" for sql server") {
+            DriverFamily::OdbcDriverForSqlServer
+        } else if lower.starts_with("sql server native client") {
+            DriverFamily::SqlServerNativeClient
+        } else if lower == "sql server" {
+            DriverFamily::SqlServer
+        } else {
+            DriverFamily::Other
+        };
+
+        let version = name.split_whitespace().find_map(|word| {
+            let mut parts = word.split('.');
+            let major = parts.next()?.parse().ok()?;
+            let minor = match parts.next() {
+                Some(minor) => minor.parse().ok()?,
+                None => 0,
+            };
+
+            Some(DriverVersion { major, minor })
+        });
+
+        OdbcDriver {
+            name,
+            family,
+            version,
+        }
+    }
+
+    /// Indicates if the driver supports `Encrypt=Strict`, the ODBC Driver 18 for SQL
+    /// Server or later.
+    pub fn supports_strict_encryption(&self) -> bool {
+        self.family == DriverFamily::OdbcDriverForSqlServer
+            && self.version.is_some_and(|v| v.major >= 18)
+    }
+}
+
+/// An ODBC connection string, parsed with the ODBC rules where a value is quoted
+/// with braces, a `}` being doubled within them.
+///
+/// Like the Driver Manager, the first occurrence of a repeated keyword wins, and
+/// when both `DSN` and `DRIVER` are set, the one appearing first is used and the
+/// other is ignored.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::odbc::{OdbcConnStr, OdbcSource};
+/// use std::str::FromStr;
+///
+/// let conn = OdbcConnStr::from_str("Driver={ODBC Driver 18 for SQL Server};DSN=Sales;Server=.;server=x").unwrap();
+///
+/// assert_eq!(Some(OdbcSource::Driver), conn.source());
+/// assert_eq!(Some("{ODBC Driver 18 for SQL Server}"), conn.driver());
+/// assert_eq!(None, conn.dsn());
+/// assert_eq!(Some("."), conn.get("SERVER"));
+///
+/// let conn = OdbcConnStr::from_str("DRIVER={SQL Server};SERVER=.;DATABASE=Db1;UID=me;PWD={a;b}}c};Trusted_Connection=yes").unwrap();
+///
+/// assert_eq!(Some("."), conn.server());
+/// assert_eq!(Some("Db1"), conn.database());
+/// assert_eq!(Some("me"), conn.user_id());
+/// assert!(conn.trusted_connection().unwrap());
+/// assert_eq!(
+///     "database=Db1;driver={SQL Server};pwd={a;b}}c};server=.;trusted_connection=yes;uid=me",
+///     conn.to_connection_string_with_secrets(),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct OdbcConnStr {
+    map: Arc<HashMap<String, String>>,
+    source: Option<OdbcSource>,
+}
+
+impl FromStr for OdbcConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl OdbcConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the
+    /// connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        let map = parse(conn_str, true, &keywords::ODBC, options)?;
+        let mut chars = conn_str.char_indices();
+        let mut buf = String::new();
+        let mut source = None;
+
+        while let Some(Pair { key, .. }) = parse_key_value(&mut chars, &mut buf, true)? {
+            source = match key.as_str() {
+                "dsn" => Some(OdbcSource::Dsn),
+                "driver" => Some(OdbcSource::Driver),
+                "" => break,
+                _ => continue,
+            };
+            break;
+        }
+
+        Ok(OdbcConnStr {
+            map: Arc::new(map),
+            source,
+        })
+    }
+
+    /// Classifies the connection, `FILEDSN` taking precedence over `DSN` and `DRIVER`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::odbc::{ConnectionKind, OdbcConnStr};
+    /// use std::str::FromStr;
+    ///
+    /// let kind = |s| OdbcConnStr::from_str(s).unwrap().connection_kind();
+    ///
+    /// assert_eq!(ConnectionKind::FileDsn, kind("dsn=Sales;filedsn=C:\\sales.dsn"));
+    /// assert_eq!(ConnectionKind::Dsn, kind("dsn=Sales;driver={SQL Server}"));
+    /// assert_eq!(ConnectionKind::DsnLess, kind("driver={SQL Server};server=."));
+    /// assert_eq!(ConnectionKind::Dsn, kind("uid=me"));
+    /// ```
+    pub fn connection_kind(&self) -> ConnectionKind {
+        if self.file_dsn().is_some() {
+            return ConnectionKind::FileDsn;
+        }
+
+        match self.source {
+            Some(OdbcSource::Driver) => ConnectionKind::DsnLess,
+            Some(OdbcSource::Dsn) | None => ConnectionKind::Dsn,
+        }
+    }
+
+    /// Gets the driver, `None` when missing or ignored because of a preceding `DSN`.
+    pub fn driver(&self) -> Option<&str> {
+        match self.source {
+            Some(OdbcSource::Driver) => self.keyword("driver"),
+            _ => None,
+        }
+    }
+
+    /// Gets the normalized driver, `None` when missing or ignored because of a
+    /// preceding `DSN`.
+    pub fn driver_info(&self) -> Option<OdbcDriver> {
+        self.driver().map(OdbcDriver::new)
+    }
+
+    /// Gets the data source name, `None` when missing or ignored because of a
+    /// preceding `DRIVER`.
+    pub fn dsn(&self) -> Option<&str> {
+        match self.source {
+            Some(OdbcSource::Dsn) => self.keyword("dsn"),
+            _ => None,
+        }
+    }
+
+    /// Gets the name of the .dsn file, `None` when missing or blank.
+    pub fn file_dsn(&self) -> Option<&str> {
+        self.keyword("filedsn").filter(|s| !s.trim().is_empty())
+    }
+
+    /// Merges the attributes of a .dsn file, given its content, into the connection.
+    ///
+    /// The attributes of the connection string take precedence over the ones of the
+    /// file, and `FILEDSN` is removed from the effective connection string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::odbc::{ConnectionKind, OdbcConnStr};
+    /// use std::str::FromStr;
+    ///
+    /// let file = "[ODBC]\nDRIVER=ODBC Driver 18 for SQL Server\nSERVER=db1\nDATABASE=Sales\n";
+    /// let conn = OdbcConnStr::from_str("FILEDSN=sales.dsn;Database=Archive;UID=me").unwrap();
+    /// let conn = conn.merge_file_dsn(file);
+    ///
+    /// assert_eq!(ConnectionKind::DsnLess, conn.connection_kind());
+    /// assert_eq!(
+    ///     "database=Archive;driver=ODBC Driver 18 for SQL Server;server=db1;uid=me",
+    ///     conn.to_string(),
+    /// );
+    /// ```
+    pub fn merge_file_dsn(&self, content: &str) -> Self {
+        FileDsn::parse(content).merge(self)
+    }
+
+    /// Reads the .dsn file named by `FILEDSN` and merges its attributes into the
+    /// connection, like [`merge_file_dsn`](Self::merge_file_dsn).
+    ///
+    /// The connection is returned unchanged without a `FILEDSN`.
+    pub fn resolve_file_dsn(&self) -> Result<Self, Error> {
+        match self.file_dsn() {
+            Some(path) => Ok(FileDsn::read(path.trim())?.merge(self)),
+            None => Ok(self.clone()),
+        }
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.map, &keywords::ODBC, true, false)
+    }
+
+    /// Gets the keywords in lower case and their values, for the drivers formatting
+    /// them with their own table.
+    pub(crate) fn map(&self) -> &HashMap<String, String> {
+        &self.map
+    }
+
+    /// Gets the value of a keyword, ignoring case, including the keywords specific to
+    /// the driver.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.map.get(&key.trim().to_lowercase()).map(|s| s.as_str())
+    }
+
+    pub fn database(&self) -> Option<&str> {
+        self.keyword("database")
+    }
+
+    #[cfg(not(feature = "secret"))]
+    pub fn password(&self) -> Option<&str> {
+        self.keyword("pwd")
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.keyword("pwd").map(crate::secret::Secret::new)
+    }
+
+    pub fn server(&self) -> Option<&str> {
+        self.keyword("server")
+    }
+
+    /// Gets the keyword designating the driver, the first of `DSN` or `DRIVER`.
+    pub fn source(&self) -> Option<OdbcSource> {
+        self.source
+    }
+
+    /// Indicates if the Windows account of the user is used, `Trusted_Connection=yes`.
+    pub fn trusted_connection(&self) -> Result<bool, Error> {
+        match self.keyword("trusted_connection") {
+            Some(v) => parse_bool(v.trim()),
+            None => Ok(false),
+        }
+    }
+
+    pub fn user_id(&self) -> Option<&str> {
+        self.keyword("uid")
+    }
+
+    fn keyword(&self, name: &str) -> Option<&str> {
+        get(&self.map, &keywords::ODBC, name)
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.map, &keywords::ODBC, validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for OdbcConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(
+            &self.map,
+            &keywords::ODBC,
+            true,
+            true,
+        ))
+    }
+}
+
+/// The attributes of an ODBC File DSN, the `[ODBC]` section of a .dsn file, such as
+/// `[ODBC]\nDRIVER=SQL Server\nSERVER=.\n`.
+///
+/// The keywords are read ignoring case, the first occurrence of a repeated keyword
+/// winning, and the lines starting with `;` or `#` are comments.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::odbc::{FileDsn, OdbcConnStr, OdbcSource};
+/// use std::str::FromStr;
+///
+/// let dsn = FileDsn::parse("[ODBC]\nDRIVER=SQL Server\nSERVER=.\nDATABASE=Sales\nWSID=ws1\n");
+///
+/// assert_eq!(Some("SQL Server"), dsn.get("Driver"));
+///
+/// let conn = dsn.to_conn_str();
+///
+/// assert_eq!(Some(OdbcSource::Driver), conn.source());
+/// assert_eq!("database=Sales;driver=SQL Server;server=.;wsid=ws1", conn.to_string());
+///
+/// let conn = dsn.merge(&OdbcConnStr::from_str("Database=Archive;UID=me").unwrap());
+///
+/// assert_eq!(Some("Archive"), conn.database());
+/// assert_eq!(Some("me"), conn.user_id());
+/// ```
+#[derive(Clone)]
+pub struct FileDsn(Arc<HashMap<String, String>>);
+
+impl FileDsn {
+    /// Reads the attributes of the `[ODBC]` section, empty without one.
+    pub fn parse(content: &str) -> Self {
+        FileDsn(Arc::new(dsn_file_attributes(content)))
+    }
+
+    /// Decodes the bytes with [`decode_bytes`] and reads the attributes, the .dsn
+    /// files saved by the ODBC Data Source Administrator being often UTF-16.
+    pub fn parse_from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(Self::parse(&decode_bytes(bytes)?))
+    }
+
+    /// Reads a .dsn file, the extension `.dsn` being added when missing like the
+    /// Driver Manager does.
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+
+        let path = if path.extension().is_some() {
+            PathBuf::from(path)
+        } else {
+            path.with_extension("dsn")
+        };
+
+        match fs::read(&path) {
+            Ok(content) => Self::parse_from_bytes(&content),
+            Err(e) => Err(Error::Unreadable {
+                path: path.display().to_string(),
+                message: e.to_string(),
+            }),
+        }
+    }
+
+    /// Gets the attributes, keyed by their keyword in lower case.
+    pub fn attributes(&self) -> &HashMap<String, String> {
+        &self.0
+    }
+
+    /// Gets the value of a keyword, ignoring case.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(&key.trim().to_lowercase()).map(|s| s.as_str())
+    }
+
+    /// Merges the attributes into a connection string, as the Driver Manager does
+    /// when connecting with a `FILEDSN`.
+    ///
+    /// The attributes of the connection string take precedence over the ones of the
+    /// file, and `FILEDSN` is removed from the effective connection string.
+    pub fn merge(&self, conn: &OdbcConnStr) -> OdbcConnStr {
+        merge_data_source(&self.0, conn, "filedsn")
+    }
+
+    /// Converts the attributes into a connection string, `DRIVER` being used over
+    /// `DSN` when the file has both.
+    pub fn to_conn_str(&self) -> OdbcConnStr {
+        let mut map = (*self.0).clone();
+        map.remove("filedsn");
+
+        OdbcConnStr {
+            source: source_of(&map),
+            map: Arc::new(map),
+        }
+    }
+}
+
+/// Merges the attributes of a data source into a connection string, the ones of the
+/// connection string taking precedence, and removes the keyword naming the data source.
+///
+/// When the data source is named by `DSN`, a `DRIVER` ignored by the connection
+/// string is not merged either.
+pub(crate) fn merge_data_source(
+    attributes: &HashMap<String, String>,
+    conn: &OdbcConnStr,
+    keyword: &str,
+) -> OdbcConnStr {
+    let by_dsn = keyword == "dsn" && conn.source == Some(OdbcSource::Dsn);
+    let mut map = attributes.clone();
+    map.remove(keyword);
+
+    for (key, value) in conn.map.iter() {
+        if key != keyword && !(by_dsn && key == "driver") {
+            map.insert(key.clone(), value.clone());
+        }
+    }
+
+    let source = match conn.source {
+        Some(_) if !by_dsn => conn.source,
+        _ => source_of(&map),
+    };
+
+    OdbcConnStr {
+        map: Arc::new(map),
+        source,
+    }
+}
+
+/// Gets the keyword designating the driver among attributes without order, `DRIVER`
+/// before `DSN`.
+fn source_of(map: &HashMap<String, String>) -> Option<OdbcSource> {
+    if map.contains_key("driver") {
+        Some(OdbcSource::Driver)
+    } else if map.contains_key("dsn") {
+        Some(OdbcSource::Dsn)
+    } else {
+        None
+    }
+}
+
+/// Reads the attributes of the `[ODBC]` section of a .dsn file, in lower case.
+fn dsn_file_attributes(content: &str) -> HashMap<String, String> {
+    let mut map = HashMap::new();
+    let mut in_odbc = false;
+
+    for line in content.lines().map(str::trim) {
+        if line.starts_with('[') && line.ends_with(']') {
+            in_odbc = line[1..line.len() - 1].trim().eq_ignore_ascii_case("odbc");
+        } else if in_odbc && !line.starts_with([';', '#']) {
+            if let Some((key, value)) = line.split_once('=') {
+                map.entry(key.trim().to_lowercase())
+                    .or_insert_with(|| value.trim().to_owned());
+            }
+        }
+    }
+
+    map
+}
+
+#[test]
+fn dsn_preceding_the_driver_is_authoritative() {
+    let conn = OdbcConnStr::from_str("dsn=Sales;uid=me;driver={SQL Server};dsn=Other").unwrap();
+
+    assert_eq!(Some(OdbcSource::Dsn), conn.source());
+    assert_eq!(Some("Sales"), conn.dsn());
+    assert_eq!(None, conn.driver());
+    assert_eq!(None, OdbcConnStr::from_str("server=.").unwrap().source());
+}
+
+#[test]
+fn file_dsn_attributes_are_overridden() {
+    let dsn = FileDsn::parse_from_bytes(
+        b"; saved\r\n[odbc]\r\nDSN=Sales\r\nFILEDSN=x.dsn\r\nPWD=p\r\npwd=q\r\n[Other]\r\nUID=no\r\n",
+    )
+    .unwrap();
+
+    assert_eq!(Some("p"), dsn.get("pwd"));
+    assert_eq!(None, dsn.get("uid"));
+    assert_eq!(Some(OdbcSource::Dsn), dsn.to_conn_str().source());
+    assert_eq!("dsn=Sales;pwd=***", dsn.to_conn_str().to_string());
+
+    let conn = OdbcConnStr::from_str("Driver={SQL Server};FILEDSN=x").unwrap();
+    let conn = dsn.merge(&conn);
+    assert_eq!(Some("{SQL Server}"), conn.driver());
+    assert_eq!(None, conn.file_dsn());
+    assert!(FileDsn::parse("DRIVER=x").attributes().is_empty());
+}
+
+#[test]
+fn missing_dsn_file_is_reported() {
+    let conn = OdbcConnStr::from_str("filedsn=/does/not/exist").unwrap();
+
+    match conn.resolve_file_dsn() {
+        Err(Error::Unreadable { path, .. }) => assert_eq!("/does/not/exist.dsn", path),
+        _ => panic!("the file should be unreadable"),
+    }
+}
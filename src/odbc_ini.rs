@@ -0,0 +1,212 @@
+//! The data sources of unixODBC, configured in the `odbc.ini` files, and its
+//! drivers, configured in the `odbcinst.ini` files.
+
+use crate::odbc::{merge_data_source, ConnectionKind, OdbcConnStr};
+use crate::{decode_bytes, Error};
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// The sections of an `odbc.ini` file, each one naming a data source, or of an
+/// `odbcinst.ini` file, each one naming a driver.
+///
+/// The names of the sections and the keywords are read ignoring case, the first
+/// occurrence of a repeated keyword winning, and the lines starting with `;` or `#`
+/// are comments.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::odbc::OdbcConnStr;
+/// use conn_str::odbc_ini::OdbcIni;
+/// use std::str::FromStr;
+///
+/// let ini = OdbcIni::parse("[ODBC Data Sources]\nSales = PostgreSQL\n\n[Sales]\nDriver = PostgreSQL\nServername = db1\nDatabase = sales\n");
+///
+/// assert_eq!(vec!["ODBC Data Sources", "Sales"], ini.names().collect::<Vec<_>>());
+/// assert_eq!(Some(&"db1".to_owned()), ini.section("sales").unwrap().get("servername"));
+///
+/// let conn = ini.resolve(&OdbcConnStr::from_str("DSN=Sales;UID=me").unwrap()).unwrap();
+///
+/// assert_eq!(Some("PostgreSQL"), conn.driver());
+/// assert_eq!("database=sales;driver=PostgreSQL;servername=db1;uid=me", conn.to_string());
+/// ```
+#[derive(Clone, Default)]
+pub struct OdbcIni {
+    sections: Vec<(String, HashMap<String, String>)>,
+}
+
+impl OdbcIni {
+    /// Reads the sections of the content of a file, the lines preceding the first
+    /// section being ignored.
+    pub fn parse(content: &str) -> Self {
+        let mut ini = OdbcIni::default();
+        let mut current = None;
+
+        for line in content.lines().map(str::trim) {
+            if line.starts_with('[') && line.ends_with(']') {
+                let name = line[1..line.len() - 1].trim();
+
+                current = Some(match ini.position(name) {
+                    Some(i) => i,
+                    None => {
+                        ini.sections.push((name.to_owned(), HashMap::new()));
+                        ini.sections.len() - 1
+                    }
+                });
+            } else if let (Some(i), false) = (current, line.starts_with([';', '#'])) {
+                if let Some((key, value)) = line.split_once('=') {
+                    ini.sections[i]
+                        .1
+                        .entry(key.trim().to_lowercase())
+                        .or_insert_with(|| value.trim().to_owned());
+                }
+            }
+        }
+
+        ini
+    }
+
+    /// Decodes the bytes with [`decode_bytes`] and reads the sections.
+    pub fn parse_from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(Self::parse(&decode_bytes(bytes)?))
+    }
+
+    /// Reads the sections of a file.
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+
+        match fs::read(path) {
+            Ok(content) => Self::parse_from_bytes(&content),
+            Err(e) => Err(Error::Unreadable {
+                path: path.display().to_string(),
+                message: e.to_string(),
+            }),
+        }
+    }
+
+    /// Reads the data sources of the user and of the system, like the Driver Manager
+    /// of unixODBC, the ones of the user hiding the ones of the system with the same
+    /// name.
+    ///
+    /// The file of the user is `$ODBCINI`, or `~/.odbc.ini` when missing, and the file
+    /// of the system is `odbc.ini` within `$ODBCSYSINI`, or `/etc/odbc.ini` when
+    /// missing. A file which does not exist is skipped.
+    pub fn load() -> Result<Self, Error> {
+        let user = match env::var_os("ODBCINI") {
+            Some(path) => Some(PathBuf::from(path)),
+            None => env::var_os("HOME").map(|home| Path::new(&home).join(".odbc.ini")),
+        };
+
+        let system = match env::var_os("ODBCSYSINI") {
+            Some(dir) => Path::new(&dir).join("odbc.ini"),
+            None => PathBuf::from("/etc/odbc.ini"),
+        };
+
+        let mut ini = OdbcIni::default();
+
+        for path in user.iter().chain(Some(&system)) {
+            match fs::metadata(path) {
+                Err(e) if e.kind() == ErrorKind::NotFound => continue,
+                _ => ini.extend(Self::read(path)?),
+            }
+        }
+
+        Ok(ini)
+    }
+
+    /// Gets the names of the sections, in their order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.sections.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Resolves the data source of a connection string, merging the attributes of its
+    /// section into the connection as the Driver Manager does.
+    ///
+    /// The attributes of the connection string take precedence over the ones of the
+    /// section, and `DSN` is removed from the effective connection string. Without
+    /// `DSN` or `DRIVER`, the `Default` data source is used. A connection naming its
+    /// driver or a .dsn file is returned unchanged.
+    pub fn resolve(&self, conn: &OdbcConnStr) -> Result<OdbcConnStr, Error> {
+        if conn.connection_kind() != ConnectionKind::Dsn {
+            return Ok(conn.clone());
+        }
+
+        let name = conn.dsn().map_or("Default", str::trim);
+
+        match self.section(name) {
+            Some(attributes) => Ok(merge_data_source(attributes, conn, "dsn")),
+            None => Err(Error::InvalidValue {
+                key: "dsn".to_owned(),
+                value: name.to_owned(),
+                expected: format!(
+                    "a data source among {}",
+                    self.names().collect::<Vec<_>>().join(", ")
+                ),
+            }),
+        }
+    }
+
+    /// Gets the attributes of a section, keyed by their keyword in lower case.
+    pub fn section(&self, name: &str) -> Option<&HashMap<String, String>> {
+        self.position(name).map(|i| &self.sections[i].1)
+    }
+
+    /// Adds the sections of another file, the keywords already read winning.
+    fn extend(&mut self, other: OdbcIni) {
+        for (name, attributes) in other.sections {
+            match self.position(&name) {
+                Some(i) => {
+                    for (key, value) in attributes {
+                        self.sections[i].1.entry(key).or_insert(value);
+                    }
+                }
+                None => self.sections.push((name, attributes)),
+            }
+        }
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        let name = name.trim();
+
+        self.sections
+            .iter()
+            .position(|(n, _)| n.eq_ignore_ascii_case(name))
+    }
+}
+
+#[test]
+fn dsn_hides_the_driver_of_the_connection() {
+    use std::str::FromStr;
+
+    let ini = OdbcIni::parse(
+        "; comment\nignored=1\n[default]\nDriver=SQLite3\nDatabase=/tmp/a.db\n[Sales]\n# Driver=x\nServer=db1\nServer=db2\n[SALES]\nPort=5432\n",
+    );
+
+    assert_eq!(vec!["default", "Sales"], ini.names().collect::<Vec<_>>());
+    assert_eq!(
+        Some(&"5432".to_owned()),
+        ini.section("sales").unwrap().get("port")
+    );
+
+    let conn = OdbcConnStr::from_str("DSN=Sales;DRIVER={SQL Server}").unwrap();
+    let conn = ini.resolve(&conn).unwrap();
+    assert_eq!(None, conn.driver());
+    assert_eq!("port=5432;server=db1", conn.to_string());
+
+    let conn = ini
+        .resolve(&OdbcConnStr::from_str("UID=me").unwrap())
+        .unwrap();
+    assert_eq!(Some("SQLite3"), conn.driver());
+
+    let conn = OdbcConnStr::from_str("Driver=x;DSN=Missing").unwrap();
+    assert_eq!(
+        "driver=x;dsn=Missing",
+        ini.resolve(&conn).unwrap().to_string()
+    );
+    assert!(ini
+        .resolve(&OdbcConnStr::from_str("DSN=Missing").unwrap())
+        .is_err());
+}
@@ -0,0 +1,221 @@
+//! OLE DB connection strings, as written by the `OleDbConnectionStringBuilder`.
+
+use crate::keywords::{self, ValueKind};
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{append_key_value, decode_bytes, get, parse, Error, ParseOptions};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// An OLE DB connection string.
+///
+/// The properties unknown to OLE DB are passed through to the provider, and the
+/// `Extended Properties` hold a nested connection string of the provider, such as
+/// the `Excel 12.0;HDR=YES` of the Excel files.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::oledb::OleDbConnStr;
+/// use std::str::FromStr;
+///
+/// let conn = OleDbConnStr::from_str("Data Source=.;Initial Catalog=Db1;OLE DB Services=-4;Provider=SQLOLEDB;Auto Translate=False").unwrap();
+///
+/// assert_eq!(Some("SQLOLEDB"), conn.provider());
+/// assert_eq!(Some("Db1"), conn.initial_catalog());
+/// assert_eq!(Some(-4), conn.ole_db_services().unwrap());
+/// assert_eq!(Some("False"), conn.get("auto translate"));
+///
+/// // the provider is written first, like the OleDbConnectionStringBuilder does
+/// assert_eq!(
+///     "provider=SQLOLEDB;auto translate=False;data source=.;initial catalog=Db1;ole db services=-4",
+///     conn.to_string(),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct OleDbConnStr(Arc<HashMap<String, String>>);
+
+impl FromStr for OleDbConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl OleDbConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    /// Parses the connection string, failing without a `Provider` when the keys are
+    /// required, as an `OleDbConnection` cannot be opened without it.
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        let conn = OleDbConnStr(Arc::new(parse(
+            conn_str,
+            false,
+            &keywords::OLE_DB,
+            options,
+        )?));
+
+        if options.required && conn.provider().is_none_or(|p| p.trim().is_empty()) {
+            return Err(Error::MissingKey("provider".to_owned()));
+        }
+
+        Ok(conn)
+    }
+
+    pub fn data_source(&self) -> Option<&str> {
+        self.get("data source")
+    }
+
+    /// Gets the nested connection string of the provider.
+    pub fn extended_properties(&self) -> Option<&str> {
+        self.get("extended properties")
+    }
+
+    /// Gets a property of the `Extended Properties`, ignoring case.
+    ///
+    /// The items without a `=`, such as the `Excel 12.0` version of the Excel files,
+    /// are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::oledb::OleDbConnStr;
+    /// use std::str::FromStr;
+    ///
+    /// let conn = OleDbConnStr::from_str(r#"Provider=Microsoft.ACE.OLEDB.12.0;Data Source=c:\data.xlsx;Extended Properties="Excel 12.0 Xml;HDR=YES;IMEX=1""#).unwrap();
+    ///
+    /// assert_eq!(Some("Excel 12.0 Xml;HDR=YES;IMEX=1"), conn.extended_properties());
+    /// assert_eq!(Some("YES"), conn.extended_property("hdr"));
+    /// assert_eq!(None, conn.extended_property("Excel 12.0 Xml"));
+    /// ```
+    pub fn extended_property(&self, key: &str) -> Option<&str> {
+        let key = key.trim();
+
+        self.extended_properties()?
+            .split(';')
+            .filter_map(|item| item.split_once('='))
+            .find(|(k, _)| k.trim().eq_ignore_ascii_case(key))
+            .map(|(_, v)| v.trim())
+    }
+
+    /// Gets the value of a property, ignoring case, including the properties of the
+    /// provider.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        get(&self.0, &keywords::OLE_DB, key)
+            .or_else(|| self.0.get(&key.trim().to_lowercase()).map(|s| s.as_str()))
+    }
+
+    pub fn initial_catalog(&self) -> Option<&str> {
+        self.get("initial catalog")
+    }
+
+    /// Gets the `OLE DB Services` bitmask, such as `-4` disabling the pooling and the
+    /// automatic enlistment.
+    pub fn ole_db_services(&self) -> Result<Option<i32>, Error> {
+        match self.get("ole db services") {
+            Some(v) => v.trim().parse().map(Some).map_err(|_| Error::InvalidValue {
+                key: "ole db services".to_owned(),
+                value: v.to_owned(),
+                expected: keywords::OLE_DB
+                    .get("ole db services")
+                    .unwrap()
+                    .kind
+                    .to_string(),
+            }),
+            None => Ok(None),
+        }
+    }
+
+    #[cfg(not(feature = "secret"))]
+    pub fn password(&self) -> Option<&str> {
+        self.get("password")
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("password").map(crate::secret::Secret::new)
+    }
+
+    pub fn provider(&self) -> Option<&str> {
+        self.get("provider")
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.0, false)
+    }
+
+    pub fn user_id(&self) -> Option<&str> {
+        self.get("user id")
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.0, &keywords::OLE_DB, validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for OleDbConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(&self.0, true))
+    }
+}
+
+/// Formats the provider followed by the other keys sorted, replacing the secrets by
+/// `***` when they are redacted.
+fn to_connection_string(map: &HashMap<String, String>, redact: bool) -> String {
+    let mut keys: Vec<_> = map.keys().collect();
+    keys.sort_by_key(|k| (k.as_str() != "provider", *k));
+
+    let mut out = String::new();
+
+    for key in keys {
+        let secret = keywords::OLE_DB
+            .find(key)
+            .is_some_and(|k| k.kind == ValueKind::Secret);
+
+        if secret && redact {
+            append_key_value(&mut out, key, "***", false);
+        } else {
+            append_key_value(&mut out, key, &map[key], false);
+        }
+    }
+
+    out
+}
+
+#[test]
+fn provider_is_required_and_written_first() {
+    let options = ParseOptions {
+        required: true,
+        ..Default::default()
+    };
+
+    match OleDbConnStr::parse_with("Data Source=.;Provider=", &options) {
+        Err(Error::MissingKey(key)) => assert_eq!("provider", key),
+        _ => panic!("the provider should be missing"),
+    }
+
+    let conn = OleDbConnStr::from_str(
+        "Jet OLEDB:Database Password=x;Extended Properties='Excel 8.0;HDR=No';Provider=Microsoft.Jet.OLEDB.4.0",
+    )
+    .unwrap();
+
+    assert_eq!(
+        "provider=Microsoft.Jet.OLEDB.4.0;extended properties=\"Excel 8.0;HDR=No\";jet oledb:database password=***",
+        conn.to_string()
+    );
+}
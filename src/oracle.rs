@@ -0,0 +1,406 @@
+//! Oracle connection strings, as parsed by the Oracle Data Provider for .NET
+//! (Oracle.ManagedDataAccess).
+
+use crate::host::Host;
+use crate::keywords;
+use crate::tns::Descriptor;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{decode_bytes, get, parse, parse_bool, to_connection_string, Error, ParseOptions};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// The `DBA Privilege` of an Oracle connection.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DbaPrivilege {
+    SysDba,
+    SysOper,
+    SysAsm,
+    SysBackup,
+    SysDg,
+    SysKm,
+}
+
+impl FromStr for DbaPrivilege {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "sysdba" => Ok(DbaPrivilege::SysDba),
+            "sysoper" => Ok(DbaPrivilege::SysOper),
+            "sysasm" => Ok(DbaPrivilege::SysAsm),
+            "sysbackup" => Ok(DbaPrivilege::SysBackup),
+            "sysdg" => Ok(DbaPrivilege::SysDg),
+            "syskm" => Ok(DbaPrivilege::SysKm),
+            _ => Err(Error::InvalidValue {
+                key: "dba privilege".to_owned(),
+                value: s.to_owned(),
+                expected: keywords::ORACLE
+                    .get("dba privilege")
+                    .unwrap()
+                    .kind
+                    .to_string(),
+            }),
+        }
+    }
+}
+
+/// Formats the privilege as written by ODP.NET.
+impl fmt::Display for DbaPrivilege {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            DbaPrivilege::SysDba => "SYSDBA",
+            DbaPrivilege::SysOper => "SYSOPER",
+            DbaPrivilege::SysAsm => "SYSASM",
+            DbaPrivilege::SysBackup => "SYSBACKUP",
+            DbaPrivilege::SysDg => "SYSDG",
+            DbaPrivilege::SysKm => "SYSKM",
+        })
+    }
+}
+
+/// The `SERVER` type of an EZConnect, the kind of server process handling the
+/// connection.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ServerType {
+    Dedicated,
+    Shared,
+
+    /// A pooled server of the Database Resident Connection Pooling.
+    Pooled,
+}
+
+impl FromStr for ServerType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "dedicated" => Ok(ServerType::Dedicated),
+            "shared" => Ok(ServerType::Shared),
+            "pooled" => Ok(ServerType::Pooled),
+            _ => Err(Error::InvalidValue {
+                key: "server".to_owned(),
+                value: s.to_owned(),
+                expected: "`dedicated`, `shared` or `pooled`".to_owned(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for ServerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ServerType::Dedicated => "dedicated",
+            ServerType::Shared => "shared",
+            ServerType::Pooled => "pooled",
+        })
+    }
+}
+
+/// An EZConnect data source, `[//]host[:port][/[service_name][:server][/instance]]`.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::oracle::{EzConnect, ServerType};
+///
+/// let ez: EzConnect = "//db1.example.com:1522/sales.example.com:pooled/sales1".parse().unwrap();
+///
+/// assert_eq!("db1.example.com", ez.host);
+/// assert_eq!(Some(1522), ez.port);
+/// assert_eq!(Some("sales.example.com"), ez.service_name.as_deref());
+/// assert_eq!(Some(ServerType::Pooled), ez.server);
+/// assert_eq!(Some("sales1"), ez.instance.as_deref());
+/// assert_eq!("db1.example.com:1522/sales.example.com:pooled/sales1", ez.to_string());
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct EzConnect {
+    /// The host name or the IP address, an IPv6 address being written without its
+    /// brackets.
+    pub host: String,
+
+    /// The listener port, `None` for the default port 1521.
+    pub port: Option<u16>,
+
+    pub service_name: Option<String>,
+    pub server: Option<ServerType>,
+    pub instance: Option<String>,
+}
+
+impl FromStr for EzConnect {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidValue {
+            key: "data source".to_owned(),
+            value: s.to_owned(),
+            expected: "`[//]host[:port][/[service_name][:server][/instance]]`".to_owned(),
+        };
+
+        let trimmed = s.trim();
+        let trimmed = trimmed.strip_prefix("//").unwrap_or(trimmed);
+
+        // the path starts at the first `/` following the brackets of an IPv6 address
+        let start = trimmed.find(']').unwrap_or(0);
+        let (address, path) = match trimmed[start..].find('/') {
+            Some(i) => (&trimmed[..start + i], &trimmed[start + i + 1..]),
+            None => (trimmed, ""),
+        };
+
+        let host: Host = address.parse().map_err(|_| invalid())?;
+
+        if host.name.is_empty() {
+            return Err(invalid());
+        }
+
+        let (service, instance) = path.split_once('/').unwrap_or((path, ""));
+        let (service, server) = service.split_once(':').unwrap_or((service, ""));
+        let non_empty = |s: &str| Some(s.trim().to_owned()).filter(|s| !s.is_empty());
+
+        Ok(EzConnect {
+            host: host.name,
+            port: host.port,
+            service_name: non_empty(service),
+            server: non_empty(server)
+                .map(|s| s.parse())
+                .transpose()
+                .map_err(|_| invalid())?,
+            instance: non_empty(instance),
+        })
+    }
+}
+
+/// Formats the EZConnect without its optional leading `//`.
+impl fmt::Display for EzConnect {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Host::new(&self.host, self.port).fmt(f)?;
+
+        if self.service_name.is_none() && self.server.is_none() && self.instance.is_none() {
+            return Ok(());
+        }
+
+        f.write_str("/")?;
+
+        if let Some(service_name) = &self.service_name {
+            f.write_str(service_name)?;
+        }
+
+        if let Some(server) = self.server {
+            write!(f, ":{}", server)?;
+        }
+
+        if let Some(instance) = &self.instance {
+            write!(f, "/{}", instance)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An ODP.NET connection string.
+///
+/// The `Data Source` is a net service name of the tnsnames.ora file, an EZConnect
+/// such as `db1:1521/Sales` or a connect descriptor, which is often written on
+/// several lines and is kept as written.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::oracle::{DbaPrivilege, OracleConnStr};
+/// use std::str::FromStr;
+///
+/// let conn = OracleConnStr::from_str("Data Source=db1:1521/Sales;User Id=me;Password=secret;Connection Timeout=30;DBA Privilege=SYSDBA;Proxy User Id=app").unwrap();
+///
+/// assert_eq!(Some("db1:1521/Sales"), conn.data_source());
+/// assert_eq!(Some("me"), conn.user_id());
+/// assert_eq!(30, conn.connection_timeout().unwrap());
+/// assert_eq!(Some(DbaPrivilege::SysDba), conn.dba_privilege().unwrap());
+/// assert_eq!(Some("app"), conn.proxy_user_id());
+/// assert!(conn.pooling().unwrap());
+/// ```
+#[derive(Clone)]
+pub struct OracleConnStr(Arc<HashMap<String, String>>);
+
+impl FromStr for OracleConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl OracleConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        Ok(OracleConnStr(Arc::new(parse(
+            conn_str,
+            false,
+            &keywords::ORACLE,
+            options,
+        )?)))
+    }
+
+    /// Gets the number of seconds to wait for a connection from the pool, 15 when
+    /// missing.
+    pub fn connection_timeout(&self) -> Result<u32, Error> {
+        match self.get("connection timeout") {
+            Some(v) => v.trim().parse().map_err(|_| Error::InvalidValue {
+                key: "connection timeout".to_owned(),
+                value: v.to_owned(),
+                expected: keywords::ORACLE
+                    .get("connection timeout")
+                    .unwrap()
+                    .kind
+                    .to_string(),
+            }),
+            None => Ok(15),
+        }
+    }
+
+    /// Gets the net service name, the EZConnect or the connect descriptor of the
+    /// database.
+    pub fn data_source(&self) -> Option<&str> {
+        self.get("data source")
+    }
+
+    pub fn dba_privilege(&self) -> Result<Option<DbaPrivilege>, Error> {
+        self.get("dba privilege").map(str::parse).transpose()
+    }
+
+    /// Parses the data source when it is a connect descriptor, `None` when it is
+    /// missing, an EZConnect or a net service name.
+    pub fn descriptor(&self) -> Result<Option<Descriptor>, Error> {
+        match self.data_source().map(str::trim) {
+            Some(s) if s.starts_with('(') => s.parse().map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Parses the data source when it is an EZConnect, `None` when it is missing, a
+    /// connect descriptor or a net service name, written without a port nor a
+    /// service.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use conn_str::oracle::OracleConnStr;
+    /// use std::str::FromStr;
+    ///
+    /// let conn = OracleConnStr::from_str("Data Source=db1/Sales;User Id=me").unwrap();
+    /// let ez = conn.ez_connect().unwrap().unwrap();
+    ///
+    /// assert_eq!(("db1", None), (ez.host.as_str(), ez.port));
+    /// assert_eq!(Some("Sales"), ez.service_name.as_deref());
+    ///
+    /// let conn = OracleConnStr::from_str("Data Source=SALES_PROD").unwrap();
+    /// assert!(conn.ez_connect().unwrap().is_none());
+    /// ```
+    pub fn ez_connect(&self) -> Result<Option<EzConnect>, Error> {
+        match self.data_source().map(str::trim) {
+            Some(s) if !s.starts_with('(') && s.contains([':', '/']) => s.parse().map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    #[cfg(not(feature = "secret"))]
+    pub fn password(&self) -> Option<&str> {
+        self.get("password")
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("password").map(crate::secret::Secret::new)
+    }
+
+    pub fn pooling(&self) -> Result<bool, Error> {
+        match self.get("pooling") {
+            Some(v) => parse_bool(v),
+            None => Ok(true),
+        }
+    }
+
+    /// Gets the proxy user connecting on behalf of the [`user_id`](Self::user_id).
+    pub fn proxy_user_id(&self) -> Option<&str> {
+        self.get("proxy user id")
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.0, &keywords::ORACLE, false, false)
+    }
+
+    pub fn user_id(&self) -> Option<&str> {
+        self.get("user id")
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        get(&self.0, &keywords::ORACLE, name)
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.0, &keywords::ORACLE, validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for OracleConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(
+            &self.0,
+            &keywords::ORACLE,
+            false,
+            true,
+        ))
+    }
+}
+
+#[test]
+fn connect_descriptors_are_kept_as_data_source() {
+    let conn = OracleConnStr::from_str(
+        "Data Source=(DESCRIPTION=\n  (ADDRESS=(PROTOCOL=TCP)(HOST=db1)(PORT=1521))\n  (CONNECT_DATA=(SERVICE_NAME=Sales)));\nUser Id=me;Proxy Password=x",
+    )
+    .unwrap();
+
+    assert_eq!(
+        Some("(DESCRIPTION=\n  (ADDRESS=(PROTOCOL=TCP)(HOST=db1)(PORT=1521))\n  (CONNECT_DATA=(SERVICE_NAME=Sales)))"),
+        conn.data_source()
+    );
+    assert_eq!(Some("me"), conn.user_id());
+    assert_eq!(
+        Some("Sales"),
+        conn.descriptor()
+            .unwrap()
+            .unwrap()
+            .connect_data
+            .service_name
+            .as_deref()
+    );
+    assert_eq!(None, conn.dba_privilege().unwrap());
+    assert!(conn.to_string().ends_with("proxy password=***;user id=me"));
+}
+
+#[test]
+fn ez_connect_splits_its_parts() {
+    let ez: EzConnect = "[::1]:1521/sales:dedicated".parse().unwrap();
+
+    assert_eq!("::1", ez.host);
+    assert_eq!(Some(ServerType::Dedicated), ez.server);
+    assert_eq!(None, ez.instance);
+    assert_eq!("[::1]:1521/sales:dedicated", ez.to_string());
+
+    assert!("db1:1521/sales:direct".parse::<EzConnect>().is_err());
+    assert!("db1:port/sales".parse::<EzConnect>().is_err());
+}
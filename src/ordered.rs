@@ -0,0 +1,247 @@
+//! Editing of connection strings keeping their original text.
+//!
+//! An [`OrderedConnStr`] keeps the keys by order of appearance, with the text of
+//! each pair as written, so that editing a value produces a minimal change once the
+//! connection string is formatted again.
+
+use crate::{append_key_value, parse_key_value, Error, Pair};
+use std::fmt;
+
+/// A key and its value, with its original text until it is edited.
+#[derive(Clone)]
+struct Entry {
+    /// The key in lower case.
+    key: String,
+
+    /// The key as written, unescaped, formatted again once the value is edited.
+    name: String,
+
+    value: String,
+
+    /// The text of the pair as written, `None` once the value is edited.
+    text: Option<String>,
+}
+
+/// An editable connection string keeping the order of its keys.
+///
+/// The existing keys keep their position and their text, the new keys are appended
+/// at the end.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::ordered::OrderedConnStr;
+///
+/// let mut conn = OrderedConnStr::parse("Server = .; Password='old' ;Database=Db1", false).unwrap();
+///
+/// conn.set("password", "new;1");
+/// conn.set("Encrypt", "true");
+///
+/// assert_eq!(
+///     r#"Server = .;Password="new;1";Database=Db1;Encrypt=true"#,
+///     conn.to_connection_string(),
+/// );
+///
+/// conn.move_to("encrypt", 1);
+///
+/// assert_eq!(
+///     vec!["server", "encrypt", "password", "database"],
+///     conn.iter().map(|(k, _)| k).collect::<Vec<_>>(),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct OrderedConnStr {
+    entries: Vec<Entry>,
+    use_odbc_rules: bool,
+}
+
+impl OrderedConnStr {
+    /// Parses a connection string, keeping every key, including the repeated ones.
+    pub fn parse(conn_str: &str, use_odbc_rules: bool) -> Result<Self, Error> {
+        let mut chars = conn_str.char_indices();
+        let mut buf = String::new();
+        let mut entries = Vec::new();
+
+        while let Some(pair) = parse_key_value(&mut chars, &mut buf, use_odbc_rules)? {
+            let Pair {
+                key,
+                value,
+                key_index,
+                value_range,
+            } = pair;
+
+            if key.is_empty() {
+                break;
+            }
+
+            let name = conn_str[key_index..value_range.start].trim_end();
+            let name = name.strip_suffix('=').unwrap_or(name).trim_end();
+
+            // a `=` within a key is doubled, except with the ODBC rules
+            let name = match use_odbc_rules {
+                true => name.to_owned(),
+                false => name.replace("==", "="),
+            };
+
+            entries.push(Entry {
+                key,
+                name,
+                value,
+                text: Some(conn_str[key_index..value_range.end].trim_end().to_owned()),
+            });
+        }
+
+        Ok(OrderedConnStr {
+            entries,
+            use_odbc_rules,
+        })
+    }
+
+    /// Gets the value of the first occurrence of a key, ignoring case.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.position(key).map(|i| self.entries[i].value.as_str())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterates over the lower case keys and the values, by position.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries
+            .iter()
+            .map(|e| (e.key.as_str(), e.value.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Moves the first occurrence of a key to a position, shifting the following
+    /// keys, returning `false` when the key is missing.
+    ///
+    /// The key is moved to the end when the position is out of bounds.
+    pub fn move_to(&mut self, key: &str, index: usize) -> bool {
+        match self.position(key) {
+            Some(i) => {
+                let entry = self.entries.remove(i);
+                let index = index.min(self.entries.len());
+                self.entries.insert(index, entry);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes every occurrence of a key, returning the value of the first one.
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        let key = key.trim().to_lowercase();
+        let mut removed = None;
+
+        self.entries.retain(|e| {
+            if e.key != key {
+                return true;
+            }
+
+            removed.get_or_insert_with(|| e.value.clone());
+            false
+        });
+
+        removed
+    }
+
+    /// Sets the value of the first occurrence of a key, in place, or appends the key.
+    pub fn set(&mut self, key: &str, value: &str) {
+        match self.position(key) {
+            Some(i) => self.set_at(i, value),
+            None => self.entries.push(Entry {
+                key: key.trim().to_lowercase(),
+                name: key.trim().to_owned(),
+                value: value.to_owned(),
+                text: None,
+            }),
+        }
+    }
+
+    /// Formats the connection string, the unchanged keys keeping their text.
+    pub fn to_connection_string(&self) -> String {
+        let mut out = String::new();
+
+        for entry in &self.entries {
+            match &entry.text {
+                Some(text) => {
+                    if !out.is_empty() {
+                        out.push(';');
+                    }
+                    out.push_str(text);
+                }
+                None => append_key_value(&mut out, &entry.name, &entry.value, self.use_odbc_rules),
+            }
+        }
+
+        out
+    }
+
+    /// Sets the value of the entry at a position, such as a repeated key.
+    pub(crate) fn set_at(&mut self, index: usize, value: &str) {
+        let entry = &mut self.entries[index];
+
+        if entry.value != value {
+            entry.value = value.to_owned();
+            entry.text = None;
+        }
+    }
+
+    fn position(&self, key: &str) -> Option<usize> {
+        let key = key.trim().to_lowercase();
+        self.entries.iter().position(|e| e.key == key)
+    }
+}
+
+/// Formats the keys only, the values being possibly secrets of an unknown format.
+impl fmt::Debug for OrderedConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map()
+            .entries(self.entries.iter().map(|e| (&e.key, "***")))
+            .finish()
+    }
+}
+
+#[test]
+fn unchanged_pairs_keep_their_text() {
+    let text = "Driver={SQL Server};PWD={a;b};UID=me;uid=other";
+    let mut conn = OrderedConnStr::parse(text, true).unwrap();
+
+    assert_eq!(text, conn.to_connection_string());
+    assert_eq!(Some("me"), conn.get("UID"));
+
+    conn.set("pwd", "{c}");
+    assert_eq!(Some("me".to_owned()), conn.remove("uid"));
+    assert_eq!("Driver={SQL Server};PWD={c}", conn.to_connection_string());
+}
+
+#[test]
+fn escaped_keys_are_escaped_once() {
+    let mut conn = OrderedConnStr::parse("A==B=1;c=2", false).unwrap();
+
+    assert_eq!(Some("1"), conn.get("a=b"));
+
+    conn.set("a=b", "9");
+    assert_eq!("A==B=9;c=2", conn.to_connection_string());
+
+    let conn = OrderedConnStr::parse(&conn.to_connection_string(), false).unwrap();
+    assert_eq!(
+        vec![("a=b", "9"), ("c", "2")],
+        conn.iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn debug_masks_the_values() {
+    let conn = OrderedConnStr::parse("Server=.;Password=Pass123", false).unwrap();
+
+    assert_eq!(
+        r#"{"server": "***", "password": "***"}"#,
+        format!("{:?}", conn)
+    );
+}
@@ -0,0 +1,109 @@
+//! Percent-encoding of the parts of the URL-style connection strings.
+
+use crate::Error;
+use std::borrow::Cow;
+
+/// The part of a URL a value is encoded for, each one keeping its own set of
+/// characters unencoded.
+///
+/// The unreserved characters, the ASCII letters and digits with `-`, `.`, `_` and `~`,
+/// are never encoded.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Component {
+    /// The user name or the password before the `@`, every other character being
+    /// encoded.
+    UserInfo,
+
+    /// The path, such as a database name or a file path, keeping `/`, `:` and `@`.
+    Path,
+
+    /// A key or a value of the query, keeping `/`, `:`, `@` and `,`.
+    Query,
+}
+
+impl Component {
+    fn keeps(self, b: u8) -> bool {
+        let unreserved = b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~');
+
+        unreserved
+            || match self {
+                Component::UserInfo => false,
+                Component::Path => matches!(b, b'/' | b':' | b'@'),
+                Component::Query => matches!(b, b'/' | b':' | b'@' | b','),
+            }
+    }
+}
+
+/// Encodes a value for a part of a URL, the other characters being written as `%XX`
+/// escapes of their UTF-8 bytes.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::percent::{decode, encode, Component};
+///
+/// assert_eq!("p%40ss%3Aw%2Frd", encode("p@ss:w/rd", Component::UserInfo));
+/// assert_eq!("data/my%20db", encode("data/my db", Component::Path));
+/// assert_eq!("dc:ny,rack:1", encode("dc:ny,rack:1", Component::Query));
+/// assert_eq!("p@ss:w/rd", decode("p%40ss%3Aw%2Frd").unwrap());
+/// ```
+pub fn encode(value: &str, component: Component) -> Cow<'_, str> {
+    if value.bytes().all(|b| component.keeps(b)) {
+        return Cow::Borrowed(value);
+    }
+
+    let mut out = String::with_capacity(value.len() + 8);
+
+    for b in value.bytes() {
+        if component.keeps(b) {
+            out.push(b as char);
+        } else {
+            out.push_str(&format!("%{:02X}", b));
+        }
+    }
+
+    Cow::Owned(out)
+}
+
+/// Decodes the `%XX` escapes of a value, failing on an incomplete escape or on
+/// escaped bytes which are not UTF-8.
+///
+/// A `+` is kept as is, it stands for a space only in the HTML forms.
+pub fn decode(value: &str) -> Result<Cow<'_, str>, Error> {
+    if !value.contains('%') {
+        return Ok(Cow::Borrowed(value));
+    }
+
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if bytes[index] == b'%' {
+            let byte = value
+                .get(index + 1..index + 3)
+                .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                .ok_or(Error::SyntaxError(index))?;
+
+            out.push(byte);
+            index += 3;
+        } else {
+            out.push(bytes[index]);
+            index += 1;
+        }
+    }
+
+    String::from_utf8(out)
+        .map(Cow::Owned)
+        .map_err(|e| Error::InvalidEncoding(e.utf8_error().valid_up_to()))
+}
+
+#[test]
+fn decode_rejects_incomplete_escapes_and_invalid_utf8() {
+    assert_eq!("é+a", decode("%C3%A9+a").unwrap());
+    assert_eq!("%C3%A9", encode("é", Component::Query));
+    assert!(matches!(decode("ab%2"), Err(Error::SyntaxError(2))));
+    assert!(matches!(decode("a%+1"), Err(Error::SyntaxError(1))));
+    assert!(matches!(decode("a%FF"), Err(Error::InvalidEncoding(1))));
+}
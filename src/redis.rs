@@ -0,0 +1,210 @@
+//! Redis configuration strings, as parsed by StackExchange.Redis.
+
+use crate::host::Host;
+use crate::keywords;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{decode_bytes, get, parse_bool, parse_pairs, Error, ParseOptions};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// A StackExchange.Redis configuration string, such as
+/// `host1:6379,host2:6380,password=secret,ssl=true,abortConnect=false`.
+///
+/// The items are separated by commas, the ones without `=` being the endpoints of the
+/// servers. The options are compared ignoring case and are stored in lower case.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::host::Host;
+/// use conn_str::redis::RedisConnStr;
+/// use std::str::FromStr;
+///
+/// let conn = RedisConnStr::from_str("host1:6379,host2:6380,password=secret,ssl=true,abortConnect=false,defaultDatabase=2").unwrap();
+///
+/// assert_eq!(&[Host::new("host1", Some(6379)), Host::new("host2", Some(6380))], conn.endpoints());
+/// assert!(conn.ssl().unwrap());
+/// assert!(!conn.abort_connect().unwrap());
+/// assert_eq!(Some(2), conn.default_database().unwrap());
+/// assert_eq!(5000, conn.connect_timeout().unwrap());
+/// assert_eq!("host1:6379,host2:6380,abortconnect=false,defaultdatabase=2,password=***,ssl=true", conn.to_string());
+/// ```
+#[derive(Clone)]
+pub struct RedisConnStr {
+    endpoints: Arc<Vec<Host>>,
+    map: Arc<HashMap<String, String>>,
+}
+
+impl FromStr for RedisConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl RedisConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the configuration string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        let mut endpoints = Vec::new();
+        let mut pairs = Vec::new();
+        let mut index = 0;
+
+        for item in conn_str.split(',') {
+            let start = index + item.len() - item.trim_start().len();
+            index += item.len() + 1;
+
+            match item.split_once('=') {
+                Some((key, value)) => {
+                    pairs.push((key.trim().to_owned(), value.trim().to_owned(), start));
+                }
+                None if item.trim().is_empty() => {}
+                None => endpoints.push(item.parse()?),
+            }
+        }
+
+        Ok(RedisConnStr {
+            endpoints: Arc::new(endpoints),
+            map: Arc::new(parse_pairs(conn_str, pairs, &keywords::REDIS, options)?),
+        })
+    }
+
+    /// Indicates if connecting fails when no server is available, `true` when missing,
+    /// instead of retrying in the background.
+    pub fn abort_connect(&self) -> Result<bool, Error> {
+        self.bool_or("abortconnect", true)
+    }
+
+    /// Gets the number of milliseconds to wait for a connection to open, 5000 when
+    /// missing.
+    pub fn connect_timeout(&self) -> Result<u32, Error> {
+        Ok(self.int("connecttimeout")?.unwrap_or(5000))
+    }
+
+    /// Gets the database used when none is given, the database 0 being used when
+    /// missing.
+    pub fn default_database(&self) -> Result<Option<u32>, Error> {
+        self.int("defaultdatabase")
+    }
+
+    /// Gets the endpoints of the servers, in the order they are written.
+    pub fn endpoints(&self) -> &[Host] {
+        &self.endpoints
+    }
+
+    /// Gets an option, ignoring case.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        get(&self.map, &keywords::REDIS, name).or_else(|| {
+            self.map
+                .get(&name.trim().to_lowercase())
+                .map(|s| s.as_str())
+        })
+    }
+
+    #[cfg(not(feature = "secret"))]
+    pub fn password(&self) -> Option<&str> {
+        self.get("password")
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("password").map(crate::secret::Secret::new)
+    }
+
+    pub fn ssl(&self) -> Result<bool, Error> {
+        self.bool_or("ssl", false)
+    }
+
+    /// Formats the configuration string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        self.to_connection_string(false)
+    }
+
+    pub fn user(&self) -> Option<&str> {
+        self.get("user")
+    }
+
+    fn bool_or(&self, name: &str, default: bool) -> Result<bool, Error> {
+        match self.get(name) {
+            Some(v) => parse_bool(v),
+            None => Ok(default),
+        }
+    }
+
+    fn int(&self, name: &str) -> Result<Option<u32>, Error> {
+        match self.get(name) {
+            Some(v) => v.trim().parse().map(Some).map_err(|_| Error::InvalidValue {
+                key: name.to_owned(),
+                value: v.to_owned(),
+                expected: keywords::REDIS.get(name).unwrap().kind.to_string(),
+            }),
+            None => Ok(None),
+        }
+    }
+
+    /// Formats the endpoints followed by the options sorted by name.
+    fn to_connection_string(&self, redact: bool) -> String {
+        let mut items: Vec<_> = self.endpoints.iter().map(Host::to_string).collect();
+        let mut keys: Vec<_> = self.map.keys().collect();
+
+        keys.sort();
+
+        for key in keys {
+            let secret = keywords::REDIS.find(key).is_some_and(|k| k.is_sensitive());
+
+            match secret && redact {
+                true => items.push(format!("{}=***", key)),
+                false => items.push(format!("{}={}", key, self.map[key])),
+            }
+        }
+
+        items.join(",")
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.map, &keywords::REDIS, validator)
+    }
+}
+
+/// Formats the configuration string with its secrets redacted.
+impl fmt::Display for RedisConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_connection_string(true))
+    }
+}
+
+#[test]
+fn endpoints_are_mixed_with_options() {
+    let conn = RedisConnStr::from_str(" cache.example.net:6380 , Password=a=b ,, [::1] ").unwrap();
+
+    assert_eq!(
+        &[
+            Host::new("cache.example.net", Some(6380)),
+            Host::new("::1", None)
+        ],
+        conn.endpoints()
+    );
+    assert_eq!(
+        "cache.example.net:6380,[::1],password=a=b",
+        conn.to_connection_string_with_secrets()
+    );
+    assert!(conn.abort_connect().unwrap());
+
+    let conn = RedisConnStr::from_str("localhost,connectTimeout=soon").unwrap();
+    assert!(conn.connect_timeout().is_err());
+    assert!(RedisConnStr::from_str("localhost:port").is_err());
+}
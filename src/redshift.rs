@@ -0,0 +1,276 @@
+//! Amazon Redshift connection strings, as passed to the Redshift ODBC driver or to the
+//! .NET providers.
+
+use crate::keywords;
+use crate::pg::SslMode;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{
+    decode_bytes, get, parse, parse_bool, parse_port, to_connection_string, Error, ParseOptions,
+};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// A Redshift connection string, parsed with the ODBC rules where a value is quoted
+/// with braces.
+///
+/// With `IAM=1`, the password is replaced by temporary credentials, requested for the
+/// `DbUser` of the cluster `ClusterID` in the `Region`. Both are read from the
+/// endpoint, `<cluster>.<id>.<region>.redshift.amazonaws.com`, when missing.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::redshift::RedshiftConnStr;
+/// use std::str::FromStr;
+///
+/// let conn = RedshiftConnStr::from_str("Server=cluster.x.redshift.amazonaws.com;Port=5439;Database=dev;UID=u;PWD=p;IAM=1;ClusterID=c;Region=us-east-1").unwrap();
+///
+/// assert!(conn.iam().unwrap());
+/// assert_eq!(Some("c"), conn.cluster_id());
+/// assert_eq!(Some("us-east-1"), conn.region());
+/// assert_eq!(Some(5439), conn.port().unwrap());
+/// assert_eq!(Some("dev"), conn.database());
+///
+/// let conn = RedshiftConnStr::from_str("Driver={Amazon Redshift (x64)};Server=sales.abc123.eu-west-1.redshift.amazonaws.com;IAM=1;DbUser=me;DbGroups=a,b").unwrap();
+///
+/// assert_eq!(Some("sales"), conn.cluster_id());
+/// assert_eq!(Some("eu-west-1"), conn.region());
+/// assert_eq!(vec!["a", "b"], conn.db_groups());
+/// ```
+#[derive(Clone)]
+pub struct RedshiftConnStr(Arc<HashMap<String, String>>);
+
+impl FromStr for RedshiftConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl RedshiftConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        Ok(RedshiftConnStr(Arc::new(parse(
+            conn_str,
+            true,
+            &keywords::REDSHIFT,
+            options,
+        )?)))
+    }
+
+    /// Gets the access key of the IAM user or role.
+    pub fn access_key_id(&self) -> Option<&str> {
+        self.get("accesskeyid")
+    }
+
+    /// Indicates if the `DbUser` is created when it does not exist.
+    pub fn auto_create(&self) -> Result<bool, Error> {
+        self.flag("autocreate")
+    }
+
+    /// Gets the identifier of the cluster, the first label of the endpoint when
+    /// missing.
+    pub fn cluster_id(&self) -> Option<&str> {
+        self.get("clusterid")
+            .or_else(|| self.endpoint_labels().map(|labels| labels[0]))
+    }
+
+    pub fn database(&self) -> Option<&str> {
+        self.get("database")
+    }
+
+    /// Gets the groups joined by the `DbUser` for the session, empty when missing.
+    pub fn db_groups(&self) -> Vec<&str> {
+        match self.get("dbgroups") {
+            Some(groups) => groups
+                .split(',')
+                .map(str::trim)
+                .filter(|g| !g.is_empty())
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Gets the database user of the IAM credentials, the `UID` being used when
+    /// missing.
+    pub fn db_user(&self) -> Option<&str> {
+        self.get("dbuser")
+    }
+
+    /// Indicates if the connection authenticates with the temporary credentials of
+    /// IAM, `IAM=1`.
+    pub fn iam(&self) -> Result<bool, Error> {
+        self.flag("iam")
+    }
+
+    #[cfg(not(feature = "secret"))]
+    pub fn password(&self) -> Option<&str> {
+        self.get("pwd")
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("pwd").map(crate::secret::Secret::new)
+    }
+
+    /// Gets the credentials provider of a federated identity, such as `AzureAD` or
+    /// `Okta`.
+    pub fn plugin_name(&self) -> Option<&str> {
+        self.get("plugin_name")
+    }
+
+    /// Gets the port, `None` for the default port 5439.
+    pub fn port(&self) -> Result<Option<u16>, Error> {
+        match self.get("port") {
+            Some(port) => parse_port("port", port).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Gets the profile of the AWS credentials file holding the access keys.
+    pub fn profile(&self) -> Option<&str> {
+        self.get("profile")
+    }
+
+    /// Gets the AWS region of the cluster, the third label of the endpoint when
+    /// missing.
+    pub fn region(&self) -> Option<&str> {
+        self.get("region")
+            .or_else(|| self.endpoint_labels().map(|labels| labels[2]))
+    }
+
+    /// Gets the secret access key of the IAM user or role.
+    #[cfg(not(feature = "secret"))]
+    pub fn secret_access_key(&self) -> Option<&str> {
+        self.get("secretaccesskey")
+    }
+
+    /// Gets the secret access key of the IAM user or role.
+    #[cfg(feature = "secret")]
+    pub fn secret_access_key(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("secretaccesskey").map(crate::secret::Secret::new)
+    }
+
+    /// Gets the endpoint of the cluster.
+    pub fn server(&self) -> Option<&str> {
+        self.get("server")
+    }
+
+    /// Gets the `SSLMode`, `verify-ca` when missing.
+    pub fn ssl_mode(&self) -> Result<SslMode, Error> {
+        match self.get("sslmode") {
+            Some(mode) => mode.parse(),
+            None => Ok(SslMode::VerifyCa),
+        }
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.0, &keywords::REDSHIFT, true, false)
+    }
+
+    pub fn uid(&self) -> Option<&str> {
+        self.get("uid")
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        get(&self.0, &keywords::REDSHIFT, name)
+    }
+
+    /// Splits a `<cluster>.<id>.<region>.redshift.amazonaws.com` endpoint into its
+    /// labels, `None` for the other servers.
+    fn endpoint_labels(&self) -> Option<Vec<&str>> {
+        const SUFFIX: &str = ".redshift.amazonaws.com";
+
+        let server = self.server()?.trim();
+        let split = server.len().checked_sub(SUFFIX.len())?;
+
+        if !server.is_char_boundary(split) || !server[split..].eq_ignore_ascii_case(SUFFIX) {
+            return None;
+        }
+
+        let labels: Vec<&str> = server[..split].split('.').collect();
+
+        match labels.len() == 3 && labels.iter().all(|l| !l.is_empty()) {
+            true => Some(labels),
+            false => None,
+        }
+    }
+
+    /// Reads a boolean written `1` or `0` by the ODBC driver, or `true` or `false`.
+    fn flag(&self, name: &str) -> Result<bool, Error> {
+        match self.get(name).map(str::trim) {
+            Some("1") => Ok(true),
+            Some("0") => Ok(false),
+            Some(v) => parse_bool(v),
+            None => Ok(false),
+        }
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.0, &keywords::REDSHIFT, validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for RedshiftConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(
+            &self.0,
+            &keywords::REDSHIFT,
+            true,
+            true,
+        ))
+    }
+}
+
+#[test]
+fn iam_fields_are_read_from_the_endpoint() {
+    let conn = RedshiftConnStr::from_str(
+        "Host=db.internal;IAM=true;AccessKeyID=AKIA;SecretAccessKey={a;b};AutoCreate=0",
+    )
+    .unwrap();
+
+    assert!(conn.iam().unwrap());
+    assert!(!conn.auto_create().unwrap());
+    assert_eq!(None, conn.cluster_id());
+    assert_eq!(None, conn.region());
+    assert_eq!(Some("AKIA"), conn.access_key_id());
+    assert_eq!(
+        "accesskeyid=AKIA;autocreate=0;host=db.internal;iam=true;secretaccesskey=***",
+        conn.to_string()
+    );
+
+    assert!(RedshiftConnStr::from_str("IAM=2").unwrap().iam().is_err());
+}
+
+#[test]
+fn flags_written_as_numbers_are_valid() {
+    let conn = RedshiftConnStr::from_str("Server=c;IAM=1;AutoCreate=0;DbUser=me").unwrap();
+
+    assert!(conn.iam().unwrap());
+    assert!(conn.validate().is_empty());
+    assert!(RedshiftConnStr::parse_with(
+        "Server=c;IAM=1",
+        &ParseOptions {
+            strict: true,
+            ..Default::default()
+        }
+    )
+    .is_ok());
+}
@@ -0,0 +1,447 @@
+//! References to secrets stored outside of the connection strings.
+//!
+//! A value such as `@Microsoft.KeyVault(SecretUri=...)` stands for a secret held by
+//! a vault. The references are found in the values of a connection string and
+//! resolved on demand, replacing each of them by its secret.
+
+use crate::ordered::OrderedConnStr;
+use crate::{parse_key_value, Error, Pair};
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+
+const KEY_VAULT: &str = "@microsoft.keyvault(";
+
+/// A custom syntax of the references, the identifier of the secret being enclosed by
+/// a prefix and a suffix, such as `${secret:` and `}`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferenceSyntax {
+    pub prefix: String,
+    pub suffix: String,
+}
+
+impl ReferenceSyntax {
+    pub fn new(prefix: &str, suffix: &str) -> Self {
+        ReferenceSyntax {
+            prefix: prefix.to_owned(),
+            suffix: suffix.to_owned(),
+        }
+    }
+}
+
+/// A reference to a secret, found in place of a value.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::reference::SecretReference;
+///
+/// let reference =
+///     SecretReference::parse("@Microsoft.KeyVault(VaultName=vault1;SecretName=DbPassword)")
+///         .unwrap()
+///         .unwrap();
+///
+/// assert_eq!(
+///     Some("https://vault1.vault.azure.net/secrets/DbPassword".to_owned()),
+///     reference.secret_uri(),
+/// );
+///
+/// assert!(SecretReference::parse("Pass123").unwrap().is_none());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SecretReference {
+    /// An Azure Key Vault reference, using the `SecretUri` or the `VaultName` and
+    /// `SecretName` forms. The vault is the url of the vault.
+    KeyVault {
+        vault: String,
+        secret: String,
+        version: Option<String>,
+    },
+
+    /// A reference of a custom syntax, identified by the prefix of its syntax.
+    Custom { prefix: String, id: String },
+}
+
+impl SecretReference {
+    /// Parses an Azure Key Vault reference, returning `None` when the value is not a
+    /// reference.
+    pub fn parse(value: &str) -> Result<Option<Self>, Error> {
+        Self::parse_with(value, &[])
+    }
+
+    /// Parses an Azure Key Vault reference or a reference of one of the custom
+    /// syntaxes, returning `None` when the value is not a reference.
+    pub fn parse_with(value: &str, syntaxes: &[ReferenceSyntax]) -> Result<Option<Self>, Error> {
+        let value = value.trim();
+
+        if value
+            .get(..KEY_VAULT.len())
+            .is_some_and(|p| p.eq_ignore_ascii_case(KEY_VAULT))
+        {
+            return key_vault(value)
+                .map(Some)
+                .ok_or_else(|| Error::InvalidValue {
+                    key: "@Microsoft.KeyVault".to_owned(),
+                    value: value.to_owned(),
+                    expected: "`@Microsoft.KeyVault(SecretUri=...)` or \
+                           `@Microsoft.KeyVault(VaultName=...;SecretName=...)`"
+                        .to_owned(),
+                });
+        }
+
+        Ok(syntaxes.iter().find_map(|s| {
+            let id = value.strip_prefix(&*s.prefix)?.strip_suffix(&*s.suffix)?;
+
+            Some(SecretReference::Custom {
+                prefix: s.prefix.clone(),
+                id: id.to_owned(),
+            })
+        }))
+    }
+
+    /// The uri of a Key Vault secret, `None` for a custom reference.
+    pub fn secret_uri(&self) -> Option<String> {
+        match self {
+            SecretReference::KeyVault {
+                vault,
+                secret,
+                version,
+            } => Some(match version {
+                Some(version) => format!("{}/secrets/{}/{}", vault, secret, version),
+                None => format!("{}/secrets/{}", vault, secret),
+            }),
+            SecretReference::Custom { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for SecretReference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SecretReference::KeyVault { .. } => write!(
+                f,
+                "@Microsoft.KeyVault(SecretUri={})",
+                self.secret_uri().unwrap_or_default()
+            ),
+            SecretReference::Custom { prefix, id } => write!(f, "{}{}", prefix, id),
+        }
+    }
+}
+
+fn key_vault(value: &str) -> Option<SecretReference> {
+    let inner = value[KEY_VAULT.len()..].strip_suffix(')')?;
+    let mut chars = inner.char_indices();
+    let mut buf = String::new();
+    let mut params = HashMap::new();
+
+    while let Some(Pair { key, value, .. }) = parse_key_value(&mut chars, &mut buf, false).ok()? {
+        if key.is_empty() {
+            break;
+        }
+        params.insert(key, value.trim().to_owned());
+    }
+
+    if let Some(uri) = params.get("secreturi") {
+        let (vault, rest) = uri.split_once("/secrets/")?;
+        let mut parts = rest.trim_end_matches('/').splitn(2, '/');
+        let secret = parts.next().filter(|s| !s.is_empty())?;
+
+        return Some(SecretReference::KeyVault {
+            vault: vault.trim_end_matches('/').to_owned(),
+            secret: secret.to_owned(),
+            version: parts.next().map(str::to_owned),
+        })
+        .filter(|_| vault.starts_with("https://"));
+    }
+
+    let vault = params.get("vaultname").filter(|s| !s.is_empty())?;
+    let secret = params.get("secretname").filter(|s| !s.is_empty())?;
+
+    Some(SecretReference::KeyVault {
+        vault: format!("https://{}.vault.azure.net", vault),
+        secret: secret.clone(),
+        version: params
+            .get("secretversion")
+            .filter(|s| !s.is_empty())
+            .cloned(),
+    })
+}
+
+/// Finds the references in the values of a connection string, with their lower case
+/// key.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::reference::{find, ReferenceSyntax, SecretReference};
+///
+/// let syntaxes = [ReferenceSyntax::new("${secret:", "}")];
+/// let conn = "server=.;user id=${secret:db-user};password=\"@Microsoft.KeyVault(SecretUri=https://v1.vault.azure.net/secrets/pwd/)\"";
+///
+/// let references = find(conn, false, &syntaxes).unwrap();
+///
+/// assert_eq!("user id", references[0].0);
+/// assert_eq!(
+///     SecretReference::Custom { prefix: "${secret:".to_owned(), id: "db-user".to_owned() },
+///     references[0].1,
+/// );
+/// assert_eq!("password", references[1].0);
+/// ```
+pub fn find(
+    conn_str: &str,
+    use_odbc_rules: bool,
+    syntaxes: &[ReferenceSyntax],
+) -> Result<Vec<(String, SecretReference)>, Error> {
+    let conn = OrderedConnStr::parse(conn_str, use_odbc_rules)?;
+
+    Ok(references(&conn, use_odbc_rules, syntaxes)?
+        .into_iter()
+        .map(|(_, key, reference)| (key, reference))
+        .collect())
+}
+
+/// Finds the references of the entries, with the position of their entry, since a
+/// key may be repeated.
+fn references(
+    conn: &OrderedConnStr,
+    use_odbc_rules: bool,
+    syntaxes: &[ReferenceSyntax],
+) -> Result<Vec<(usize, String, SecretReference)>, Error> {
+    let mut references = Vec::new();
+
+    for (i, (key, value)) in conn.iter().enumerate() {
+        // the ODBC values keep their braces
+        let value = match value.strip_prefix('{').and_then(|v| v.strip_suffix('}')) {
+            Some(inner) if use_odbc_rules => inner,
+            _ => value,
+        };
+
+        let reference =
+            SecretReference::parse_with(value, syntaxes).map_err(|e| with_key(e, key))?;
+
+        if let Some(reference) = reference {
+            references.push((i, key.to_owned(), reference));
+        }
+    }
+
+    Ok(references)
+}
+
+/// A store of secrets, such as a vault or the environment, resolving the references.
+///
+/// It is implemented by the functions taking a reference and returning the secret or
+/// an error message.
+pub trait SecretResolver {
+    fn resolve(&self, reference: &SecretReference) -> Result<String, String>;
+}
+
+impl<F> SecretResolver for F
+where
+    F: Fn(&SecretReference) -> Result<String, String>,
+{
+    fn resolve(&self, reference: &SecretReference) -> Result<String, String> {
+        self(reference)
+    }
+}
+
+/// The future of a secret returned by an [`AsyncSecretResolver`].
+#[cfg(feature = "async")]
+pub type SecretFuture<'a> = Pin<Box<dyn Future<Output = Result<String, String>> + Send + 'a>>;
+
+/// A store of secrets accessed asynchronously, such as a vault queried over the
+/// network.
+///
+/// It is implemented by the functions taking a reference and returning a future of
+/// the secret.
+#[cfg(feature = "async")]
+pub trait AsyncSecretResolver {
+    fn resolve<'a>(&'a self, reference: &'a SecretReference) -> SecretFuture<'a>;
+}
+
+#[cfg(feature = "async")]
+impl<F, Fut> AsyncSecretResolver for F
+where
+    F: Fn(&SecretReference) -> Fut,
+    Fut: Future<Output = Result<String, String>> + Send + 'static,
+{
+    fn resolve<'a>(&'a self, reference: &'a SecretReference) -> SecretFuture<'a> {
+        Box::pin(self(reference))
+    }
+}
+
+/// Resolves the custom references from the environment variables named by their
+/// identifier, such as `${env:DB_PASSWORD}`.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::reference::{resolve, EnvironmentResolver, ReferenceSyntax};
+///
+/// std::env::set_var("DB_PASSWORD", "Pass123");
+///
+/// let syntaxes = [ReferenceSyntax::new("${env:", "}")];
+/// let conn = resolve("server=.;password=${env:DB_PASSWORD}", false, &syntaxes, EnvironmentResolver)
+///     .unwrap();
+///
+/// assert_eq!("server=.;password=Pass123", conn);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EnvironmentResolver;
+
+impl SecretResolver for EnvironmentResolver {
+    fn resolve(&self, reference: &SecretReference) -> Result<String, String> {
+        match reference {
+            SecretReference::Custom { id, .. } => {
+                env::var(id).map_err(|e| format!("`{}`: {}", id, e))
+            }
+            SecretReference::KeyVault { .. } => {
+                Err("Key Vault references are not resolved from the environment".to_owned())
+            }
+        }
+    }
+}
+
+/// Replaces the references of a connection string by the secrets of the resolver,
+/// keeping the text of the other pairs as written, without the whitespace around the
+/// `;` separators.
+///
+/// The resolver is called once per reference, a failure being reported as
+/// [`Error::SecretUnavailable`].
+///
+/// # Example
+///
+/// ```
+/// use conn_str::reference::{resolve, ReferenceSyntax, SecretReference};
+///
+/// let syntaxes = [ReferenceSyntax::new("${secret:", "}")];
+/// let conn = "Server=.; Password=${secret:db}";
+///
+/// let resolved = resolve(conn, false, &syntaxes, |reference: &SecretReference| match reference {
+///     SecretReference::Custom { id, .. } if id == "db" => Ok("Pass;1".to_owned()),
+///     _ => Err("unknown secret".to_owned()),
+/// })
+/// .unwrap();
+///
+/// assert_eq!("Server=.;Password=\"Pass;1\"", resolved);
+/// ```
+pub fn resolve<R: SecretResolver>(
+    conn_str: &str,
+    use_odbc_rules: bool,
+    syntaxes: &[ReferenceSyntax],
+    resolver: R,
+) -> Result<String, Error> {
+    let mut conn = OrderedConnStr::parse(conn_str, use_odbc_rules)?;
+
+    for (i, key, reference) in references(&conn, use_odbc_rules, syntaxes)? {
+        let secret = resolver
+            .resolve(&reference)
+            .map_err(|message| unavailable(&key, message))?;
+        conn.set_at(i, &secret);
+    }
+
+    Ok(conn.to_connection_string())
+}
+
+/// Replaces the references of a connection string like [`resolve`], with a resolver
+/// returning futures, the references being resolved one after the other.
+#[cfg(feature = "async")]
+pub async fn resolve_async<R: AsyncSecretResolver>(
+    conn_str: &str,
+    use_odbc_rules: bool,
+    syntaxes: &[ReferenceSyntax],
+    resolver: R,
+) -> Result<String, Error> {
+    let mut conn = OrderedConnStr::parse(conn_str, use_odbc_rules)?;
+
+    for (i, key, reference) in references(&conn, use_odbc_rules, syntaxes)? {
+        let secret = resolver
+            .resolve(&reference)
+            .await
+            .map_err(|message| unavailable(&key, message))?;
+        conn.set_at(i, &secret);
+    }
+
+    Ok(conn.to_connection_string())
+}
+
+fn unavailable(key: &str, message: String) -> Error {
+    Error::SecretUnavailable {
+        key: key.to_owned(),
+        message,
+    }
+}
+
+fn with_key(error: Error, key: &str) -> Error {
+    match error {
+        Error::InvalidValue {
+            value, expected, ..
+        } => Error::InvalidValue {
+            key: key.to_owned(),
+            value,
+            expected,
+        },
+        e => e,
+    }
+}
+
+#[test]
+fn key_vault_references_are_parsed_or_rejected() {
+    let reference = SecretReference::parse(
+        "@Microsoft.KeyVault(SecretUri=https://v1.vault.azure.net/secrets/pwd/ec96f02080254f109c51a1f14cdb1931)",
+    )
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(
+        SecretReference::KeyVault {
+            vault: "https://v1.vault.azure.net".to_owned(),
+            secret: "pwd".to_owned(),
+            version: Some("ec96f02080254f109c51a1f14cdb1931".to_owned()),
+        },
+        reference
+    );
+
+    match find("pwd=@Microsoft.KeyVault(SecretName=pwd)", true, &[]) {
+        Err(Error::InvalidValue { key, .. }) => assert_eq!("pwd", key),
+        _ => panic!("the reference should be rejected"),
+    }
+}
+
+#[test]
+fn repeated_keys_are_resolved_where_they_reference() {
+    let syntaxes = [ReferenceSyntax::new("${s:", "}")];
+    let conn = resolve(
+        "pwd=x;pwd=${s:a}",
+        false,
+        &syntaxes,
+        |_: &SecretReference| Ok("SECRET".to_owned()),
+    )
+    .unwrap();
+
+    assert_eq!("pwd=x;pwd=SECRET", conn);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn resolve_async_reports_the_unavailable_secrets() {
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    let conn = "server=.;pwd={@Microsoft.KeyVault(VaultName=v1;SecretName=pwd)}";
+    let future = resolve_async(conn, true, &[], |r: &SecretReference| {
+        let uri = r.secret_uri().unwrap_or_default();
+        async move { Err(uri) }
+    });
+
+    match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(Err(Error::SecretUnavailable { key, message })) => {
+            assert_eq!("pwd", key);
+            assert_eq!("https://v1.vault.azure.net/secrets/pwd", message);
+        }
+        _ => panic!("the secret should be unavailable"),
+    }
+}
@@ -0,0 +1,163 @@
+//! Connection strings stored as values of the Windows registry.
+//!
+//! Only the `REG_SZ` and `REG_EXPAND_SZ` values hold connection strings, the
+//! environment variables of the latter being expanded before parsing.
+
+use crate::set::ConnStrSet;
+use crate::{AnyConnStr, Error, ParseOptions};
+use std::env;
+use winreg::enums::{HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE, REG_EXPAND_SZ, REG_SZ};
+use winreg::{RegKey, RegValue};
+
+/// A root key of the registry.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Hive {
+    CurrentUser,
+    LocalMachine,
+}
+
+impl Hive {
+    fn name(self) -> &'static str {
+        match self {
+            Hive::CurrentUser => "HKEY_CURRENT_USER",
+            Hive::LocalMachine => "HKEY_LOCAL_MACHINE",
+        }
+    }
+
+    fn open(self, path: &str) -> Result<RegKey, Error> {
+        let root = RegKey::predef(match self {
+            Hive::CurrentUser => HKEY_CURRENT_USER,
+            Hive::LocalMachine => HKEY_LOCAL_MACHINE,
+        });
+
+        root.open_subkey(path)
+            .map_err(|e| unreadable(self, path, None, e.to_string()))
+    }
+}
+
+/// Reads a string value of a key, expanding the environment variables of a
+/// `REG_EXPAND_SZ` value.
+pub fn read_value(hive: Hive, path: &str, name: &str) -> Result<String, Error> {
+    let value = hive
+        .open(path)?
+        .get_raw_value(name)
+        .map_err(|e| unreadable(hive, path, Some(name), e.to_string()))?;
+
+    string_value(&value)
+        .ok_or_else(|| unreadable(hive, path, Some(name), "not a string value".to_owned()))
+}
+
+/// Reads a value of a key and parses it, detecting the format of the connection
+/// string.
+///
+/// # Example
+///
+/// ```no_run
+/// use conn_str::registry::{read, Hive};
+/// use conn_str::ParseOptions;
+///
+/// let conn = read(Hive::LocalMachine, r"SOFTWARE\MyApp", "Db", &ParseOptions::default());
+/// ```
+pub fn read(
+    hive: Hive,
+    path: &str,
+    name: &str,
+    options: &ParseOptions,
+) -> Result<AnyConnStr, Error> {
+    AnyConnStr::parse_with(&read_value(hive, path, name)?, options)
+}
+
+/// Reads every string value of a key as a named connection string, ignoring the
+/// values of the other types.
+pub fn read_key(hive: Hive, path: &str, options: &ParseOptions) -> Result<ConnStrSet, Error> {
+    let key = hive.open(path)?;
+    let mut set = ConnStrSet::new();
+
+    for item in key.enum_values() {
+        let (name, value) = item.map_err(|e| unreadable(hive, path, None, e.to_string()))?;
+
+        if let Some(value) = string_value(&value) {
+            set.insert(&name, AnyConnStr::parse_with(&value, options)?);
+        }
+    }
+
+    Ok(set)
+}
+
+fn string_value(value: &RegValue) -> Option<String> {
+    if value.vtype != REG_SZ && value.vtype != REG_EXPAND_SZ {
+        return None;
+    }
+
+    let units = value
+        .bytes
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .collect::<Vec<_>>();
+
+    let s = String::from_utf16_lossy(&units);
+    let s = s.trim_end_matches('\0');
+
+    Some(if value.vtype == REG_EXPAND_SZ {
+        expand_environment(s)
+    } else {
+        s.to_owned()
+    })
+}
+
+/// Replaces the `%NAME%` environment variables, keeping the unknown ones as written
+/// like `ExpandEnvironmentStrings`.
+fn expand_environment(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(start) = rest.find('%') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start + 1..];
+
+        match rest.find('%') {
+            Some(end) => {
+                let name = &rest[..end];
+
+                match env::var(name) {
+                    Ok(value) if !name.is_empty() => out.push_str(&value),
+                    _ => {
+                        out.push('%');
+                        out.push_str(name);
+                        out.push('%');
+                    }
+                }
+
+                rest = &rest[end + 1..];
+            }
+            None => out.push('%'),
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+fn unreadable(hive: Hive, path: &str, name: Option<&str>, message: String) -> Error {
+    let mut full = format!("{}\\{}", hive.name(), path);
+
+    if let Some(name) = name {
+        full.push('\\');
+        full.push_str(name);
+    }
+
+    Error::Unreadable {
+        path: full,
+        message,
+    }
+}
+
+#[test]
+fn expand_environment_keeps_the_unknown_variables() {
+    env::set_var("CONN_STR_REGISTRY_HOST", "db1");
+
+    assert_eq!(
+        "server=db1;x=%CONN_STR_UNKNOWN%;p=%%;a=50%",
+        expand_environment("server=%CONN_STR_REGISTRY_HOST%;x=%CONN_STR_UNKNOWN%;p=%%;a=50%")
+    );
+}
@@ -0,0 +1,328 @@
+//! Connection strings of custom formats described at runtime.
+//!
+//! A [`Schema`] lists the keys of a format unknown to this crate, so that its
+//! connection strings get the same strict parsing and validation as the built-in
+//! formats.
+
+use crate::keywords::{Lookup, ValueKind};
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{append_key_value, parse, Error, ParseOptions};
+use std::collections::HashMap;
+use std::fmt;
+
+/// A key of a [`Schema`].
+#[derive(Clone, Debug)]
+pub struct SchemaKey {
+    /// The canonical name of the key, in lower case.
+    pub name: String,
+
+    /// The synonyms of the key, in lower case, by order of precedence.
+    pub synonyms: Vec<String>,
+
+    /// The kind of value accepted by the key.
+    pub kind: ValueKind,
+
+    /// Set when the key must be present.
+    pub required: bool,
+
+    /// Set when the value is a credential, such as a password or a token.
+    pub sensitive: bool,
+}
+
+impl SchemaKey {
+    /// Creates an optional key accepting any text.
+    pub fn new(name: &str) -> Self {
+        SchemaKey {
+            name: name.trim().to_lowercase(),
+            synonyms: Vec::new(),
+            kind: ValueKind::Text,
+            required: false,
+            sensitive: false,
+        }
+    }
+
+    pub fn kind(mut self, kind: ValueKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Marks the value as a credential, which is checked by the hardened parsing and
+    /// by the password policy of the [`Validator`].
+    ///
+    /// The keys of kind [`ValueKind::Secret`] are always sensitive.
+    pub fn sensitive(mut self) -> Self {
+        self.sensitive = true;
+        self
+    }
+
+    pub fn synonyms(mut self, synonyms: &[&str]) -> Self {
+        self.synonyms = synonyms.iter().map(|s| s.trim().to_lowercase()).collect();
+        self
+    }
+
+    /// Iterates over the canonical name followed by the synonyms.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        Some(self.name.as_str())
+            .into_iter()
+            .chain(self.synonyms.iter().map(|s| s.as_str()))
+    }
+
+    /// Indicates if the value is a credential, which should be redacted.
+    pub fn is_sensitive(&self) -> bool {
+        self.sensitive || self.kind == ValueKind::Secret
+    }
+}
+
+/// The keys of a custom connection string format.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::keywords::ValueKind;
+/// use conn_str::schema::{Schema, SchemaKey};
+/// use conn_str::ParseOptions;
+///
+/// let schema = Schema::new()
+///     .key(SchemaKey::new("endpoint").synonyms(&["ep"]).required())?
+///     .key(SchemaKey::new("port").kind(ValueKind::Int { min: 1, max: 65535 }))?
+///     .key(SchemaKey::new("token").kind(ValueKind::Secret))?;
+///
+/// let options = ParseOptions { strict: true, ..Default::default() };
+/// let conn = schema.parse_with("EP=gw.local;Port=8443;Token=abc", &options).unwrap();
+///
+/// assert_eq!(Some("gw.local"), conn.get("endpoint"));
+/// assert_eq!("endpoint=gw.local;port=8443;token=***", conn.to_string());
+/// assert_eq!("endpoint=gw.local;port=8443;token=abc", conn.to_connection_string_with_secrets());
+/// assert!(conn.validate().is_empty());
+///
+/// assert!(schema.parse_with("endpoint=gw.local;port=0", &options).is_err());
+/// # Ok::<(), conn_str::Error>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Schema {
+    keys: Vec<SchemaKey>,
+
+    /// Every canonical name and synonym, with the index of its key.
+    lookup: HashMap<String, usize>,
+}
+
+impl Schema {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a key, replacing the key with the same name, or fails with
+    /// [`Error::DuplicateKey`] when one of its names is already used by another key.
+    pub fn key(mut self, key: SchemaKey) -> Result<Self, Error> {
+        for name in key.names() {
+            if let Some(other) = self.lookup.get(name).map(|i| &self.keys[*i]) {
+                if other.name != key.name {
+                    return Err(Error::DuplicateKey {
+                        key: name.to_owned(),
+                        used_by: other.name.clone(),
+                    });
+                }
+            }
+        }
+
+        let index = match self.keys.iter().position(|k| k.name == key.name) {
+            Some(index) => {
+                self.lookup.retain(|_, i| *i != index);
+                self.keys[index] = key;
+                index
+            }
+            None => {
+                self.keys.push(key);
+                self.keys.len() - 1
+            }
+        };
+
+        for name in self.keys[index].names() {
+            self.lookup.insert(name.to_owned(), index);
+        }
+
+        Ok(self)
+    }
+
+    /// Finds a key by its canonical name or one of its synonyms, ignoring case.
+    pub fn find(&self, name: &str) -> Option<&SchemaKey> {
+        self.lookup
+            .get(&name.trim().to_lowercase())
+            .map(|i| &self.keys[*i])
+    }
+
+    /// Iterates over the keys, by order of addition.
+    pub fn iter(&self) -> impl Iterator<Item = &SchemaKey> {
+        self.keys.iter()
+    }
+
+    /// Parses a connection string of this format.
+    pub fn parse(&self, conn_str: &str) -> Result<SchemaConnStr<'_>, Error> {
+        self.parse_with(conn_str, &ParseOptions::default())
+    }
+
+    /// Parses a connection string of this format with the given options.
+    ///
+    /// A strict parsing fails on the keys missing from the schema and on the invalid
+    /// values.
+    pub fn parse_with(
+        &self,
+        conn_str: &str,
+        options: &ParseOptions,
+    ) -> Result<SchemaConnStr<'_>, Error> {
+        let conn = SchemaConnStr {
+            schema: self,
+            map: parse(conn_str, false, self, options)?,
+        };
+
+        if options.required {
+            if let Some(key) = self
+                .iter()
+                .find(|k| k.required && conn.get(&k.name).is_none())
+            {
+                return Err(Error::MissingKey(key.name.clone()));
+            }
+        }
+
+        Ok(conn)
+    }
+}
+
+impl Lookup for Schema {
+    fn lookup(&self, key: &str) -> Option<(&str, ValueKind, bool)> {
+        self.find(key)
+            .map(|k| (k.name.as_str(), k.kind, k.is_sensitive()))
+    }
+}
+
+/// A connection string parsed with a [`Schema`].
+#[derive(Clone)]
+pub struct SchemaConnStr<'a> {
+    schema: &'a Schema,
+    pub(crate) map: HashMap<String, String>,
+}
+
+impl<'a> SchemaConnStr<'a> {
+    /// Gets the value of a key of the schema, looking up its synonyms.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.schema
+            .find(name)
+            .and_then(|k| k.names().find_map(|n| self.map.get(n)))
+            .map(|s| s.as_str())
+    }
+
+    pub fn schema(&self) -> &'a Schema {
+        self.schema
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        self.to_connection_string(false)
+    }
+
+    /// Gets the keys unknown to the schema, in lower case and sorted.
+    pub fn unknown_keys(&self) -> Vec<&str> {
+        let mut keys: Vec<_> = self
+            .map
+            .keys()
+            .filter(|k| self.schema.find(k).is_none())
+            .map(|k| k.as_str())
+            .collect();
+
+        keys.sort();
+        keys
+    }
+
+    /// Validates the connection string, returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::schema(self, validator)
+    }
+
+    /// Writes the keys of the schema under their canonical names, by order of the
+    /// schema, followed by the unknown keys.
+    fn to_connection_string(&self, redact: bool) -> String {
+        let mut out = String::new();
+
+        for key in self.schema.iter() {
+            if let Some(value) = self.get(&key.name) {
+                let value = match redact && key.is_sensitive() {
+                    true => "***",
+                    false => value,
+                };
+
+                append_key_value(&mut out, &key.name, value, false);
+            }
+        }
+
+        for key in self.unknown_keys() {
+            append_key_value(&mut out, key, &self.map[key], false);
+        }
+
+        out
+    }
+}
+
+/// Formats the connection string with its sensitive keys redacted.
+impl<'a> fmt::Display for SchemaConnStr<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_connection_string(true))
+    }
+}
+
+/// Formats the connection string with its sensitive keys redacted.
+impl<'a> fmt::Debug for SchemaConnStr<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("SchemaConnStr")
+            .field(&self.to_connection_string(true))
+            .finish()
+    }
+}
+
+#[test]
+fn schema_rejects_names_used_twice() {
+    let schema = Schema::new()
+        .key(SchemaKey::new("endpoint").synonyms(&["ep"]))
+        .and_then(|s| s.key(SchemaKey::new("endpoint").synonyms(&["url"])))
+        .unwrap();
+
+    assert!(schema.find("ep").is_none());
+    assert_eq!("endpoint", schema.find("URL").unwrap().name);
+
+    match schema.key(SchemaKey::new("address").synonyms(&["url"])) {
+        Err(Error::DuplicateKey { key, used_by }) => {
+            assert_eq!("url", key);
+            assert_eq!("endpoint", used_by);
+        }
+        _ => panic!("the synonym should be already used"),
+    }
+}
+
+#[test]
+fn sensitive_keys_are_redacted() {
+    let schema = Schema::new()
+        .key(SchemaKey::new("endpoint"))
+        .and_then(|s| s.key(SchemaKey::new("api key").synonyms(&["key"]).sensitive()))
+        .unwrap();
+    let conn = schema.parse("key=s3cret;endpoint=gw").unwrap();
+
+    assert_eq!("endpoint=gw;api key=***", conn.to_string());
+    assert_eq!(
+        r#"SchemaConnStr("endpoint=gw;api key=***")"#,
+        format!("{:?}", conn)
+    );
+    assert_eq!(
+        "endpoint=gw;api key=s3cret",
+        conn.to_connection_string_with_secrets()
+    );
+}
@@ -0,0 +1,43 @@
+//! Secrets that cannot be displayed by mistake.
+//!
+//! With the `secret` feature, the getters of the credentials return a [`Secret`],
+//! which implements neither `Display` nor `Debug`, so that a credential is never
+//! formatted into a log without an explicit call to [`Secret::expose`].
+
+use crate::secret_eq;
+
+/// A credential borrowed from a connection string.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::MsSqlConnStr;
+/// use std::str::FromStr;
+///
+/// let conn = MsSqlConnStr::from_str("server=.;password=Pass1=3").unwrap();
+/// let password = conn.password().unwrap();
+///
+/// assert_eq!("Pass1=3", password.expose());
+/// ```
+#[derive(Clone, Copy)]
+pub struct Secret<'a>(&'a str);
+
+impl<'a> Secret<'a> {
+    pub(crate) fn new(value: &'a str) -> Self {
+        Secret(value)
+    }
+
+    /// Gets the value of the secret, such as to hand it to a driver.
+    pub fn expose(&self) -> &'a str {
+        self.0
+    }
+}
+
+/// Compares the secrets in a time independent of their content.
+impl<'a> PartialEq for Secret<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        secret_eq(self.0, other.0)
+    }
+}
+
+impl<'a> Eq for Secret<'a> {}
@@ -0,0 +1,315 @@
+//! Addresses of the servers of an MS SQL connection string.
+
+use crate::{parse_port, Error};
+use std::fmt;
+use std::net::Ipv6Addr;
+use std::str::FromStr;
+
+/// A SQL Server data source, `[protocol:]server[\instance][,port]` or the path of a
+/// named pipe.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::server::{DataSource, ServerProtocol};
+///
+/// let source: DataSource = "tcp:[2001:db8::1]\\INST,1433".parse().unwrap();
+///
+/// assert_eq!(Some(ServerProtocol::Tcp), source.protocol);
+/// assert_eq!("2001:db8::1", source.host);
+/// assert_eq!("tcp:[2001:db8::1]\\INST,1433", source.to_string());
+///
+/// let local: DataSource = "(localdb)\\MSSQLLocalDB".parse().unwrap();
+/// assert!(local.is_local_db());
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DataSource {
+    pub protocol: Option<ServerProtocol>,
+
+    /// The host name or the IP address, an IPv6 address being written without its
+    /// brackets, or the server of a named pipe.
+    pub host: String,
+
+    /// The named instance, or the instance of a `MSSQL$instance` named pipe.
+    pub instance: Option<String>,
+
+    /// The TCP port, `None` for the default port or the port of the named instance.
+    pub port: Option<u16>,
+
+    /// The name of the pipe of a named pipes data source, such as `sql\query`.
+    pub pipe: Option<String>,
+}
+
+impl DataSource {
+    /// Creates the data source of a host, without protocol, instance or port.
+    pub fn new(host: &str) -> Self {
+        DataSource {
+            protocol: None,
+            host: host.to_owned(),
+            instance: None,
+            port: None,
+            pipe: None,
+        }
+    }
+
+    /// Indicates if the data source is a SQL Server Express LocalDB instance.
+    pub fn is_local_db(&self) -> bool {
+        self.host.eq_ignore_ascii_case("(localdb)")
+    }
+
+    /// Parses a data source, the errors being reported for the given key.
+    pub(crate) fn parse(key: &str, value: &str) -> Result<Self, Error> {
+        let invalid = || Error::InvalidValue {
+            key: key.to_owned(),
+            value: value.to_owned(),
+            expected: "`[protocol:]server[\\instance][,port]`".to_owned(),
+        };
+
+        let (protocol, s) = split_protocol(value.trim());
+
+        if protocol == Some(ServerProtocol::NamedPipes) {
+            let pipe = s.parse::<NamedPipe>().map_err(|_| invalid())?;
+
+            return Ok(DataSource {
+                protocol,
+                instance: pipe.instance().map(str::to_owned),
+                host: pipe.server,
+                port: None,
+                pipe: Some(pipe.name),
+            });
+        }
+
+        let (s, port) = match s.rsplit_once(',') {
+            Some((s, port)) => (s, Some(parse_port("port", port).map_err(|_| invalid())?)),
+            None => (s, None),
+        };
+
+        let (host, instance) = match s.split_once('\\') {
+            Some((host, instance)) => (host.trim(), Some(instance.trim())),
+            None => (s.trim(), None),
+        };
+
+        let host = match host.strip_prefix('[') {
+            Some(ip) => ip
+                .strip_suffix(']')
+                .filter(|ip| ip.parse::<Ipv6Addr>().is_ok())
+                .ok_or_else(invalid)?,
+            None => host,
+        };
+
+        if host.is_empty() || instance.is_some_and(str::is_empty) {
+            return Err(invalid());
+        }
+
+        Ok(DataSource {
+            protocol,
+            host: host.to_owned(),
+            instance: instance.map(str::to_owned),
+            port,
+            pipe: None,
+        })
+    }
+}
+
+impl FromStr for DataSource {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse("data source", s)
+    }
+}
+
+/// Formats the data source as written in a connection string, an IPv6 address being
+/// written between brackets.
+impl fmt::Display for DataSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(protocol) = self.protocol {
+            write!(f, "{}:", protocol)?;
+        }
+
+        if let (Some(ServerProtocol::NamedPipes), Some(pipe)) = (self.protocol, &self.pipe) {
+            return write!(f, "\\\\{}\\pipe\\{}", self.host, pipe);
+        }
+
+        if self.host.parse::<Ipv6Addr>().is_ok() {
+            write!(f, "[{}]", self.host)?;
+        } else {
+            f.write_str(&self.host)?;
+        }
+
+        if let Some(instance) = &self.instance {
+            write!(f, "\\{}", instance)?;
+        }
+
+        if let Some(port) = self.port {
+            write!(f, ",{}", port)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A server to try when opening a connection, by order of preference.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FailoverCandidate {
+    pub data_source: DataSource,
+
+    /// Indicates that every address of the host should be tried in parallel, as for
+    /// the listener of an availability group spanning several subnets.
+    pub multi_subnet: bool,
+}
+
+/// A network protocol of SQL Server, prefixing a data source.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ServerProtocol {
+    /// `tcp:`
+    Tcp,
+
+    /// `np:`, followed by the path of a named pipe.
+    NamedPipes,
+
+    /// `lpc:`, the shared memory of a local server.
+    SharedMemory,
+
+    /// `admin:`, the dedicated administrator connection.
+    Admin,
+}
+
+impl FromStr for ServerProtocol {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "tcp" => Ok(ServerProtocol::Tcp),
+            "np" => Ok(ServerProtocol::NamedPipes),
+            "lpc" => Ok(ServerProtocol::SharedMemory),
+            "admin" => Ok(ServerProtocol::Admin),
+            _ => Err(Error::InvalidValue {
+                key: "protocol".to_owned(),
+                value: s.to_owned(),
+                expected: "`tcp`, `np`, `lpc` or `admin`".to_owned(),
+            }),
+        }
+    }
+}
+
+/// Formats the prefix of the protocol, without its colon.
+impl fmt::Display for ServerProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ServerProtocol::Tcp => "tcp",
+            ServerProtocol::NamedPipes => "np",
+            ServerProtocol::SharedMemory => "lpc",
+            ServerProtocol::Admin => "admin",
+        })
+    }
+}
+
+/// Splits the protocol prefix of a data source from the rest, a data source without a
+/// known prefix being returned as is.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::server::{split_protocol, ServerProtocol};
+///
+/// assert_eq!((Some(ServerProtocol::Tcp), "db1,1433"), split_protocol("tcp:db1,1433"));
+/// assert_eq!((None, "[::1],1433"), split_protocol("[::1],1433"));
+/// ```
+pub fn split_protocol(data_source: &str) -> (Option<ServerProtocol>, &str) {
+    match data_source.split_once(':') {
+        Some((protocol, rest)) => match protocol.parse() {
+            Ok(protocol) => (Some(protocol), rest.trim()),
+            Err(_) => (None, data_source),
+        },
+        None => (None, data_source),
+    }
+}
+
+/// The path of a named pipe, `\\server\pipe\name`.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::server::NamedPipe;
+///
+/// let pipe: NamedPipe = r"\\db1\pipe\MSSQL$INST\sql\query".parse().unwrap();
+///
+/// assert_eq!("db1", pipe.server);
+/// assert_eq!(Some("INST"), pipe.instance());
+/// assert_eq!(r"\\db1\pipe\MSSQL$INST\sql\query", pipe.to_string());
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct NamedPipe {
+    /// The server, `.` for the local one.
+    pub server: String,
+
+    /// The name of the pipe, such as `sql\query` for the default instance.
+    pub name: String,
+}
+
+impl NamedPipe {
+    /// Gets the instance of a `MSSQL$instance\sql\query` pipe.
+    pub fn instance(&self) -> Option<&str> {
+        let (first, _) = self.name.split_once('\\')?;
+
+        first
+            .get(..6)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("mssql$"))
+            .map(|_| &first[6..])
+            .filter(|instance| !instance.is_empty())
+    }
+}
+
+impl FromStr for NamedPipe {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed = s.trim().strip_prefix("\\\\").and_then(|path| {
+            let (server, rest) = path.split_once('\\')?;
+            let (pipe, name) = rest.split_once('\\')?;
+
+            if server.is_empty() || name.is_empty() || !pipe.eq_ignore_ascii_case("pipe") {
+                return None;
+            }
+
+            Some(NamedPipe {
+                server: server.to_owned(),
+                name: name.to_owned(),
+            })
+        });
+
+        parsed.ok_or_else(|| Error::InvalidValue {
+            key: "pipe".to_owned(),
+            value: s.to_owned(),
+            expected: "`\\\\server\\pipe\\name`".to_owned(),
+        })
+    }
+}
+
+impl fmt::Display for NamedPipe {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "\\\\{}\\pipe\\{}", self.server, self.name)
+    }
+}
+
+#[test]
+fn data_sources_are_decomposed() {
+    let source: DataSource = "tcp:db1\\INST,1500".parse().unwrap();
+    assert_eq!("db1", source.host);
+    assert_eq!(Some(1500), source.port);
+    assert_eq!(Some("INST"), source.instance.as_deref());
+
+    let source: DataSource = r"np:\\db2\pipe\MSSQL$INST\sql\query".parse().unwrap();
+    assert_eq!("db2", source.host);
+    assert_eq!(Some("INST"), source.instance.as_deref());
+    assert_eq!(r"np:\\db2\pipe\MSSQL$INST\sql\query", source.to_string());
+
+    let source: DataSource = "2001:db8::1,1433".parse().unwrap();
+    assert_eq!("2001:db8::1", source.host);
+    assert_eq!("[2001:db8::1],1433", source.to_string());
+
+    assert!("[db1],1433".parse::<DataSource>().is_err());
+    assert!("db1,0".parse::<DataSource>().is_err());
+    assert!("db1\\".parse::<DataSource>().is_err());
+}
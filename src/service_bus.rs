@@ -0,0 +1,191 @@
+//! Azure Service Bus connection strings, as parsed by the Azure Service Bus client
+//! libraries.
+
+use crate::keywords;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{decode_bytes, get, parse, parse_bool, to_connection_string, Error, ParseOptions};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// An Azure Service Bus connection string, authorized by a shared access policy or by
+/// a shared access signature.
+///
+/// The `Debug` implementation masks the keys like the [`Display`](fmt::Display) one.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::service_bus::ServiceBusConnStr;
+/// use std::str::FromStr;
+///
+/// let conn = ServiceBusConnStr::from_str("Endpoint=sb://ns.servicebus.windows.net/;SharedAccessKeyName=RootManageSharedAccessKey;SharedAccessKey=abc=;EntityPath=queue1").unwrap();
+///
+/// assert_eq!(Some("sb://ns.servicebus.windows.net/"), conn.endpoint());
+/// assert_eq!(Some("ns.servicebus.windows.net"), conn.fully_qualified_namespace());
+/// assert_eq!(Some("RootManageSharedAccessKey"), conn.shared_access_key_name());
+/// assert_eq!(Some("queue1"), conn.entity_path());
+/// assert_eq!(
+///     "ServiceBusConnStr(\"endpoint=sb://ns.servicebus.windows.net/;entitypath=queue1;sharedaccesskey=***;sharedaccesskeyname=RootManageSharedAccessKey\")",
+///     format!("{:?}", conn),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct ServiceBusConnStr(Arc<HashMap<String, String>>);
+
+impl FromStr for ServiceBusConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl ServiceBusConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    /// Parses the connection string, which requires an `Endpoint`.
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        let map = parse(conn_str, false, &keywords::SERVICE_BUS, options)?;
+
+        match get(&map, &keywords::SERVICE_BUS, "endpoint") {
+            Some(endpoint) if !endpoint.trim().is_empty() => Ok(ServiceBusConnStr(Arc::new(map))),
+            _ => Err(Error::MissingKey("endpoint".to_owned())),
+        }
+    }
+
+    /// Gets the url of the namespace, `sb://<namespace>.servicebus.windows.net/`.
+    pub fn endpoint(&self) -> Option<&str> {
+        self.get("endpoint")
+    }
+
+    /// Gets the name of the queue or of the topic.
+    pub fn entity_path(&self) -> Option<&str> {
+        self.get("entitypath")
+    }
+
+    /// Gets the host of the `Endpoint`, such as `<namespace>.servicebus.windows.net`.
+    pub fn fully_qualified_namespace(&self) -> Option<&str> {
+        self.endpoint().and_then(endpoint_host)
+    }
+
+    /// Gets the key of the shared access policy.
+    #[cfg(not(feature = "secret"))]
+    pub fn shared_access_key(&self) -> Option<&str> {
+        self.get("sharedaccesskey")
+    }
+
+    /// Gets the key of the shared access policy.
+    #[cfg(feature = "secret")]
+    pub fn shared_access_key(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("sharedaccesskey").map(crate::secret::Secret::new)
+    }
+
+    /// Gets the name of the shared access policy.
+    pub fn shared_access_key_name(&self) -> Option<&str> {
+        self.get("sharedaccesskeyname")
+    }
+
+    /// Gets the shared access signature used instead of the policy key.
+    #[cfg(not(feature = "secret"))]
+    pub fn shared_access_signature(&self) -> Option<&str> {
+        self.get("sharedaccesssignature")
+    }
+
+    /// Gets the shared access signature used instead of the policy key.
+    #[cfg(feature = "secret")]
+    pub fn shared_access_signature(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("sharedaccesssignature")
+            .map(crate::secret::Secret::new)
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.0, &keywords::SERVICE_BUS, false, false)
+    }
+
+    /// Indicates if the connection targets the local emulator,
+    /// `UseDevelopmentEmulator=true`.
+    pub fn use_development_emulator(&self) -> Result<bool, Error> {
+        match self.get("usedevelopmentemulator") {
+            Some(v) => parse_bool(v.trim()),
+            None => Ok(false),
+        }
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        get(&self.0, &keywords::SERVICE_BUS, name)
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.0, &keywords::SERVICE_BUS, validator)
+    }
+}
+
+/// Formats the connection string with its keys masked.
+impl fmt::Debug for ServiceBusConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("ServiceBusConnStr")
+            .field(&self.to_string())
+            .finish()
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for ServiceBusConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(
+            &self.0,
+            &keywords::SERVICE_BUS,
+            false,
+            true,
+        ))
+    }
+}
+
+/// Gets the host of an endpoint url, such as `sb://host/`, the scheme being optional.
+pub(crate) fn endpoint_host(endpoint: &str) -> Option<&str> {
+    let endpoint = endpoint.trim();
+    let rest = match endpoint.find("://") {
+        Some(i) => &endpoint[i + 3..],
+        None => endpoint,
+    };
+
+    match rest.split(['/', ':']).next() {
+        Some(host) if !host.is_empty() => Some(host),
+        _ => None,
+    }
+}
+
+#[test]
+fn endpoint_is_required() {
+    let conn = ServiceBusConnStr::from_str(
+        "Endpoint=sb://localhost;SharedAccessSignature=SharedAccessSignature sr=x&sig=y;UseDevelopmentEmulator=true",
+    )
+    .unwrap();
+
+    assert_eq!(Some("localhost"), conn.fully_qualified_namespace());
+    assert!(conn.use_development_emulator().unwrap());
+    assert!(conn.shared_access_key().is_none());
+    assert_eq!(
+        "endpoint=sb://localhost;sharedaccesssignature=***;usedevelopmentemulator=true",
+        conn.to_string()
+    );
+
+    assert!(matches!(
+        ServiceBusConnStr::from_str("SharedAccessKeyName=a;SharedAccessKey=b"),
+        Err(Error::MissingKey(_))
+    ));
+}
@@ -0,0 +1,135 @@
+//! Named connection strings of an application.
+
+use crate::batch::strip_comment;
+use crate::validate::{ValidationIssue, Validator};
+use crate::{AnyConnStr, Error, ParseOptions};
+use std::collections::BTreeMap;
+
+/// A set of SQL Server or Entity Framework connection strings, by name.
+///
+/// The set is read from and written to a document of `name=connection string` lines.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::set::ConnStrSet;
+/// use conn_str::{ParseOptions, Provider};
+///
+/// let doc = "Sales=server=.;database=Sales;pwd=x\n\nModel=provider connection string='server=.'\n";
+/// let set = ConnStrSet::from_document(doc, &ParseOptions::default()).unwrap();
+///
+/// assert_eq!(Provider::EntityFramework, set.get("Model").unwrap().provider());
+/// assert_eq!(vec!["Model", "Sales"], set.iter().map(|(n, _)| n).collect::<Vec<_>>());
+///
+/// assert_eq!(
+///     "Model=provider connection string=\"server=.\"\nSales=database=Sales;pwd=x;server=.\n",
+///     set.to_document_with_secrets(),
+/// );
+/// ```
+#[derive(Clone, Default)]
+pub struct ConnStrSet {
+    entries: BTreeMap<String, AnyConnStr>,
+}
+
+impl ConnStrSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads a document of `name=connection string` lines, skipping the blank lines
+    /// and the comments when [`ParseOptions::comments`] is set.
+    ///
+    /// The errors carry the number of the line, starting at 1.
+    pub fn from_document(doc: &str, options: &ParseOptions) -> Result<Self, Error> {
+        let mut set = ConnStrSet::new();
+
+        for (index, line) in doc.lines().enumerate() {
+            let line = strip_comment(line, options);
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let at_line = |error| Error::AtLine {
+                line: index + 1,
+                error: Box::new(error),
+            };
+
+            let (name, conn_str) = line
+                .split_once('=')
+                .ok_or_else(|| at_line(Error::SyntaxError(line.len())))?;
+
+            let conn = AnyConnStr::parse_with(conn_str, options).map_err(at_line)?;
+            set.insert(name.trim(), conn);
+        }
+
+        Ok(set)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&AnyConnStr> {
+        self.entries.get(name)
+    }
+
+    /// Adds a connection string, returning the one it replaces.
+    pub fn insert(&mut self, name: &str, conn: AnyConnStr) -> Option<AnyConnStr> {
+        self.entries.insert(name.to_owned(), conn)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterates over the names and the connection strings, sorted by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &AnyConnStr)> {
+        self.entries.iter().map(|(n, c)| (n.as_str(), c))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<AnyConnStr> {
+        self.entries.remove(name)
+    }
+
+    /// Writes the document of `name=connection string` lines, with the secrets.
+    pub fn to_document_with_secrets(&self) -> String {
+        let mut doc = String::new();
+
+        for (name, conn) in self.iter() {
+            doc.push_str(name);
+            doc.push('=');
+            doc.push_str(&conn.to_connection_string_with_secrets());
+            doc.push('\n');
+        }
+
+        doc
+    }
+
+    /// Validates every connection string, returning the issues found by name.
+    ///
+    /// The connection strings without issues are omitted.
+    pub fn validate(&self) -> Vec<(&str, Vec<ValidationIssue>)> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates every connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<(&str, Vec<ValidationIssue>)> {
+        self.iter()
+            .map(|(name, conn)| (name, conn.validate_with(validator)))
+            .filter(|(_, issues)| !issues.is_empty())
+            .collect()
+    }
+}
+
+#[test]
+fn document_errors_carry_the_line_number() {
+    let doc = "a=server=.\nb=server='.\n";
+
+    match ConnStrSet::from_document(doc, &ParseOptions::default()) {
+        Err(Error::AtLine { line: 2, error }) => {
+            assert!(matches!(*error, Error::SyntaxError(_)))
+        }
+        _ => panic!("the second line should fail"),
+    }
+}
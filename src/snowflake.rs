@@ -0,0 +1,236 @@
+//! Snowflake connection strings, as parsed by the Snowflake .NET driver.
+
+use crate::keywords;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{decode_bytes, get, parse, to_connection_string, Error, ParseOptions};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// The `authenticator` of a Snowflake connection.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Authenticator {
+    /// `snowflake`, the user and the password of Snowflake.
+    Snowflake,
+
+    /// `externalbrowser`, a single sign-on in a web browser.
+    ExternalBrowser,
+
+    /// `snowflake_jwt`, the key pair authentication with a private key.
+    Jwt,
+
+    /// `oauth`, an OAuth token given by the `token`.
+    OAuth,
+
+    /// `username_password_mfa`, the password followed by a multi-factor passcode.
+    UsernamePasswordMfa,
+
+    /// The `https://` url of an Okta endpoint, the native single sign-on of Okta.
+    Okta(String),
+}
+
+impl FromStr for Authenticator {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim();
+
+        match value.to_lowercase().as_str() {
+            "snowflake" => Ok(Authenticator::Snowflake),
+            "externalbrowser" => Ok(Authenticator::ExternalBrowser),
+            "snowflake_jwt" => Ok(Authenticator::Jwt),
+            "oauth" => Ok(Authenticator::OAuth),
+            "username_password_mfa" => Ok(Authenticator::UsernamePasswordMfa),
+            v if v.starts_with("https://") && v.contains(".okta.com") => {
+                Ok(Authenticator::Okta(value.to_owned()))
+            }
+            _ => Err(Error::InvalidValue {
+                key: "authenticator".to_owned(),
+                value: s.to_owned(),
+                expected: "`snowflake`, `externalbrowser`, `snowflake_jwt`, `oauth`, `username_password_mfa` or an Okta url".to_owned(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Authenticator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Authenticator::Snowflake => "snowflake",
+            Authenticator::ExternalBrowser => "externalbrowser",
+            Authenticator::Jwt => "snowflake_jwt",
+            Authenticator::OAuth => "oauth",
+            Authenticator::UsernamePasswordMfa => "username_password_mfa",
+            Authenticator::Okta(url) => url,
+        })
+    }
+}
+
+/// A Snowflake .NET driver connection string.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::snowflake::{Authenticator, SnowflakeConnStr};
+/// use std::str::FromStr;
+///
+/// let conn = SnowflakeConnStr::from_str("account=xy12345;user=me;password=p;db=MYDB;schema=PUBLIC;warehouse=WH;role=ANALYST;authenticator=externalbrowser").unwrap();
+///
+/// assert_eq!(Some("xy12345"), conn.account());
+/// assert_eq!(Some("WH"), conn.warehouse());
+/// assert_eq!(Some("ANALYST"), conn.role());
+/// assert_eq!(Authenticator::ExternalBrowser, conn.authenticator().unwrap());
+/// assert_eq!(Some("MYDB"), conn.database());
+/// assert_eq!(Some("xy12345.snowflakecomputing.com".to_owned()), conn.host());
+/// ```
+#[derive(Clone)]
+pub struct SnowflakeConnStr(Arc<HashMap<String, String>>);
+
+impl FromStr for SnowflakeConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl SnowflakeConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        Ok(SnowflakeConnStr(Arc::new(parse(
+            conn_str,
+            false,
+            &keywords::SNOWFLAKE,
+            options,
+        )?)))
+    }
+
+    /// Gets the identifier of the account, possibly followed by its region.
+    pub fn account(&self) -> Option<&str> {
+        self.get("account")
+    }
+
+    /// Gets the authenticator, `snowflake` when missing.
+    pub fn authenticator(&self) -> Result<Authenticator, Error> {
+        match self.get("authenticator") {
+            Some(v) => v.parse(),
+            None => Ok(Authenticator::Snowflake),
+        }
+    }
+
+    pub fn database(&self) -> Option<&str> {
+        self.get("db")
+    }
+
+    /// Gets the host, `<account>.snowflakecomputing.com` when missing, the region
+    /// being inserted before the domain when set.
+    pub fn host(&self) -> Option<String> {
+        if let Some(host) = self.get("host") {
+            return Some(host.trim().to_owned());
+        }
+
+        let account = self.account()?.trim();
+
+        Some(match self.get("region").map(str::trim) {
+            Some(region) if !region.is_empty() && !account.contains('.') => {
+                format!("{}.{}.snowflakecomputing.com", account, region)
+            }
+            _ => format!("{}.snowflakecomputing.com", account),
+        })
+    }
+
+    #[cfg(not(feature = "secret"))]
+    pub fn password(&self) -> Option<&str> {
+        self.get("password")
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("password").map(crate::secret::Secret::new)
+    }
+
+    /// Gets the path of the private key of the `snowflake_jwt` authenticator.
+    pub fn private_key_file(&self) -> Option<&str> {
+        self.get("private_key_file")
+    }
+
+    pub fn role(&self) -> Option<&str> {
+        self.get("role")
+    }
+
+    pub fn schema(&self) -> Option<&str> {
+        self.get("schema")
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.0, &keywords::SNOWFLAKE, false, false)
+    }
+
+    pub fn user(&self) -> Option<&str> {
+        self.get("user")
+    }
+
+    /// Gets the virtual warehouse running the queries.
+    pub fn warehouse(&self) -> Option<&str> {
+        self.get("warehouse")
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        get(&self.0, &keywords::SNOWFLAKE, name)
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.0, &keywords::SNOWFLAKE, validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for SnowflakeConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(
+            &self.0,
+            &keywords::SNOWFLAKE,
+            false,
+            true,
+        ))
+    }
+}
+
+#[test]
+fn authenticators_may_be_okta_urls() {
+    let conn = SnowflakeConnStr::from_str(
+        "ACCOUNT=xy12345;REGION=us-east-1;AUTHENTICATOR=https://me.okta.com;private_key_pwd=k",
+    )
+    .unwrap();
+
+    assert_eq!(
+        Authenticator::Okta("https://me.okta.com".to_owned()),
+        conn.authenticator().unwrap(),
+    );
+    assert_eq!(
+        Some("xy12345.us-east-1.snowflakecomputing.com".to_owned()),
+        conn.host(),
+    );
+    assert_eq!(
+        "account=xy12345;authenticator=https://me.okta.com;private_key_pwd=***;region=us-east-1",
+        conn.to_string(),
+    );
+
+    let conn = SnowflakeConnStr::from_str("authenticator=saml").unwrap();
+    assert!(conn.authenticator().is_err());
+    assert_eq!(None, conn.host());
+}
@@ -0,0 +1,220 @@
+//! SQLite connection strings, as parsed by Microsoft.Data.Sqlite.
+
+use crate::keywords;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{decode_bytes, get, parse, parse_bool, to_connection_string, Error, ParseOptions};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// The data source of an in-memory database.
+pub const MEMORY: &str = ":memory:";
+
+/// The `Mode` in which a SQLite database is opened.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum OpenMode {
+    /// The database is created when missing, the default.
+    ReadWriteCreate,
+
+    ReadWrite,
+    ReadOnly,
+
+    /// The database is held in memory, shared by the connections using the same
+    /// data source name and a shared cache.
+    Memory,
+}
+
+impl FromStr for OpenMode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "readwritecreate" => Ok(OpenMode::ReadWriteCreate),
+            "readwrite" => Ok(OpenMode::ReadWrite),
+            "readonly" => Ok(OpenMode::ReadOnly),
+            "memory" => Ok(OpenMode::Memory),
+            _ => Err(Error::InvalidValue {
+                key: "mode".to_owned(),
+                value: s.to_owned(),
+                expected: keywords::SQLITE.get("mode").unwrap().kind.to_string(),
+            }),
+        }
+    }
+}
+
+/// The `Cache` mode of a SQLite connection.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CacheMode {
+    /// The default mode of the native library, usually private.
+    Default,
+
+    Private,
+
+    /// The cache is shared by the connections to the same database.
+    Shared,
+}
+
+impl FromStr for CacheMode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "default" => Ok(CacheMode::Default),
+            "private" => Ok(CacheMode::Private),
+            "shared" => Ok(CacheMode::Shared),
+            _ => Err(Error::InvalidValue {
+                key: "cache".to_owned(),
+                value: s.to_owned(),
+                expected: keywords::SQLITE.get("cache").unwrap().kind.to_string(),
+            }),
+        }
+    }
+}
+
+/// A Microsoft.Data.Sqlite connection string.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::sqlite::{CacheMode, OpenMode, SqliteConnStr};
+/// use std::str::FromStr;
+///
+/// let conn = SqliteConnStr::from_str("Data Source=app.db;Mode=ReadWriteCreate;Cache=Shared;Password=x;Foreign Keys=True").unwrap();
+///
+/// assert_eq!(Some("app.db"), conn.data_source());
+/// assert_eq!(OpenMode::ReadWriteCreate, conn.mode().unwrap());
+/// assert_eq!(CacheMode::Shared, conn.cache().unwrap());
+/// assert_eq!(Some(true), conn.foreign_keys().unwrap());
+/// assert!(conn.pooling().unwrap());
+/// assert!(!conn.is_in_memory().unwrap());
+/// assert_eq!("cache=Shared;data source=app.db;foreign keys=True;mode=ReadWriteCreate;password=***", conn.to_string());
+/// ```
+#[derive(Clone)]
+pub struct SqliteConnStr(Arc<HashMap<String, String>>);
+
+impl FromStr for SqliteConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl SqliteConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        Ok(SqliteConnStr(Arc::new(parse(
+            conn_str,
+            false,
+            &keywords::SQLITE,
+            options,
+        )?)))
+    }
+
+    /// Gets the `Cache` mode, `Default` when missing.
+    pub fn cache(&self) -> Result<CacheMode, Error> {
+        match self.get("cache") {
+            Some(cache) => cache.parse(),
+            None => Ok(CacheMode::Default),
+        }
+    }
+
+    /// Gets the path of the database file, [`MEMORY`] for an in-memory database.
+    ///
+    /// An empty data source opens a temporary database on disk, deleted when the
+    /// connection is closed.
+    pub fn data_source(&self) -> Option<&str> {
+        self.get("data source")
+    }
+
+    /// Indicates if the foreign key constraints are enabled or disabled when opening
+    /// the connection, `None` keeping the setting of the database.
+    pub fn foreign_keys(&self) -> Result<Option<bool>, Error> {
+        match self.get("foreign keys").map(str::trim) {
+            Some("") | None => Ok(None),
+            Some(v) => parse_bool(v).map(Some),
+        }
+    }
+
+    /// Indicates if the database is held in memory, with the `:memory:` data source or
+    /// the `Memory` mode.
+    pub fn is_in_memory(&self) -> Result<bool, Error> {
+        Ok(self.data_source().map(str::trim) == Some(MEMORY) || self.mode()? == OpenMode::Memory)
+    }
+
+    /// Gets the `Mode`, `ReadWriteCreate` when missing.
+    pub fn mode(&self) -> Result<OpenMode, Error> {
+        match self.get("mode") {
+            Some(mode) => mode.parse(),
+            None => Ok(OpenMode::ReadWriteCreate),
+        }
+    }
+
+    #[cfg(not(feature = "secret"))]
+    pub fn password(&self) -> Option<&str> {
+        self.get("password")
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("password").map(crate::secret::Secret::new)
+    }
+
+    pub fn pooling(&self) -> Result<bool, Error> {
+        match self.get("pooling") {
+            Some(v) => parse_bool(v),
+            None => Ok(true),
+        }
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.0, &keywords::SQLITE, false, false)
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        get(&self.0, &keywords::SQLITE, name)
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.0, &keywords::SQLITE, validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for SqliteConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(
+            &self.0,
+            &keywords::SQLITE,
+            false,
+            true,
+        ))
+    }
+}
+
+#[test]
+fn memory_databases_are_detected() {
+    let conn = SqliteConnStr::from_str("Filename=:memory:").unwrap();
+    assert!(conn.is_in_memory().unwrap());
+
+    let conn = SqliteConnStr::from_str("Data Source=shared;Mode=Memory;Cache=Shared").unwrap();
+    assert!(conn.is_in_memory().unwrap());
+
+    let conn = SqliteConnStr::from_str("Data Source=app.db;Mode=Writable").unwrap();
+    assert!(conn.is_in_memory().is_err());
+    assert_eq!(None, conn.foreign_keys().unwrap());
+}
@@ -0,0 +1,288 @@
+//! Teradata connection strings, as parsed by the Teradata .NET Data Provider.
+
+use crate::keywords;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{
+    decode_bytes, get, parse, parse_bool, parse_port, to_connection_string, Error, ParseOptions,
+};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// The `Authentication Mechanism` of a Teradata connection.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AuthMechanism {
+    /// `TD2`, the user and the password of the database.
+    Td2,
+
+    /// `LDAP`, the user and the password of a directory.
+    Ldap,
+
+    /// `KRB5`, the Kerberos ticket of the Windows account.
+    Kerberos,
+
+    /// `TDNEGO`, negotiated between the client and the database.
+    TdNego,
+
+    /// `JWT`, a JSON web token given by the `Authentication String`.
+    Jwt,
+
+    /// `BROWSER`, a single sign-on in a web browser.
+    Browser,
+}
+
+impl FromStr for AuthMechanism {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "td2" => Ok(AuthMechanism::Td2),
+            "ldap" => Ok(AuthMechanism::Ldap),
+            "krb5" => Ok(AuthMechanism::Kerberos),
+            "tdnego" => Ok(AuthMechanism::TdNego),
+            "jwt" => Ok(AuthMechanism::Jwt),
+            "browser" => Ok(AuthMechanism::Browser),
+            _ => Err(Error::InvalidValue {
+                key: "authentication mechanism".to_owned(),
+                value: s.to_owned(),
+                expected: keywords::TERADATA
+                    .get("authentication mechanism")
+                    .unwrap()
+                    .kind
+                    .to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for AuthMechanism {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            AuthMechanism::Td2 => "TD2",
+            AuthMechanism::Ldap => "LDAP",
+            AuthMechanism::Kerberos => "KRB5",
+            AuthMechanism::TdNego => "TDNEGO",
+            AuthMechanism::Jwt => "JWT",
+            AuthMechanism::Browser => "BROWSER",
+        })
+    }
+}
+
+/// The `Session Mode`, the transaction semantics of a Teradata session.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SessionMode {
+    /// `ANSI`, the transactions being committed explicitly.
+    Ansi,
+
+    /// `TERA`, each statement being its own transaction unless enclosed by `BT` and
+    /// `ET`.
+    Teradata,
+}
+
+impl FromStr for SessionMode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "ansi" => Ok(SessionMode::Ansi),
+            "tera" => Ok(SessionMode::Teradata),
+            _ => Err(Error::InvalidValue {
+                key: "session mode".to_owned(),
+                value: s.to_owned(),
+                expected: keywords::TERADATA
+                    .get("session mode")
+                    .unwrap()
+                    .kind
+                    .to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for SessionMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SessionMode::Ansi => "ANSI",
+            SessionMode::Teradata => "TERA",
+        })
+    }
+}
+
+/// A Teradata .NET Data Provider connection string.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::teradata::{AuthMechanism, TeradataConnStr};
+/// use std::str::FromStr;
+///
+/// let conn = TeradataConnStr::from_str("Data Source=td;User Id=u;Password=p;Database=db;Authentication Mechanism=LDAP;Session Character Set=UTF8").unwrap();
+///
+/// assert_eq!(Some("td"), conn.data_source());
+/// assert_eq!(Some("u"), conn.user_id());
+/// assert_eq!(Some("db"), conn.database());
+/// assert_eq!(AuthMechanism::Ldap, conn.authentication_mechanism().unwrap());
+/// assert_eq!(Some("UTF8"), conn.session_character_set());
+/// assert_eq!(
+///     "authentication mechanism=LDAP;data source=td;database=db;password=***;session character set=UTF8;user id=u",
+///     conn.to_string(),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct TeradataConnStr(Arc<HashMap<String, String>>);
+
+impl FromStr for TeradataConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl TeradataConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        Ok(TeradataConnStr(Arc::new(parse(
+            conn_str,
+            false,
+            &keywords::TERADATA,
+            options,
+        )?)))
+    }
+
+    pub fn account_string(&self) -> Option<&str> {
+        self.get("account string")
+    }
+
+    /// Gets the logon mechanism, `TD2` when missing.
+    pub fn authentication_mechanism(&self) -> Result<AuthMechanism, Error> {
+        match self.get("authentication mechanism") {
+            Some(v) => v.parse(),
+            None => Ok(AuthMechanism::Td2),
+        }
+    }
+
+    /// Gets the data of the logon mechanism, such as the token of `JWT`.
+    #[cfg(not(feature = "secret"))]
+    pub fn authentication_string(&self) -> Option<&str> {
+        self.get("authentication string")
+    }
+
+    /// Gets the data of the logon mechanism, such as the token of `JWT`.
+    #[cfg(feature = "secret")]
+    pub fn authentication_string(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("authentication string")
+            .map(crate::secret::Secret::new)
+    }
+
+    pub fn connection_pooling(&self) -> Result<bool, Error> {
+        match self.get("connection pooling") {
+            Some(v) => parse_bool(v.trim()),
+            None => Ok(false),
+        }
+    }
+
+    pub fn data_source(&self) -> Option<&str> {
+        self.get("data source")
+    }
+
+    /// Gets the default database of the session, the one of the user when missing.
+    pub fn database(&self) -> Option<&str> {
+        self.get("database")
+    }
+
+    #[cfg(not(feature = "secret"))]
+    pub fn password(&self) -> Option<&str> {
+        self.get("password")
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("password").map(crate::secret::Secret::new)
+    }
+
+    /// Gets the `Port Number`, `None` for the default port 1025.
+    pub fn port(&self) -> Result<Option<u16>, Error> {
+        match self.get("port number") {
+            Some(port) => parse_port("port number", port).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Gets the query band of the session, `name=value;` pairs tagging its requests.
+    pub fn query_band(&self) -> Option<&str> {
+        self.get("query band")
+    }
+
+    pub fn session_character_set(&self) -> Option<&str> {
+        self.get("session character set")
+    }
+
+    /// Gets the transaction semantics, `None` for the default mode of the database.
+    pub fn session_mode(&self) -> Result<Option<SessionMode>, Error> {
+        self.get("session mode").map(str::parse).transpose()
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.0, &keywords::TERADATA, false, false)
+    }
+
+    pub fn user_id(&self) -> Option<&str> {
+        self.get("user id")
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        get(&self.0, &keywords::TERADATA, name)
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.0, &keywords::TERADATA, validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for TeradataConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(
+            &self.0,
+            &keywords::TERADATA,
+            false,
+            true,
+        ))
+    }
+}
+
+#[test]
+fn session_options_are_typed() {
+    let conn = TeradataConnStr::from_str(
+        "Server=td;UID=u;Session Mode=tera;Port=1026;Auth Mechanism=krb5;Pooling=true",
+    )
+    .unwrap();
+
+    assert_eq!(Some(SessionMode::Teradata), conn.session_mode().unwrap());
+    assert_eq!(Some(1026), conn.port().unwrap());
+    assert_eq!(
+        AuthMechanism::Kerberos,
+        conn.authentication_mechanism().unwrap()
+    );
+    assert!(conn.connection_pooling().unwrap());
+    assert_eq!(Some("u"), conn.user_id());
+
+    let conn = TeradataConnStr::from_str("Session Mode=db2;Authentication Mechanism=ntlm").unwrap();
+    assert!(conn.session_mode().is_err());
+    assert!(conn.authentication_mechanism().is_err());
+}
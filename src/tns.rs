@@ -0,0 +1,391 @@
+//! Oracle connect descriptors, the
+//! `(DESCRIPTION=(ADDRESS=(PROTOCOL=TCP)(HOST=h)(PORT=1521))(CONNECT_DATA=(SERVICE_NAME=svc)))`
+//! data sources of the tnsnames.ora files.
+//!
+//! A descriptor is a tree of `(NAME=value)` pairs, the value being either a text or
+//! a list of nested pairs. The tree is parsed by [`NvPair`] and the usual parts of a
+//! descriptor are read from it by [`Descriptor`].
+
+use crate::oracle::ServerType;
+use crate::{parse_port, Error};
+use std::fmt;
+use std::str::FromStr;
+
+/// A `(NAME=value)` pair of a connect descriptor.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::tns::NvPair;
+///
+/// let pair: NvPair = "(CONNECT_DATA =\n  (SERVICE_NAME = sales)\n  (SERVER = dedicated))".parse().unwrap();
+///
+/// assert_eq!(Some("sales"), pair.get("service_name").and_then(NvPair::text));
+/// assert_eq!("(CONNECT_DATA=(SERVICE_NAME=sales)(SERVER=dedicated))", pair.to_string());
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct NvPair {
+    /// The name, as written, compared ignoring case.
+    pub name: String,
+
+    pub value: NvValue,
+}
+
+/// The value of a [`NvPair`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum NvValue {
+    /// A text, trimmed, without its quotes when quoted.
+    Text(String),
+
+    /// The nested pairs.
+    List(Vec<NvPair>),
+}
+
+impl NvPair {
+    /// Gets the first nested pair having this name, ignoring case.
+    pub fn get(&self, name: &str) -> Option<&NvPair> {
+        self.pairs()
+            .iter()
+            .find(|p| p.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Gets the nested pairs, empty when the value is a text.
+    pub fn pairs(&self) -> &[NvPair] {
+        match &self.value {
+            NvValue::List(pairs) => pairs,
+            NvValue::Text(_) => &[],
+        }
+    }
+
+    /// Gets the text of the value, `None` when the value is a list.
+    pub fn text(&self) -> Option<&str> {
+        match &self.value {
+            NvValue::Text(text) => Some(text),
+            NvValue::List(_) => None,
+        }
+    }
+}
+
+impl FromStr for NvPair {
+    type Err = Error;
+
+    /// Parses a pair, the whitespaces and the new lines between the parentheses being
+    /// ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { s, pos: 0 };
+        let pair = parser.pair()?;
+
+        parser.skip_whitespaces();
+
+        if parser.pos < s.len() {
+            return Err(Error::SyntaxError(parser.pos));
+        }
+
+        Ok(pair)
+    }
+}
+
+/// Formats the pair on a single line, quoting the texts holding parentheses or `=`.
+impl fmt::Display for NvPair {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}=", self.name)?;
+
+        match &self.value {
+            NvValue::Text(text) if text.contains(['(', ')', '=']) => write!(f, "\"{}\"", text)?,
+            NvValue::Text(text) => f.write_str(text)?,
+            NvValue::List(pairs) => {
+                for pair in pairs {
+                    pair.fmt(f)?;
+                }
+            }
+        }
+
+        f.write_str(")")
+    }
+}
+
+/// A recursive descent parser of the pairs, keeping the position for the syntax
+/// errors.
+struct Parser<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn expect(&mut self, c: char) -> Result<(), Error> {
+        self.skip_whitespaces();
+
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(Error::SyntaxError(self.pos))
+        }
+    }
+
+    fn pair(&mut self) -> Result<NvPair, Error> {
+        self.expect('(')?;
+
+        let name = self.take_until(|c| c == '=' || c == '(' || c == ')');
+        let name = name.trim();
+
+        if name.is_empty() {
+            return Err(Error::SyntaxError(self.pos));
+        }
+
+        self.expect('=')?;
+        self.skip_whitespaces();
+
+        let value = match self.peek() {
+            Some('(') => {
+                let mut pairs = Vec::new();
+
+                while self.peek() == Some('(') {
+                    pairs.push(self.pair()?);
+                    self.skip_whitespaces();
+                }
+
+                NvValue::List(pairs)
+            }
+            Some('"') => {
+                self.pos += 1;
+                let text = self.take_until(|c| c == '"');
+                self.expect('"')?;
+                NvValue::Text(text.to_owned())
+            }
+            _ => NvValue::Text(self.take_until(|c| c == '(' || c == ')').trim().to_owned()),
+        };
+
+        self.expect(')')?;
+
+        Ok(NvPair {
+            name: name.to_owned(),
+            value,
+        })
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.s[self.pos..].chars().next()
+    }
+
+    fn skip_whitespaces(&mut self) {
+        let rest = &self.s[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn take_until(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let rest = &self.s[self.pos..];
+        let len = rest.find(f).unwrap_or(rest.len());
+
+        self.pos += len;
+        &rest[..len]
+    }
+}
+
+/// A connect descriptor, `(DESCRIPTION=...)`.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::oracle::ServerType;
+/// use conn_str::tns::Descriptor;
+///
+/// let descriptor: Descriptor = "(DESCRIPTION=
+///     (ADDRESS_LIST=(LOAD_BALANCE=on)
+///         (ADDRESS=(PROTOCOL=TCP)(HOST=db1)(PORT=1521))
+///         (ADDRESS=(PROTOCOL=TCP)(HOST=db2)(PORT=1521)))
+///     (CONNECT_DATA=(SERVICE_NAME=sales)(SERVER=shared)))"
+///     .parse()
+///     .unwrap();
+///
+/// let list = &descriptor.address_lists[0];
+///
+/// assert_eq!(Some(true), list.load_balance);
+/// assert_eq!(Some("db2"), list.addresses[1].host.as_deref());
+/// assert_eq!(Some(1521), list.addresses[1].port);
+/// assert_eq!(Some("sales"), descriptor.connect_data.service_name.as_deref());
+/// assert_eq!(Some(ServerType::Shared), descriptor.connect_data.server);
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Descriptor {
+    /// The lists of addresses, the addresses written directly in the descriptor
+    /// being gathered in a list of their own.
+    pub address_lists: Vec<AddressList>,
+
+    pub connect_data: ConnectData,
+
+    /// Tries the address lists in a random order.
+    pub load_balance: Option<bool>,
+
+    /// Tries the next address list when connecting fails, the default.
+    pub failover: Option<bool>,
+}
+
+/// An `ADDRESS_LIST` of a connect descriptor.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct AddressList {
+    pub addresses: Vec<Address>,
+
+    /// Tries the addresses in a random order.
+    pub load_balance: Option<bool>,
+
+    /// Tries the next address when connecting fails, the default.
+    pub failover: Option<bool>,
+}
+
+/// An `ADDRESS` of a connect descriptor.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Address {
+    /// The protocol, such as `TCP`, `TCPS` or `IPC`.
+    pub protocol: String,
+
+    pub host: Option<String>,
+    pub port: Option<u16>,
+}
+
+/// The `CONNECT_DATA` of a connect descriptor, naming the database service.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ConnectData {
+    pub service_name: Option<String>,
+
+    /// The system identifier of the database, used instead of the service name by
+    /// the older databases.
+    pub sid: Option<String>,
+
+    pub instance_name: Option<String>,
+    pub server: Option<ServerType>,
+}
+
+impl Descriptor {
+    /// Reads the parts of a `DESCRIPTION` pair.
+    pub fn from_pair(pair: &NvPair) -> Result<Self, Error> {
+        if !pair.name.eq_ignore_ascii_case("description") {
+            return Err(Error::InvalidValue {
+                key: "data source".to_owned(),
+                value: pair.to_string(),
+                expected: "a `(DESCRIPTION=...)` connect descriptor".to_owned(),
+            });
+        }
+
+        let mut descriptor = Descriptor::default();
+        let mut direct = None;
+
+        for pair in pair.pairs() {
+            match pair.name.to_lowercase().as_str() {
+                "address" => {
+                    // the addresses written directly form a list at their position
+                    let i = *direct.get_or_insert_with(|| {
+                        descriptor.address_lists.push(AddressList::default());
+                        descriptor.address_lists.len() - 1
+                    });
+
+                    descriptor.address_lists[i]
+                        .addresses
+                        .push(Address::from_pair(pair)?);
+                }
+                "address_list" => descriptor.address_lists.push(AddressList::from_pair(pair)?),
+                "connect_data" => descriptor.connect_data = ConnectData::from_pair(pair)?,
+                "failover" => descriptor.failover = Some(parse_bool(pair)?),
+                "load_balance" => descriptor.load_balance = Some(parse_bool(pair)?),
+                _ => {}
+            }
+        }
+
+        Ok(descriptor)
+    }
+}
+
+impl FromStr for Descriptor {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_pair(&s.parse()?)
+    }
+}
+
+impl AddressList {
+    fn from_pair(pair: &NvPair) -> Result<Self, Error> {
+        let mut list = AddressList::default();
+
+        for pair in pair.pairs() {
+            match pair.name.to_lowercase().as_str() {
+                "address" => list.addresses.push(Address::from_pair(pair)?),
+                "failover" => list.failover = Some(parse_bool(pair)?),
+                "load_balance" => list.load_balance = Some(parse_bool(pair)?),
+                _ => {}
+            }
+        }
+
+        Ok(list)
+    }
+}
+
+impl Address {
+    fn from_pair(pair: &NvPair) -> Result<Self, Error> {
+        let port = match text(pair, "port") {
+            Some(port) => Some(parse_port("port", &port)?),
+            None => None,
+        };
+
+        Ok(Address {
+            protocol: text(pair, "protocol").unwrap_or_else(|| "TCP".to_owned()),
+            host: text(pair, "host"),
+            port,
+        })
+    }
+}
+
+impl ConnectData {
+    fn from_pair(pair: &NvPair) -> Result<Self, Error> {
+        Ok(ConnectData {
+            service_name: text(pair, "service_name"),
+            sid: text(pair, "sid"),
+            instance_name: text(pair, "instance_name"),
+            server: text(pair, "server").map(|s| s.parse()).transpose()?,
+        })
+    }
+}
+
+/// Parses the `on`, `off`, `yes`, `no`, `true` and `false` of the descriptors.
+fn parse_bool(pair: &NvPair) -> Result<bool, Error> {
+    match pair.text().map(str::to_lowercase).as_deref() {
+        Some("on" | "yes" | "true") => Ok(true),
+        Some("off" | "no" | "false") => Ok(false),
+        _ => Err(Error::InvalidValue {
+            key: pair.name.to_lowercase(),
+            value: pair.text().unwrap_or_default().to_owned(),
+            expected: "`on`, `off`, `yes`, `no`, `true` or `false`".to_owned(),
+        }),
+    }
+}
+
+/// Gets the non empty text of a nested pair.
+fn text(pair: &NvPair, name: &str) -> Option<String> {
+    pair.get(name)
+        .and_then(NvPair::text)
+        .filter(|s| !s.is_empty())
+        .map(str::to_owned)
+}
+
+#[test]
+fn descriptors_report_the_position_of_syntax_errors() {
+    let descriptor: Descriptor =
+        "(DESCRIPTION=(ADDRESS=(HOST=db1))(address=(protocol=tcps)(host=db2)(port=2484))(CONNECT_DATA=(SID=ORCL)))"
+            .parse()
+            .unwrap();
+
+    assert_eq!(1, descriptor.address_lists.len());
+    assert_eq!("TCP", descriptor.address_lists[0].addresses[0].protocol);
+    assert_eq!(Some(2484), descriptor.address_lists[0].addresses[1].port);
+    assert_eq!(Some("ORCL"), descriptor.connect_data.sid.as_deref());
+
+    assert!(matches!(
+        "(DESCRIPTION=(ADDRESS=(HOST=db1)".parse::<NvPair>(),
+        Err(Error::SyntaxError(32))
+    ));
+    assert!(matches!(
+        "(DESCRIPTION=(ADDRESS=(HOST=db1)))x".parse::<NvPair>(),
+        Err(Error::SyntaxError(34))
+    ));
+    assert!("(ADDRESS=(HOST=db1))".parse::<Descriptor>().is_err());
+}
@@ -0,0 +1,264 @@
+//! Trino and Presto JDBC urls,
+//! `jdbc:trino://host[:port][/catalog[/schema]][?property=value&...]`.
+
+use crate::host::Host;
+use crate::percent::{encode, Component};
+use crate::url::Url;
+use crate::{parse_bool, Error};
+use std::fmt;
+use std::str::FromStr;
+
+/// A Trino JDBC url, or a Presto one written `jdbc:presto://`.
+///
+/// The properties are kept as written, in their order, and are looked up ignoring
+/// case, the last occurrence of a property winning.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::host::Host;
+/// use conn_str::trino::TrinoConnStr;
+/// use std::str::FromStr;
+///
+/// let conn = TrinoConnStr::from_str("jdbc:trino://trino1:8080/hive/sales?user=me&SSL=true&sessionProperties=query_max_run_time:2h;hive.insert_existing_partitions_behavior:OVERWRITE").unwrap();
+///
+/// assert_eq!(&Host::new("trino1", Some(8080)), conn.host());
+/// assert_eq!(Some("hive"), conn.catalog());
+/// assert_eq!(Some("sales"), conn.schema());
+/// assert_eq!(Some("me"), conn.user());
+/// assert!(conn.ssl().unwrap());
+/// assert_eq!(Some("2h"), conn.session_property("query_max_run_time"));
+/// assert_eq!(
+///     Some("OVERWRITE"),
+///     conn.session_property("hive.insert_existing_partitions_behavior"),
+/// );
+/// ```
+#[derive(Clone, Eq, PartialEq)]
+pub struct TrinoConnStr {
+    presto: bool,
+    host: Host,
+    catalog: Option<String>,
+    schema: Option<String>,
+    properties: Vec<(String, String)>,
+}
+
+impl FromStr for TrinoConnStr {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidValue {
+            key: "url".to_owned(),
+            value: s.to_owned(),
+            expected: "`jdbc:trino://host[:port][/catalog[/schema]][?property=value&...]`"
+                .to_owned(),
+        };
+
+        let trimmed = s.trim();
+        let url = trimmed
+            .get(..5)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("jdbc:"))
+            .and_then(|_| Url::parse(&trimmed[5..], &["trino", "presto"]))
+            .ok_or_else(invalid)?;
+
+        if url.user.is_some() || url.password.is_some() {
+            return Err(invalid());
+        }
+
+        let host = match url.hosts()?.as_slice() {
+            [(name, "")] => Host::new(name, None),
+            [(name, port)] => format!("{}:{}", Host::new(name, None), port).parse()?,
+            _ => return Err(invalid()),
+        };
+
+        let mut segments = url.path.split('/');
+        let mut segment = || match segments.next() {
+            Some(segment) if !segment.is_empty() => url.decode(segment).map(Some),
+            _ => Ok(None),
+        };
+
+        let catalog = segment()?;
+        let schema = segment()?;
+
+        if segments.next().is_some_and(|s| !s.is_empty()) || (schema.is_some() && catalog.is_none())
+        {
+            return Err(invalid());
+        }
+
+        let properties = url
+            .query_pairs()?
+            .into_iter()
+            .map(|(key, value, _)| (key, value))
+            .collect();
+
+        Ok(TrinoConnStr {
+            presto: trimmed[5..].to_lowercase().starts_with("presto:"),
+            host,
+            catalog,
+            schema,
+            properties,
+        })
+    }
+}
+
+impl TrinoConnStr {
+    /// Gets the default catalog of the queries.
+    pub fn catalog(&self) -> Option<&str> {
+        self.catalog.as_deref()
+    }
+
+    /// Gets the value of a property, ignoring case.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        let key = key.trim();
+
+        self.properties
+            .iter()
+            .rev()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Gets the coordinator, without port when the default port of the scheme is
+    /// used.
+    pub fn host(&self) -> &Host {
+        &self.host
+    }
+
+    /// Indicates if the url is a `jdbc:presto://` url.
+    pub fn is_presto(&self) -> bool {
+        self.presto
+    }
+
+    #[cfg(not(feature = "secret"))]
+    pub fn password(&self) -> Option<&str> {
+        self.get("password")
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("password").map(crate::secret::Secret::new)
+    }
+
+    /// Gets the properties as written, in their order.
+    pub fn properties(&self) -> &[(String, String)] {
+        &self.properties
+    }
+
+    pub fn schema(&self) -> Option<&str> {
+        self.schema.as_deref()
+    }
+
+    /// Gets the session properties, the `name:value` items of the
+    /// `sessionProperties` separated by semicolons, a catalog property being written
+    /// `catalog.name`.
+    pub fn session_properties(&self) -> Vec<(&str, &str)> {
+        self.get("sessionproperties")
+            .into_iter()
+            .flat_map(|props| props.split(';'))
+            .filter_map(|item| item.split_once(':'))
+            .map(|(name, value)| (name.trim(), value.trim()))
+            .filter(|(name, _)| !name.is_empty())
+            .collect()
+    }
+
+    /// Gets a session property by its name, ignoring case.
+    pub fn session_property(&self, name: &str) -> Option<&str> {
+        let name = name.trim();
+
+        self.session_properties()
+            .into_iter()
+            .rev()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v)
+    }
+
+    /// Indicates if the connection uses TLS, `SSL=true` or the port 443.
+    pub fn ssl(&self) -> Result<bool, Error> {
+        match self.get("ssl") {
+            Some(v) => parse_bool(v.trim()),
+            None => Ok(self.host.port == Some(443)),
+        }
+    }
+
+    /// Formats the url with its secrets, where the [`Display`](fmt::Display)
+    /// implementation redacts them.
+    pub fn to_url_with_secrets(&self) -> String {
+        self.url(false)
+    }
+
+    pub fn user(&self) -> Option<&str> {
+        self.get("user")
+    }
+
+    fn url(&self, redact: bool) -> String {
+        let mut out = match self.presto {
+            true => "jdbc:presto://".to_owned(),
+            false => "jdbc:trino://".to_owned(),
+        };
+
+        out.push_str(&self.host.to_string());
+
+        for segment in self.catalog.iter().chain(&self.schema) {
+            out.push('/');
+            out.push_str(&encode(segment, Component::Path));
+        }
+
+        for (i, (key, value)) in self.properties.iter().enumerate() {
+            let secret = matches!(
+                key.to_lowercase().as_str(),
+                "password"
+                    | "accesstoken"
+                    | "extracredentials"
+                    | "sslkeystorepassword"
+                    | "ssltruststorepassword"
+            );
+
+            out.push(if i == 0 { '?' } else { '&' });
+            out.push_str(&encode(key, Component::Query));
+            out.push('=');
+
+            match secret && redact {
+                true => out.push_str("***"),
+                false => out.push_str(&encode(value, Component::Query)),
+            }
+        }
+
+        out
+    }
+}
+
+/// Formats the url with its secrets redacted.
+impl fmt::Display for TrinoConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.url(true))
+    }
+}
+
+/// Formats the url with its secrets redacted.
+impl fmt::Debug for TrinoConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("TrinoConnStr")
+            .field(&self.url(true))
+            .finish()
+    }
+}
+
+#[test]
+fn catalog_and_schema_are_optional() {
+    let conn =
+        TrinoConnStr::from_str("JDBC:presto://[::1]:443?user=me&password=p&accessToken=t").unwrap();
+
+    assert!(conn.is_presto());
+    assert_eq!(None, conn.catalog());
+    assert!(conn.ssl().unwrap());
+    assert!(conn.session_properties().is_empty());
+    assert_eq!(
+        "jdbc:presto://[::1]:443?user=me&password=***&accessToken=***",
+        conn.to_string()
+    );
+    assert!(!format!("{:?}", conn).contains("password=p"));
+
+    assert!(TrinoConnStr::from_str("jdbc:trino://h1,h2/hive").is_err());
+    assert!(TrinoConnStr::from_str("jdbc:trino://me@h1/hive").is_err());
+    assert!(TrinoConnStr::from_str("jdbc:trino://h1/hive/sales/x").is_err());
+    assert!(TrinoConnStr::from_str("jdbc:sqlserver://h1").is_err());
+}
@@ -0,0 +1,127 @@
+//! Splitting of the URL-style connection strings,
+//! `scheme://[user[:password]@][host[:port][,...]][/path][?key=value&...]`.
+//!
+//! The parts are kept as written, the positions of the decoding errors being reported
+//! within the whole connection string.
+
+use crate::{percent, Error};
+
+/// The parts of a URL, not yet decoded.
+pub(crate) struct Url<'a> {
+    source: &'a str,
+
+    pub user: Option<&'a str>,
+    pub password: Option<&'a str>,
+
+    /// The hosts separated by commas, empty when missing.
+    pub hosts: &'a str,
+
+    /// The path, without its leading `/`.
+    pub path: &'a str,
+
+    pub query: Option<&'a str>,
+}
+
+impl<'a> Url<'a> {
+    /// Splits a URL, `None` when it does not start with a scheme among `schemes`,
+    /// ignoring case.
+    pub fn parse(source: &'a str, schemes: &[&str]) -> Option<Self> {
+        let trimmed = source.trim();
+        let (scheme, rest) = trimmed.split_once("://")?;
+
+        if !schemes.iter().any(|s| s.eq_ignore_ascii_case(scheme)) {
+            return None;
+        }
+
+        let (rest, query) = match rest.split_once('?') {
+            Some((rest, query)) => (rest, Some(query)),
+            None => (rest, None),
+        };
+
+        let (authority, path) = rest.split_once('/').unwrap_or((rest, ""));
+
+        let (user_info, hosts) = match authority.rsplit_once('@') {
+            Some((user_info, hosts)) => (Some(user_info), hosts),
+            None => (None, authority),
+        };
+
+        let (user, password) = match user_info.map(|u| u.split_once(':')) {
+            Some(Some((user, password))) => (Some(user), Some(password)),
+            Some(None) => (user_info, None),
+            None => (None, None),
+        };
+
+        Some(Url {
+            source,
+            user: user.filter(|u| !u.is_empty()),
+            password,
+            hosts,
+            path,
+            query,
+        })
+    }
+
+    /// Decodes the `%XX` escapes of a part of the URL.
+    pub fn decode(&self, part: &str) -> Result<String, Error> {
+        percent::decode(part)
+            .map(|v| v.into_owned())
+            .map_err(|e| match e {
+                Error::SyntaxError(i) => Error::SyntaxError(self.index(part) + i),
+                Error::InvalidEncoding(i) => Error::InvalidEncoding(self.index(part) + i),
+                e => e,
+            })
+    }
+
+    /// Splits the hosts into their decoded name and their port as written, empty when
+    /// missing, IPv6 addresses being written between brackets.
+    pub fn hosts(&self) -> Result<Vec<(String, &'a str)>, Error> {
+        if self.hosts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.hosts
+            .split(',')
+            .map(|host| {
+                let (name, port) = match host.strip_prefix('[') {
+                    Some(rest) => {
+                        let (name, rest) = rest
+                            .split_once(']')
+                            .ok_or_else(|| Error::SyntaxError(self.index(host)))?;
+
+                        match rest {
+                            "" => (name, ""),
+                            _ => match rest.strip_prefix(':') {
+                                Some(port) => (name, port),
+                                None => return Err(Error::SyntaxError(self.index(rest))),
+                            },
+                        }
+                    }
+                    None => host.rsplit_once(':').unwrap_or((host, "")),
+                };
+
+                Ok((self.decode(name)?, port))
+            })
+            .collect()
+    }
+
+    /// Gets the position of a part within the connection string.
+    pub fn index(&self, part: &str) -> usize {
+        part.as_ptr() as usize - self.source.as_ptr() as usize
+    }
+
+    /// Decodes the `key=value` pairs of the query, with their position.
+    pub fn query_pairs(&self) -> Result<Vec<(String, String, usize)>, Error> {
+        let params = self.query.into_iter().flat_map(|q| q.split('&'));
+
+        params
+            .filter(|param| !param.is_empty())
+            .map(|param| {
+                let (key, value) = param
+                    .split_once('=')
+                    .ok_or_else(|| Error::SyntaxError(self.index(param)))?;
+
+                Ok((self.decode(key)?, self.decode(value)?, self.index(param)))
+            })
+            .collect()
+    }
+}
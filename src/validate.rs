@@ -0,0 +1,1208 @@
+//! Validation of parsed connection strings.
+//!
+//! Validation is a separate phase from parsing: it reports a list of issues and lets
+//! the caller decide whether to warn or to fail.
+//!
+//! Every connection string backed by a keyword table has a `validate` method checking
+//! its keys and values against the table. The URLs parsed without a keyword table,
+//! such as the [`JdbcUrl`](crate::jdbc::JdbcUrl), the [`AmqpUri`](crate::amqp::AmqpUri)
+//! and the Neo4j and Trino URLs, are checked by their parsing only.
+
+use crate::audit;
+use crate::keywords::{Keywords, ValueKind};
+use crate::schema::SchemaConnStr;
+use crate::server::{split_protocol, ServerProtocol};
+use crate::{
+    parse_bool, parse_port, secret_eq, Authentication, EFConnStr, EncryptMode, MsSqlConnStr,
+};
+use std::collections::HashMap;
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+#[cfg(test)]
+use crate::schema::{Schema, SchemaKey};
+#[cfg(test)]
+use std::str::FromStr;
+
+/// The severity of a [`ValidationIssue`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        })
+    }
+}
+
+/// An issue found while validating a connection string.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValidationIssue {
+    /// The stable identifier of the rule that produced the issue.
+    pub rule: &'static str,
+
+    pub severity: Severity,
+
+    /// The keys involved, in lower case.
+    pub keys: Vec<String>,
+
+    pub message: String,
+
+    /// A machine readable fix, such as the keyword to use instead.
+    pub suggestion: Option<String>,
+}
+
+impl ValidationIssue {
+    pub(crate) fn new(
+        rule: &'static str,
+        severity: Severity,
+        keys: &[&str],
+        message: String,
+    ) -> Self {
+        ValidationIssue {
+            rule,
+            severity,
+            keys: keys.iter().map(|k| (*k).to_owned()).collect(),
+            message,
+            suggestion: None,
+        }
+    }
+
+    pub(crate) fn suggest(mut self, suggestion: &str) -> Self {
+        self.suggestion = Some(suggestion.to_owned());
+        self
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} [{}]: {}", self.severity, self.rule, self.message)
+    }
+}
+
+/// Rule reporting a key unknown to the format.
+pub const UNKNOWN_KEY: &str = "unknown-key";
+
+/// Rule reporting a value outside the domain of its keyword.
+pub const INVALID_VALUE: &str = "invalid-value";
+
+/// Rule reporting a deprecated or ignored keyword, or a renamed synonym.
+pub const DEPRECATED_KEY: &str = "deprecated-key";
+
+/// Rule reporting synonyms of the same keyword set to different values.
+pub const CONFLICTING_SYNONYMS: &str = "conflicting-synonyms";
+
+/// Rule reporting a secret rejected by the password policy of a [`Validator`].
+pub const PASSWORD_POLICY: &str = "password-policy";
+
+/// A validation configured with additional checks.
+///
+/// The severity of the rules can be overridden, for instance to accept the existing
+/// configurations of an estate while keeping new ones to a stricter standard.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::audit::{ENCRYPT_NOT_SET, TRUST_SERVER_CERTIFICATE};
+/// use conn_str::validate::{Severity, Validator, UNKNOWN_KEY};
+/// use conn_str::MsSqlConnStr;
+/// use std::str::FromStr;
+///
+/// let conn = MsSqlConnStr::from_str("server=.;trust server certificate=true;x=1").unwrap();
+/// let validator = Validator::new()
+///     .environment("dev")
+///     .allow(UNKNOWN_KEY)
+///     .allow(ENCRYPT_NOT_SET)
+///     .severity_in("dev", TRUST_SERVER_CERTIFICATE, Severity::Warning);
+///
+/// assert!(conn.validate_with(&validator).is_empty());
+///
+/// let issues = validator.apply(conn.security_audit());
+/// assert_eq!(1, issues.len());
+/// assert_eq!(Severity::Warning, issues[0].severity);
+/// ```
+#[derive(Default)]
+pub struct Validator<'a> {
+    environment: Option<String>,
+    overrides: Vec<RuleOverride>,
+    password_policy: Option<Box<PasswordPolicy<'a>>>,
+    required: Vec<&'static str>,
+}
+
+type PasswordPolicy<'a> = dyn Fn(&str) -> Result<(), String> + 'a;
+
+/// Changes the severity of the issues of a rule, or suppresses them when the
+/// severity is missing.
+struct RuleOverride {
+    environment: Option<String>,
+    rule: String,
+    key: Option<String>,
+    severity: Option<Severity>,
+}
+
+impl<'a> Validator<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Suppresses the issues of a rule.
+    pub fn allow(self, rule: &str) -> Self {
+        self.add_override(None, rule, None, None)
+    }
+
+    /// Suppresses the issues of a rule in an environment.
+    pub fn allow_in(self, environment: &str, rule: &str) -> Self {
+        self.add_override(Some(environment), rule, None, None)
+    }
+
+    /// Suppresses the issues of a rule involving a key, ignoring case.
+    pub fn allow_key(self, rule: &str, key: &str) -> Self {
+        self.add_override(None, rule, Some(key), None)
+    }
+
+    /// Applies the overrides of the issues to a list of issues, such as the result of
+    /// a security audit.
+    ///
+    /// The last override matching an issue wins.
+    pub fn apply(&self, issues: Vec<ValidationIssue>) -> Vec<ValidationIssue> {
+        issues
+            .into_iter()
+            .filter_map(|mut issue| {
+                let found = self.overrides.iter().rev().find(|o| {
+                    o.rule == issue.rule
+                        && (o.environment.is_none() || o.environment == self.environment)
+                        && o.key.as_ref().is_none_or(|k| issue.keys.contains(k))
+                });
+
+                match found {
+                    Some(o) => o.severity.map(|severity| {
+                        issue.severity = severity;
+                        issue
+                    }),
+                    None => Some(issue),
+                }
+            })
+            .collect()
+    }
+
+    /// Sets the environment of the validation, which selects the overrides scoped to it.
+    pub fn environment(mut self, environment: &str) -> Self {
+        self.environment = Some(environment.to_owned());
+        self
+    }
+
+    /// Adds the security requirements of a profile, reported as errors.
+    pub fn profile(mut self, profile: Profile) -> Self {
+        self.required.extend_from_slice(profile.rules());
+        self
+    }
+
+    /// Sets the severity of the issues of a rule, to downgrade or escalate them.
+    pub fn severity(self, rule: &str, severity: Severity) -> Self {
+        self.add_override(None, rule, None, Some(severity))
+    }
+
+    /// Sets the severity of the issues of a rule in an environment.
+    pub fn severity_in(self, environment: &str, rule: &str, severity: Severity) -> Self {
+        self.add_override(Some(environment), rule, None, Some(severity))
+    }
+
+    fn add_override(
+        mut self,
+        environment: Option<&str>,
+        rule: &str,
+        key: Option<&str>,
+        severity: Option<Severity>,
+    ) -> Self {
+        self.overrides.push(RuleOverride {
+            environment: environment.map(|e| e.to_owned()),
+            rule: rule.to_owned(),
+            key: key.map(|k| k.trim().to_lowercase()),
+            severity,
+        });
+        self
+    }
+
+    /// Sets a policy applied to the credential values, such as the passwords.
+    ///
+    /// The policy returns the reason of the rejection of a value, which is reported as
+    /// an issue instead of a parse error.
+    pub fn password_policy<F>(mut self, policy: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + 'a,
+    {
+        self.password_policy = Some(Box::new(policy));
+        self
+    }
+
+    fn secrets(
+        &self,
+        map: &HashMap<String, String>,
+        keywords: &Keywords,
+        issues: &mut Vec<ValidationIssue>,
+    ) {
+        for keyword in keywords.iter().filter(|k| k.kind == ValueKind::Secret) {
+            if let Some((key, value)) = keyword.names().find_map(|n| map.get_key_value(n)) {
+                self.secret(key, value, issues);
+            }
+        }
+    }
+
+    /// Reports the issues of the audit required by the profiles.
+    fn requirements(&self, audit: Vec<ValidationIssue>, issues: &mut Vec<ValidationIssue>) {
+        for mut issue in audit {
+            if self.required.contains(&issue.rule) {
+                issue.severity = Severity::Error;
+                issues.push(issue);
+            }
+        }
+    }
+
+    fn secret(&self, key: &str, value: &str, issues: &mut Vec<ValidationIssue>) {
+        if let Some(policy) = &self.password_policy {
+            if let Err(message) = policy(value) {
+                issues.push(ValidationIssue::new(
+                    PASSWORD_POLICY,
+                    Severity::Error,
+                    &[key],
+                    message,
+                ));
+            }
+        }
+    }
+}
+
+impl<'a> From<Profile> for Validator<'a> {
+    fn from(profile: Profile) -> Self {
+        Validator::new().profile(profile)
+    }
+}
+
+/// A named set of security requirements, the rules of the [`audit`]
+/// that a connection string must pass.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::audit::TRUST_SERVER_CERTIFICATE;
+/// use conn_str::validate::{Profile, Severity, Validator};
+/// use conn_str::MsSqlConnStr;
+/// use std::str::FromStr;
+///
+/// let conn = MsSqlConnStr::from_str("server=.;encrypt=true;trust server certificate=true").unwrap();
+/// let issues = conn.validate_with(&Profile::Strict.into());
+///
+/// assert_eq!(1, issues.len());
+/// assert_eq!(TRUST_SERVER_CERTIFICATE, issues[0].rule);
+/// assert_eq!(Severity::Error, issues[0].severity);
+///
+/// // composes a profile with other checks
+/// let validator = Validator::new().profile(Profile::Custom(vec![TRUST_SERVER_CERTIFICATE]));
+/// assert_eq!(1, conn.validate_with(&validator).len());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Profile {
+    /// Requires an encrypted connection, a validated server certificate, and a password
+    /// not kept in the opened connection.
+    Strict,
+
+    /// The strict profile, also requiring an integrated or Azure Active Directory
+    /// authentication instead of a SQL Server login.
+    AzureBaseline,
+
+    /// Requires the listed rules of the audit.
+    Custom(Vec<&'static str>),
+}
+
+impl Profile {
+    /// Gets the rules of the audit required by the profile.
+    pub fn rules(&self) -> &[&'static str] {
+        const STRICT: &[&str] = &[
+            audit::ENCRYPT_DISABLED,
+            audit::ENCRYPT_NOT_SET,
+            audit::TRUST_SERVER_CERTIFICATE,
+            audit::PERSIST_SECURITY_INFO,
+        ];
+
+        const AZURE_BASELINE: &[&str] = &[
+            audit::ENCRYPT_DISABLED,
+            audit::ENCRYPT_NOT_SET,
+            audit::TRUST_SERVER_CERTIFICATE,
+            audit::PERSIST_SECURITY_INFO,
+            audit::SQL_AUTHENTICATION,
+        ];
+
+        match self {
+            Profile::Strict => STRICT,
+            Profile::AzureBaseline => AZURE_BASELINE,
+            Profile::Custom(rules) => rules,
+        }
+    }
+}
+
+/// Rule reporting an Entity Framework `name` combined with other keys.
+pub const NAME_WITH_OTHER_KEYS: &str = "name-with-other-keys";
+
+pub(crate) fn ef(conn: &EFConnStr, validator: &Validator) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+
+    keywords(&conn.0, &crate::keywords::EF, &mut issues);
+    validator.secrets(&conn.0, &crate::keywords::EF, &mut issues);
+
+    if conn.name().is_some() && conn.0.len() > 1 {
+        issues.push(ValidationIssue::new(
+            NAME_WITH_OTHER_KEYS,
+            Severity::Error,
+            &["name"],
+            "`name` cannot be combined with other keys".to_owned(),
+        ));
+    }
+
+    if conn.name().is_none() {
+        for key in conn.missing_keys() {
+            issues.push(ValidationIssue::new(
+                MISSING_REQUIRED_KEY,
+                Severity::Error,
+                &[key],
+                format!("key `{}` is required", key),
+            ));
+        }
+    }
+
+    validator.requirements(conn.security_audit(), &mut issues);
+    validator.apply(issues)
+}
+
+/// Rule reporting a key mandatory to the format, or required by a
+/// [`Schema`](crate::schema::Schema), but missing.
+pub const MISSING_REQUIRED_KEY: &str = "missing-required-key";
+
+pub(crate) fn schema(conn: &SchemaConnStr, validator: &Validator) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    let map = &conn.map;
+
+    let mut keys: Vec<_> = map.keys().collect();
+    keys.sort();
+
+    for key in keys {
+        let value = &map[key];
+
+        match conn.schema().find(key) {
+            Some(k) if !k.kind.accepts(value) => issues.push(ValidationIssue::new(
+                INVALID_VALUE,
+                Severity::Error,
+                &[key],
+                format!(
+                    "`{}` is not a valid value for `{}`, expected {}",
+                    value, key, k.kind
+                ),
+            )),
+            Some(_) => {}
+            None => issues.push(ValidationIssue::new(
+                UNKNOWN_KEY,
+                Severity::Warning,
+                &[key],
+                format!("key `{}` is not recognized", key),
+            )),
+        }
+    }
+
+    for k in conn.schema().iter() {
+        let mut names = k
+            .names()
+            .filter_map(|n| map.get_key_value(n).map(|(k, v)| (k.as_str(), v)));
+
+        let (used, value) = match names.next() {
+            Some(first) => first,
+            None => {
+                if k.required {
+                    issues.push(ValidationIssue::new(
+                        MISSING_REQUIRED_KEY,
+                        Severity::Error,
+                        &[&k.name],
+                        format!("key `{}` is required", k.name),
+                    ));
+                }
+                continue;
+            }
+        };
+
+        if k.is_sensitive() {
+            validator.secret(used, value, &mut issues);
+        }
+
+        let kind = if k.is_sensitive() {
+            ValueKind::Secret
+        } else {
+            k.kind
+        };
+
+        for (key, other) in names {
+            if !same_value(kind, value, other) {
+                issues.push(ValidationIssue::new(
+                    CONFLICTING_SYNONYMS,
+                    Severity::Error,
+                    &[used, key],
+                    format!(
+                        "`{}` and `{}` are set to different values, `{}` is used",
+                        used, key, used
+                    ),
+                ));
+            }
+        }
+    }
+
+    validator.apply(issues)
+}
+
+/// Validates a connection string against the keyword table of its format, for the
+/// formats without rules of their own.
+pub(crate) fn table(
+    map: &HashMap<String, String>,
+    table: &Keywords,
+    validator: &Validator,
+) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+
+    keywords(map, table, &mut issues);
+    validator.secrets(map, table, &mut issues);
+    validator.apply(issues)
+}
+
+/// Rule reporting a min pool size greater than the max pool size.
+pub const POOL_SIZE_CONFLICT: &str = "pool-size-conflict";
+
+/// Rule reporting a failover partner combined with multi subnet failover.
+pub const FAILOVER_PARTNER_CONFLICT: &str = "failover-partner-conflict";
+
+pub(crate) fn ms_sql(conn: &MsSqlConnStr, validator: &Validator) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+
+    keywords(&conn.0, &crate::keywords::MS_SQL, &mut issues);
+    validator.secrets(&conn.0, &crate::keywords::MS_SQL, &mut issues);
+
+    if let (Some(min), Some(max)) = (conn.get_int("min pool size"), conn.get_int("max pool size")) {
+        if min > max {
+            issues.push(ValidationIssue::new(
+                POOL_SIZE_CONFLICT,
+                Severity::Error,
+                &["min pool size", "max pool size"],
+                format!(
+                    "min pool size `{}` is greater than max pool size `{}`",
+                    min, max
+                ),
+            ));
+        }
+    }
+
+    if conn.get("failover partner").is_some()
+        && matches!(
+            conn.get("multi subnet failover").map(parse_bool),
+            Some(Ok(true))
+        )
+    {
+        issues.push(ValidationIssue::new(
+            FAILOVER_PARTNER_CONFLICT,
+            Severity::Error,
+            &["failover partner", "multi subnet failover"],
+            "`multi subnet failover` is not supported with `failover partner`".to_owned(),
+        ));
+    }
+
+    credentials(conn, &mut issues);
+    encryption(conn, &mut issues);
+    active_directory(conn, &mut issues);
+
+    let checks: [(&str, &str, SyntaxCheck); 4] = [
+        ("data source", INVALID_DATA_SOURCE, data_source),
+        ("failover partner", INVALID_DATA_SOURCE, data_source),
+        ("initial catalog", INVALID_DATABASE_NAME, database_name),
+        (
+            "application name",
+            INVALID_APPLICATION_NAME,
+            application_name,
+        ),
+    ];
+
+    for (name, rule, check) in checks.iter() {
+        if let Some((key, value)) = crate::keywords::MS_SQL
+            .get(name)
+            .and_then(|k| k.names().find_map(|n| conn.0.get_key_value(n)))
+        {
+            if let Err(message) = check(value) {
+                issues.push(ValidationIssue::new(rule, Severity::Error, &[key], message));
+            }
+        }
+    }
+
+    validator.requirements(audit::ms_sql(conn), &mut issues);
+    validator.apply(issues)
+}
+
+/// Rule reporting integrated security combined with a user id or a password.
+pub const INTEGRATED_SECURITY_WITH_CREDENTIALS: &str = "integrated-security-with-credentials";
+
+/// Rule reporting an authentication method combined with settings it does not support.
+pub const AUTHENTICATION_CONFLICT: &str = "authentication-conflict";
+
+/// Rule reporting an access token combined with other credentials.
+pub const ACCESS_TOKEN_CONFLICT: &str = "access-token-conflict";
+
+fn credentials(conn: &MsSqlConnStr, issues: &mut Vec<ValidationIssue>) {
+    let authentication = conn.authentication().ok().flatten();
+
+    if conn.access_token().is_some() {
+        let mut keys: Vec<_> = ["user id", "password", "authentication"]
+            .iter()
+            .filter(|k| conn.get(k).is_some())
+            .cloned()
+            .collect();
+
+        if conn.integrated_security().unwrap_or(false) {
+            keys.push("integrated security");
+        }
+
+        if !keys.is_empty() {
+            let message = format!(
+                "`access token` cannot be used with `{}`, the connection fails to open",
+                keys.join("` or `")
+            );
+            keys.insert(0, "access token");
+
+            issues.push(ValidationIssue::new(
+                ACCESS_TOKEN_CONFLICT,
+                Severity::Error,
+                &keys,
+                message,
+            ));
+        }
+    }
+
+    if conn.integrated_security().unwrap_or(false) {
+        let keys: Vec<_> = ["user id", "password"]
+            .iter()
+            .filter(|k| conn.get(k).is_some())
+            .cloned()
+            .collect();
+
+        if !keys.is_empty() {
+            let mut all = vec!["integrated security"];
+            all.extend(&keys);
+
+            issues.push(ValidationIssue::new(
+                INTEGRATED_SECURITY_WITH_CREDENTIALS,
+                Severity::Warning,
+                &all,
+                format!(
+                    "`integrated security` wins, the Windows account is used and `{}` is ignored",
+                    keys.join("` and `")
+                ),
+            ));
+        }
+
+        if let Some(authentication) = authentication {
+            issues.push(ValidationIssue::new(
+                AUTHENTICATION_CONFLICT,
+                Severity::Error,
+                &["integrated security", "authentication"],
+                format!(
+                    "`authentication={}` cannot be used with `integrated security`, the connection fails to open",
+                    authentication
+                ),
+            ));
+        }
+    }
+
+    if authentication == Some(Authentication::ActiveDirectoryIntegrated)
+        && conn.password().is_some()
+    {
+        issues.push(ValidationIssue::new(
+            AUTHENTICATION_CONFLICT,
+            Severity::Error,
+            &["authentication", "password"],
+            format!(
+                "`authentication={}` cannot be used with a password, the connection fails to open",
+                Authentication::ActiveDirectoryIntegrated
+            ),
+        ));
+    }
+}
+
+/// Rule reporting an Azure Active Directory authentication missing its user id or password.
+pub const ACTIVE_DIRECTORY_MISSING_CREDENTIALS: &str = "active-directory-missing-credentials";
+
+/// Rule reporting a password given to an Azure Active Directory authentication not using it.
+pub const ACTIVE_DIRECTORY_PASSWORD_NOT_SUPPORTED: &str = "active-directory-password-not-supported";
+
+/// Rule reporting an Azure Active Directory authentication prompting the user.
+pub const ACTIVE_DIRECTORY_INTERACTIVE: &str = "active-directory-interactive";
+
+fn active_directory(conn: &MsSqlConnStr, issues: &mut Vec<ValidationIssue>) {
+    use Authentication::*;
+
+    let authentication = match conn.authentication() {
+        Ok(Some(a)) => a,
+        _ => return,
+    };
+
+    match authentication {
+        ActiveDirectoryPassword | ActiveDirectoryServicePrincipal => {
+            let missing: Vec<_> = ["user id", "password"]
+                .iter()
+                .filter(|k| conn.get(k).is_none())
+                .cloned()
+                .collect();
+
+            if !missing.is_empty() {
+                let what = if authentication == ActiveDirectoryServicePrincipal {
+                    "the client id as `user id` and the secret as `password`"
+                } else {
+                    "a `user id` and a `password`"
+                };
+
+                let mut keys = vec!["authentication"];
+                keys.extend(&missing);
+
+                issues.push(ValidationIssue::new(
+                    ACTIVE_DIRECTORY_MISSING_CREDENTIALS,
+                    Severity::Error,
+                    &keys,
+                    format!("`authentication={}` requires {}", authentication, what),
+                ));
+            }
+        }
+        ActiveDirectoryManagedIdentity
+        | ActiveDirectoryDefault
+        | ActiveDirectoryWorkloadIdentity
+        | ActiveDirectoryInteractive
+        | ActiveDirectoryDeviceCodeFlow => {
+            if conn.password().is_some() {
+                issues.push(ValidationIssue::new(
+                    ACTIVE_DIRECTORY_PASSWORD_NOT_SUPPORTED,
+                    Severity::Error,
+                    &["authentication", "password"],
+                    format!(
+                        "`authentication={}` cannot be used with a password",
+                        authentication
+                    ),
+                ));
+            }
+        }
+        ActiveDirectoryIntegrated | SqlPassword => {}
+    }
+
+    if authentication == ActiveDirectoryInteractive
+        || authentication == ActiveDirectoryDeviceCodeFlow
+    {
+        issues.push(ValidationIssue::new(
+            ACTIVE_DIRECTORY_INTERACTIVE,
+            Severity::Warning,
+            &["authentication"],
+            format!(
+                "`authentication={}` prompts the user and cannot be used by services",
+                authentication
+            ),
+        ));
+    }
+}
+
+/// Rule reporting a strict encryption combined with a trusted server certificate.
+pub const STRICT_ENCRYPTION_CONFLICT: &str = "strict-encryption-conflict";
+
+/// Rule reporting a host name in certificate without encryption.
+pub const HOST_NAME_IN_CERTIFICATE_WITHOUT_ENCRYPTION: &str =
+    "host-name-in-certificate-without-encryption";
+
+fn encryption(conn: &MsSqlConnStr, issues: &mut Vec<ValidationIssue>) {
+    let mode = match conn.encrypt_mode() {
+        Ok(mode) => mode,
+        Err(_) => return,
+    };
+
+    if mode == EncryptMode::Strict && conn.trust_server_certificate().unwrap_or(false) {
+        issues.push(ValidationIssue::new(
+            STRICT_ENCRYPTION_CONFLICT,
+            Severity::Error,
+            &["encrypt", "trust server certificate"],
+            "`trust server certificate` cannot be used with `encrypt=strict`, the server certificate is always validated".to_owned(),
+        ));
+    }
+
+    if mode == EncryptMode::Optional && conn.get("host name in certificate").is_some() {
+        issues.push(ValidationIssue::new(
+            HOST_NAME_IN_CERTIFICATE_WITHOUT_ENCRYPTION,
+            Severity::Warning,
+            &["host name in certificate", "encrypt"],
+            "`host name in certificate` is only used when the connection is encrypted".to_owned(),
+        ));
+    }
+}
+
+type SyntaxCheck = fn(&str) -> Result<(), String>;
+
+/// Rule reporting a malformed server name.
+pub const INVALID_DATA_SOURCE: &str = "invalid-data-source";
+
+/// Rule reporting a database name breaking the identifier rules.
+pub const INVALID_DATABASE_NAME: &str = "invalid-database-name";
+
+/// Rule reporting an application name too long.
+pub const INVALID_APPLICATION_NAME: &str = "invalid-application-name";
+
+/// The maximum length of the SQL Server identifiers and of the application name.
+const MAX_NAME_LEN: usize = 128;
+
+/// Checks the syntax of a SQL Server data source.
+///
+/// The accepted form is `[protocol:]server[\instance][,port]` where the server is a
+/// host name, an IPv4 or an IPv6 address, `.`, `(local)` or `(localdb)`.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::validate::data_source;
+///
+/// assert!(data_source("tcp:db.contoso.com,1433").is_ok());
+/// assert!(data_source(".\\SQL2017").is_ok());
+/// assert!(data_source("2001:db8::1,1433").is_ok());
+/// assert!(data_source("db server").is_err());
+/// assert!(data_source("db,99999").is_err());
+/// ```
+pub fn data_source(s: &str) -> Result<(), String> {
+    let s = s.trim();
+
+    let s = match split_protocol(s) {
+        // named pipes are paths, not host names.
+        (Some(ServerProtocol::NamedPipes), _) => return Ok(()),
+        (_, s) => s,
+    };
+
+    let (s, port) = match s.rfind(',') {
+        Some(i) => (&s[..i], Some(s[i + 1..].trim())),
+        None => (s, None),
+    };
+
+    if let Some(port) = port {
+        parse_port("port", port).map_err(|_| format!("`{}` is not a valid port", port))?;
+    }
+
+    let (host, instance) = match s.find('\\') {
+        Some(i) => (s[..i].trim(), Some(&s[i + 1..])),
+        None => (s.trim(), None),
+    };
+
+    if let Some(instance) = instance {
+        instance_name(instance)?;
+    }
+
+    host_name(host)
+}
+
+fn host_name(host: &str) -> Result<(), String> {
+    let lower = host.to_lowercase();
+
+    if host == "." || lower == "(local)" || lower == "(localdb)" {
+        return Ok(());
+    }
+
+    if host.contains(':') {
+        let ip = host.trim_start_matches('[').trim_end_matches(']');
+
+        return match ip.parse::<Ipv6Addr>() {
+            Ok(_) => Ok(()),
+            Err(_) => Err(format!("`{}` is not a valid IPv6 address", host)),
+        };
+    }
+
+    if host.parse::<Ipv4Addr>().is_ok() {
+        return Ok(());
+    }
+
+    let valid = !host.is_empty()
+        && host.len() <= 255
+        && host.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        });
+
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("`{}` is not a valid host name", host))
+    }
+}
+
+fn instance_name(instance: &str) -> Result<(), String> {
+    let valid = !instance.is_empty()
+        && instance.chars().count() <= 16
+        && instance.starts_with(|c: char| c.is_alphabetic() || c == '_')
+        && instance
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '$' || c == '#');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("`{}` is not a valid instance name", instance))
+    }
+}
+
+/// Checks a database name against the SQL Server identifier rules.
+///
+/// The name cannot be blank, be longer than 128 characters or contain control
+/// characters.
+pub fn database_name(s: &str) -> Result<(), String> {
+    if s.trim().is_empty() {
+        Err("the database name is blank".to_owned())
+    } else if s.chars().count() > MAX_NAME_LEN {
+        Err(format!(
+            "the database name is longer than {} characters",
+            MAX_NAME_LEN
+        ))
+    } else if s.chars().any(char::is_control) {
+        Err(format!("`{}` contains control characters", s))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that an application name is not longer than 128 characters.
+pub fn application_name(s: &str) -> Result<(), String> {
+    if s.chars().count() > MAX_NAME_LEN {
+        Err(format!(
+            "the application name is longer than {} characters",
+            MAX_NAME_LEN
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks the keys and values of a connection string against a keyword table.
+fn keywords(map: &HashMap<String, String>, keywords: &Keywords, issues: &mut Vec<ValidationIssue>) {
+    let mut keys: Vec<_> = map.keys().collect();
+    keys.sort();
+
+    for key in keys {
+        match keywords.find(key) {
+            Some(keyword) => {
+                let value = &map[key];
+
+                if let Some(deprecation) = &keyword.deprecated {
+                    let issue = ValidationIssue::new(
+                        DEPRECATED_KEY,
+                        Severity::Warning,
+                        &[key],
+                        format!("key `{}` is deprecated: {}", key, deprecation.reason),
+                    );
+
+                    issues.push(match deprecation.replacement {
+                        Some(replacement) => issue.suggest(replacement),
+                        None => issue,
+                    });
+                } else if keyword.deprecated_synonyms.contains(&key.as_str()) {
+                    issues.push(
+                        ValidationIssue::new(
+                            DEPRECATED_KEY,
+                            Severity::Warning,
+                            &[key],
+                            format!("key `{}` was renamed to `{}`", key, keyword.name),
+                        )
+                        .suggest(keyword.name),
+                    );
+                }
+
+                if !keyword.kind.accepts(value) {
+                    issues.push(ValidationIssue::new(
+                        INVALID_VALUE,
+                        Severity::Error,
+                        &[key],
+                        format!(
+                            "`{}` is not a valid value for `{}`, expected {}",
+                            value, key, keyword.kind
+                        ),
+                    ));
+                }
+            }
+            None => issues.push(ValidationIssue::new(
+                UNKNOWN_KEY,
+                Severity::Warning,
+                &[key],
+                format!("key `{}` is not recognized", key),
+            )),
+        }
+    }
+
+    for keyword in keywords.iter() {
+        let mut names = keyword
+            .names()
+            .filter_map(|n| map.get_key_value(n).map(|(k, v)| (k.as_str(), v)));
+
+        let (used, value) = match names.next() {
+            Some(first) => first,
+            None => continue,
+        };
+
+        for (key, other) in names {
+            if !same_value(keyword.kind, value, other) {
+                issues.push(ValidationIssue::new(
+                    CONFLICTING_SYNONYMS,
+                    Severity::Error,
+                    &[used, key],
+                    format!(
+                        "`{}` and `{}` are set to different values, `{}` is used",
+                        used, key, used
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+fn same_value(kind: ValueKind, a: &str, b: &str) -> bool {
+    match kind {
+        ValueKind::Secret => secret_eq(a, b),
+        ValueKind::List { .. } | ValueKind::Path | ValueKind::Text => a == b,
+        ValueKind::Bool => match (parse_bool(a), parse_bool(b)) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => a.trim().eq_ignore_ascii_case(b.trim()),
+        },
+        ValueKind::Duration { .. } | ValueKind::Enum(_) | ValueKind::Int { .. } => {
+            a.trim().eq_ignore_ascii_case(b.trim())
+        }
+    }
+}
+
+#[test]
+fn deprecated_keys_are_reported_with_their_replacement() {
+    let conn = MsSqlConnStr::from_str("server=.;async=true;network library=dbmssocn").unwrap();
+    let issues: Vec<_> = conn
+        .validate()
+        .into_iter()
+        .filter(|i| i.rule == DEPRECATED_KEY)
+        .collect();
+
+    assert_eq!(2, issues.len());
+    assert_eq!(vec!["async"], issues[0].keys);
+    assert_eq!(None, issues[0].suggestion);
+    assert_eq!(Some("data source"), issues[1].suggestion.as_deref());
+
+    let conn = MsSqlConnStr::from_str("server=.;multisubnetfailover=true").unwrap();
+    let issues: Vec<_> = conn
+        .validate()
+        .into_iter()
+        .filter(|i| i.rule == DEPRECATED_KEY)
+        .collect();
+
+    assert_eq!(1, issues.len());
+    assert_eq!(
+        "key `multisubnetfailover` was renamed to `multi subnet failover`",
+        issues[0].message
+    );
+    assert_eq!(
+        Some("multi subnet failover"),
+        issues[0].suggestion.as_deref()
+    );
+}
+
+#[test]
+fn invalid_values_name_the_allowed_values() {
+    let conn = MsSqlConnStr::from_str("server=.;application intent=ReadMostly;timeout=-1").unwrap();
+    let messages: Vec<_> = conn.validate().into_iter().map(|i| i.message).collect();
+
+    assert_eq!(
+        vec![
+            "`ReadMostly` is not a valid value for `application intent`, expected one of `ReadOnly`, `ReadWrite`",
+            "`-1` is not a valid value for `timeout`, expected a number of seconds greater than or equal to 0",
+        ],
+        messages
+    );
+}
+
+#[test]
+fn conflicting_synonyms_are_reported_with_both_keys() {
+    let conn =
+        MsSqlConnStr::from_str("server=a;data source=b;pwd=x;password=y;encrypt=True;encrypt=no")
+            .unwrap();
+    let issues: Vec<_> = conn
+        .validate()
+        .into_iter()
+        .filter(|i| i.rule == CONFLICTING_SYNONYMS)
+        .collect();
+
+    assert_eq!(2, issues.len());
+    assert_eq!(vec!["data source", "server"], issues[0].keys);
+    assert_eq!(vec!["password", "pwd"], issues[1].keys);
+
+    let conn =
+        MsSqlConnStr::from_str("persistsecurityinfo=yes;persist security info=True").unwrap();
+    assert!(conn.validate().is_empty());
+}
+
+#[test]
+fn malformed_server_names_are_reported() {
+    assert!(data_source("(localdb)\\MSSQLLocalDB").is_ok());
+    assert!(data_source("np:\\\\srv\\pipe\\sql\\query").is_ok());
+    assert!(data_source("srv\\not-an-instance").is_err());
+
+    let conn = MsSqlConnStr::from_str("server=srv..local;database=Db1").unwrap();
+    let issues = conn.validate();
+
+    assert_eq!(1, issues.len());
+    assert_eq!(INVALID_DATA_SOURCE, issues[0].rule);
+    assert_eq!(vec!["server"], issues[0].keys);
+}
+
+#[test]
+fn integrated_security_with_credentials_is_reported() {
+    let conn = MsSqlConnStr::from_str("server=.;integrated security=sspi;uid=me;pwd=x").unwrap();
+    let issues = conn.validate();
+
+    assert_eq!(1, issues.len());
+    assert_eq!(INTEGRATED_SECURITY_WITH_CREDENTIALS, issues[0].rule);
+    assert_eq!(
+        vec!["integrated security", "user id", "password"],
+        issues[0].keys
+    );
+
+    let conn =
+        MsSqlConnStr::from_str("server=.;authentication=ActiveDirectoryIntegrated;pwd=x").unwrap();
+    let issues = conn.validate();
+
+    assert_eq!(1, issues.len());
+    assert_eq!(AUTHENTICATION_CONFLICT, issues[0].rule);
+}
+
+#[test]
+fn strict_encryption_conflicts_are_reported() {
+    let conn =
+        MsSqlConnStr::from_str("server=.;encrypt=Strict;trust server certificate=true").unwrap();
+    let rules: Vec<_> = conn.validate().into_iter().map(|i| i.rule).collect();
+    assert_eq!(vec![STRICT_ENCRYPTION_CONFLICT], rules);
+
+    let conn =
+        MsSqlConnStr::from_str("server=.;encrypt=false;hostnameincertificate=db.contoso.com")
+            .unwrap();
+    let rules: Vec<_> = conn.validate().into_iter().map(|i| i.rule).collect();
+    assert_eq!(vec![HOST_NAME_IN_CERTIFICATE_WITHOUT_ENCRYPTION], rules);
+}
+
+#[test]
+fn active_directory_combinations_are_checked() {
+    let rules = |s: &str| -> Vec<&str> {
+        MsSqlConnStr::from_str(s)
+            .unwrap()
+            .validate()
+            .into_iter()
+            .map(|i| i.rule)
+            .collect()
+    };
+
+    assert_eq!(
+        vec![ACTIVE_DIRECTORY_PASSWORD_NOT_SUPPORTED],
+        rules("server=.;authentication=Active Directory Managed Identity;pwd=x")
+    );
+    assert_eq!(
+        vec![ACTIVE_DIRECTORY_MISSING_CREDENTIALS],
+        rules("server=.;authentication=ActiveDirectoryServicePrincipal;user id=app")
+    );
+    assert_eq!(
+        vec![ACTIVE_DIRECTORY_INTERACTIVE],
+        rules("server=.;authentication=Active Directory Interactive;user id=me")
+    );
+    assert!(rules("server=.;authentication=Active Directory MSI;user id=client").is_empty());
+
+    let issues = MsSqlConnStr::from_str("server=.;accesstoken=eyJ0;uid=me;trusted_connection=yes")
+        .unwrap()
+        .validate();
+    let issue = issues
+        .iter()
+        .find(|i| i.rule == ACCESS_TOKEN_CONFLICT)
+        .unwrap();
+    assert_eq!(
+        vec!["access token", "user id", "integrated security"],
+        issue.keys
+    );
+}
+
+#[test]
+fn schema_reports_missing_required_keys_and_weak_secrets() {
+    let schema = Schema::new()
+        .key(SchemaKey::new("endpoint").required())
+        .and_then(|s| s.key(SchemaKey::new("api key").synonyms(&["key"]).sensitive()))
+        .unwrap();
+
+    let conn = schema.parse("api key=abc;key=abd;region=eu").unwrap();
+    let validator = Validator::new().password_policy(|s| match s.len() < 8 {
+        true => Err("too short".to_owned()),
+        false => Ok(()),
+    });
+
+    let rules: Vec<_> = conn
+        .validate_with(&validator)
+        .into_iter()
+        .map(|i| i.rule)
+        .collect();
+
+    assert_eq!(
+        vec![
+            UNKNOWN_KEY,
+            MISSING_REQUIRED_KEY,
+            PASSWORD_POLICY,
+            CONFLICTING_SYNONYMS
+        ],
+        rules
+    );
+}
+
+#[test]
+fn overrides_are_scoped_to_their_environment_and_key() {
+    let issues = vec![
+        ValidationIssue::new(UNKNOWN_KEY, Severity::Warning, &["a"], String::new()),
+        ValidationIssue::new(UNKNOWN_KEY, Severity::Warning, &["b"], String::new()),
+    ];
+
+    let validator = Validator::new()
+        .severity(UNKNOWN_KEY, Severity::Error)
+        .allow_key(UNKNOWN_KEY, "A")
+        .allow_in("prod", UNKNOWN_KEY);
+
+    let applied = validator.apply(issues.clone());
+    assert_eq!(1, applied.len());
+    assert_eq!(vec!["b"], applied[0].keys);
+    assert_eq!(Severity::Error, applied[0].severity);
+
+    assert!(validator.environment("prod").apply(issues).is_empty());
+}
+
+#[test]
+fn profiles_require_the_audit_rules_of_nested_connection_strings() {
+    let conn =
+        EFConnStr::from_str(r#"metadata=res://*/;provider=System.Data.SqlClient;provider connection string="server=.;user id=me;password=x""#)
+            .unwrap();
+
+    let rules = |profile| -> Vec<_> {
+        conn.validate_with(&Validator::from(profile))
+            .into_iter()
+            .map(|i| i.rule)
+            .collect()
+    };
+
+    assert_eq!(vec![audit::ENCRYPT_NOT_SET], rules(Profile::Strict));
+    assert_eq!(
+        vec![audit::ENCRYPT_NOT_SET, audit::SQL_AUTHENTICATION],
+        rules(Profile::AzureBaseline)
+    );
+}
@@ -0,0 +1,180 @@
+//! Vertica connection strings, as parsed by the Vertica ADO.NET provider.
+
+use crate::host::{Host, HostList};
+use crate::keywords;
+use crate::pg::SslMode;
+use crate::validate::{self, ValidationIssue, Validator};
+use crate::{
+    decode_bytes, get, parse, parse_bool, parse_port, to_connection_string, Error, ParseOptions,
+};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// A Vertica ADO.NET connection string.
+///
+/// The `BackupServerNode` lists the hosts tried in turn when the `Host` does not
+/// answer, each one possibly having its own port.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::host::Host;
+/// use conn_str::pg::SslMode;
+/// use conn_str::vertica::VerticaConnStr;
+/// use std::str::FromStr;
+///
+/// let conn = VerticaConnStr::from_str("Host=v1;Port=5433;Database=db;User=u;Password=p;BackupServerNode=v2,v3:5434;SSLMode=require").unwrap();
+///
+/// assert_eq!(Some("v1"), conn.host());
+/// assert_eq!(
+///     vec![Host::new("v2", None), Host::new("v3", Some(5434))],
+///     conn.backup_server_nodes().unwrap(),
+/// );
+/// assert_eq!(SslMode::Require, conn.ssl_mode().unwrap());
+/// assert_eq!(Some("u"), conn.user());
+/// ```
+#[derive(Clone)]
+pub struct VerticaConnStr(Arc<HashMap<String, String>>);
+
+impl FromStr for VerticaConnStr {
+    type Err = Error;
+
+    fn from_str(conn_str: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(conn_str, &ParseOptions::default())
+    }
+}
+
+impl VerticaConnStr {
+    /// Decodes the bytes with [`decode_bytes`] and parses the connection string.
+    pub fn parse_from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        Self::parse_with(&decode_bytes(bytes)?, options)
+    }
+
+    pub fn parse_with(conn_str: &str, options: &ParseOptions) -> Result<Self, Error> {
+        Ok(VerticaConnStr(Arc::new(parse(
+            conn_str,
+            false,
+            &keywords::VERTICA,
+            options,
+        )?)))
+    }
+
+    /// Gets the backup nodes as written, empty when missing. The driver connects to
+    /// the nodes without a port on the `Port`.
+    pub fn backup_server_nodes(&self) -> Result<Vec<Host>, Error> {
+        match self.get("backupservernode") {
+            Some(nodes) if !nodes.trim().is_empty() => Ok(HostList::parse_with(nodes, ',')?.hosts),
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    /// Indicates if the connection is redirected to a node chosen by the cluster.
+    pub fn connection_load_balance(&self) -> Result<bool, Error> {
+        match self.get("connectionloadbalance") {
+            Some(v) => parse_bool(v.trim()),
+            None => Ok(false),
+        }
+    }
+
+    pub fn database(&self) -> Option<&str> {
+        self.get("database")
+    }
+
+    /// Gets the host of the primary node.
+    pub fn host(&self) -> Option<&str> {
+        self.get("host")
+    }
+
+    #[cfg(not(feature = "secret"))]
+    pub fn password(&self) -> Option<&str> {
+        self.get("password")
+    }
+
+    #[cfg(feature = "secret")]
+    pub fn password(&self) -> Option<crate::secret::Secret<'_>> {
+        self.get("password").map(crate::secret::Secret::new)
+    }
+
+    /// Gets the port of the nodes without one, `None` for the default port 5433.
+    pub fn port(&self) -> Result<Option<u16>, Error> {
+        match self.get("port") {
+            Some(port) => parse_port("port", port).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Gets the `SSLMode`, or its newer name `TLSMode`, `prefer` when missing.
+    pub fn ssl_mode(&self) -> Result<SslMode, Error> {
+        match self.get("sslmode") {
+            Some(mode) if mode.trim().eq_ignore_ascii_case("allow") => Err(Error::InvalidValue {
+                key: "sslmode".to_owned(),
+                value: mode.to_owned(),
+                expected: keywords::VERTICA.get("sslmode").unwrap().kind.to_string(),
+            }),
+            Some(mode) => mode.parse(),
+            None => Ok(SslMode::Prefer),
+        }
+    }
+
+    /// Formats the connection string with its secrets, where the
+    /// [`Display`](fmt::Display) implementation redacts them.
+    pub fn to_connection_string_with_secrets(&self) -> String {
+        to_connection_string(&self.0, &keywords::VERTICA, false, false)
+    }
+
+    pub fn user(&self) -> Option<&str> {
+        self.get("user")
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<&str> {
+        get(&self.0, &keywords::VERTICA, name)
+    }
+
+    /// Validates the connection string against the keyword table of the format,
+    /// returning the issues found.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        self.validate_with(&Validator::new())
+    }
+
+    /// Validates the connection string with the checks configured in the validator.
+    pub fn validate_with(&self, validator: &Validator) -> Vec<ValidationIssue> {
+        validate::table(&self.0, &keywords::VERTICA, validator)
+    }
+}
+
+/// Formats the connection string with its secrets redacted.
+impl fmt::Display for VerticaConnStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&to_connection_string(
+            &self.0,
+            &keywords::VERTICA,
+            false,
+            true,
+        ))
+    }
+}
+
+#[test]
+fn backup_server_nodes_are_listed() {
+    let conn = VerticaConnStr::from_str(
+        "Server=v1;Backup Server Node= [::1]:5434 ,v2;TLSMode=verify-full",
+    )
+    .unwrap();
+
+    assert_eq!(
+        vec![Host::new("::1", Some(5434)), Host::new("v2", None)],
+        conn.backup_server_nodes().unwrap(),
+    );
+    assert_eq!(SslMode::VerifyFull, conn.ssl_mode().unwrap());
+
+    let conn = VerticaConnStr::from_str("Host=v1;BackupServerNode=v2:x;SSLMode=allow").unwrap();
+    assert!(conn.backup_server_nodes().is_err());
+    assert!(conn.ssl_mode().is_err());
+    assert!(VerticaConnStr::from_str("Host=v1")
+        .unwrap()
+        .backup_server_nodes()
+        .unwrap()
+        .is_empty());
+}
@@ -0,0 +1,142 @@
+//! Connection strings of the `<connectionStrings>` section of the .NET configuration
+//! files, `web.config` and `app.config`.
+
+use crate::set::ConnStrSet;
+use crate::{decode_bytes, AnyConnStr, EFConnStr, Error, MsSqlConnStr, ParseOptions};
+use roxmltree::{Document, Node};
+use std::fs;
+use std::path::Path;
+
+/// Reads the connection strings of a configuration file, by name.
+///
+/// The format of each connection string is given by its `providerName`,
+/// `System.Data.EntityClient` for Entity Framework and `System.Data.SqlClient` or
+/// `Microsoft.Data.SqlClient` for MS SQL, and is detected without one. The connection
+/// strings of the other providers are skipped. The `<clear/>` and `<remove/>`
+/// elements apply to the connection strings preceding them.
+///
+/// The errors carry the number of the line, starting at 1.
+///
+/// # Example
+///
+/// ```
+/// use conn_str::web_config::from_xml;
+/// use conn_str::{ParseOptions, Provider};
+///
+/// let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+/// <configuration>
+///   <connectionStrings>
+///     <add name="Sales" connectionString="Data Source=.;Initial Catalog=Sales;Integrated Security=True" providerName="System.Data.SqlClient" />
+///     <add name="Model" connectionString="metadata=res://*/Model.csdl;provider=System.Data.SqlClient;provider connection string=&quot;data source=.&quot;" providerName="System.Data.EntityClient" />
+///     <add name="Logs" connectionString="Server=db1;Uid=me" providerName="MySql.Data.MySqlClient" />
+///   </connectionStrings>
+/// </configuration>"#;
+///
+/// let set = from_xml(xml, &ParseOptions::default()).unwrap();
+///
+/// assert_eq!(vec!["Model", "Sales"], set.iter().map(|(n, _)| n).collect::<Vec<_>>());
+/// assert_eq!(Provider::EntityFramework, set.get("Model").unwrap().provider());
+/// assert_eq!(Provider::MsSql, set.get("Sales").unwrap().provider());
+/// ```
+pub fn from_xml(xml: &str, options: &ParseOptions) -> Result<ConnStrSet, Error> {
+    let doc = Document::parse(xml).map_err(|e| Error::AtLine {
+        line: e.pos().row as usize,
+        error: Box::new(Error::SyntaxError((e.pos().col as usize).saturating_sub(1))),
+    })?;
+
+    let mut set = ConnStrSet::new();
+    let sections = doc
+        .descendants()
+        .filter(|n| n.has_tag_name("connectionStrings"));
+
+    for node in sections.flat_map(|n| n.children()).filter(Node::is_element) {
+        let at_line = |error| Error::AtLine {
+            line: doc.text_pos_at(node.range().start).row as usize,
+            error: Box::new(error),
+        };
+
+        let attribute = |name: &str| {
+            node.attribute(name)
+                .ok_or_else(|| at_line(Error::MissingKey(name.to_owned())))
+        };
+
+        match node.tag_name().name() {
+            "add" => {
+                let name = attribute("name")?;
+                let conn_str = attribute("connectionString")?;
+
+                if let Some(conn) =
+                    parse(conn_str, node.attribute("providerName"), options).map_err(at_line)?
+                {
+                    set.insert(name, conn);
+                }
+            }
+            "clear" => set = ConnStrSet::new(),
+            "remove" => {
+                set.remove(attribute("name")?);
+            }
+            _ => {}
+        }
+    }
+
+    Ok(set)
+}
+
+/// Reads the connection strings of a configuration file, like [`from_xml`].
+pub fn read<P: AsRef<Path>>(path: P, options: &ParseOptions) -> Result<ConnStrSet, Error> {
+    let path = path.as_ref();
+
+    match fs::read(path) {
+        Ok(content) => from_xml(&decode_bytes(&content)?, options),
+        Err(e) => Err(Error::Unreadable {
+            path: path.display().to_string(),
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Parses a connection string in the format of its provider, `None` for the providers
+/// of the other formats.
+fn parse(
+    conn_str: &str,
+    provider_name: Option<&str>,
+    options: &ParseOptions,
+) -> Result<Option<AnyConnStr>, Error> {
+    let provider_name = provider_name.map(str::trim).unwrap_or_default();
+
+    if provider_name.is_empty() {
+        return AnyConnStr::parse_with(conn_str, options).map(Some);
+    }
+
+    match provider_name.to_lowercase().as_str() {
+        "system.data.entityclient" => Ok(Some(AnyConnStr::EntityFramework(EFConnStr::parse_with(
+            conn_str, options,
+        )?))),
+        "system.data.sqlclient" | "microsoft.data.sqlclient" => Ok(Some(AnyConnStr::MsSql(
+            MsSqlConnStr::parse_with(conn_str, options)?,
+        ))),
+        _ => Ok(None),
+    }
+}
+
+#[test]
+fn errors_carry_the_line() {
+    let xml = "<configuration>\n<connectionStrings>\n<add name=\"A\" connectionString=\"server=.\" />\n<clear />\n<add name=\"B\" connectionString=\"server=.\" />\n<add name=\"C\" connectionString=\"server=.\" />\n<remove name=\"C\" />\n</connectionStrings>\n</configuration>";
+    let set = from_xml(xml, &ParseOptions::default()).unwrap();
+
+    assert_eq!(vec!["B"], set.iter().map(|(n, _)| n).collect::<Vec<_>>());
+
+    let xml = "<configuration>\n<connectionStrings>\n<add name=\"A\" />\n</connectionStrings>\n</configuration>";
+
+    match from_xml(xml, &ParseOptions::default()) {
+        Err(Error::AtLine { line: 3, error }) => {
+            assert!(matches!(*error, Error::MissingKey(ref k) if k == "connectionString"))
+        }
+        _ => panic!("the connection string should be missing"),
+    }
+
+    assert!(matches!(
+        from_xml("<configuration>\n<a b=>", &ParseOptions::default()),
+        Err(Error::AtLine { line: 2, .. })
+    ));
+}